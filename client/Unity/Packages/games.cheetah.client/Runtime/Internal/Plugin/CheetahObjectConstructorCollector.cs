@@ -77,7 +77,7 @@ namespace Games.Cheetah.Client.Internal.Plugin
         }
 
 
-        private void OnStructChange(ushort creator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer data)
+        private void OnStructChange(ushort creator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer data, ulong serverTime)
         {
             if (creatingObjects.TryGetValue(objectId, out var creatingObject))
             {
@@ -85,7 +85,7 @@ namespace Games.Cheetah.Client.Internal.Plugin
             }
         }
 
-        private void OnDoubleChange(ushort creator, in CheetahObjectId objectId, ushort fieldId, double value)
+        private void OnDoubleChange(ushort creator, in CheetahObjectId objectId, ushort fieldId, double value, ulong serverTime)
         {
             if (creatingObjects.TryGetValue(objectId, out var creatingObject))
             {
@@ -93,7 +93,7 @@ namespace Games.Cheetah.Client.Internal.Plugin
             }
         }
 
-        private void OnLongChange(ushort creator, in CheetahObjectId objectId, ushort fieldId, long value)
+        private void OnLongChange(ushort creator, in CheetahObjectId objectId, ushort fieldId, long value, ulong serverTime)
         {
             if (creatingObjects.TryGetValue(objectId, out var creatingObject))
             {
@@ -15,7 +15,7 @@ namespace Games.Cheetah.Client.Internal.Plugin.Routers.ByField
         }
 
 
-        private void OnNewEvent(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer data)
+        private void OnNewEvent(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer data, ulong serverTime)
         {
             Notify(commandCreator, in objectId, fieldId, ref data);
         }
@@ -14,7 +14,7 @@ namespace Games.Cheetah.Client.Internal.Plugin.Routers.ByObjectId
             doubleCommandRouter.ChangeListener += OnChange;
         }
 
-        private void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, double value)
+        private void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, double value, ulong serverTime)
         {
             Notify(commandCreator, in objectId, fieldId, ref value);
         }
@@ -14,7 +14,7 @@ namespace Games.Cheetah.Client.Internal.Plugin.Routers.ByObjectId
             eventCommandRouter.NewEventListener += OnChange;
         }
 
-        private void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer value)
+        private void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer value, ulong serverTime)
         {
             Notify(commandCreator, in objectId, fieldId, ref value);
         }
@@ -14,7 +14,7 @@ namespace Games.Cheetah.Client.Internal.Plugin.Routers.ByObjectId
             longCommandRouter.ChangeListener += OnChange;
         }
 
-        private void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, long value)
+        private void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, long value, ulong serverTime)
         {
             Notify(commandCreator, in objectId, fieldId, ref value);
         }
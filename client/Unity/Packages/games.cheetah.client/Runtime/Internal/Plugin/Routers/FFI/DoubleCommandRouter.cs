@@ -24,11 +24,11 @@ namespace Games.Cheetah.Client.Internal.Plugin.Routers.FFI
         }
 
         [MonoPInvokeCallback(typeof(IDoubleServerAPI.Listener))]
-        private static void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, double value)
+        private static void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, double value, ulong serverTime)
         {
             try
             {
-                current.ChangeListener?.Invoke(commandCreator, in objectId, fieldId, value);
+                current.ChangeListener?.Invoke(commandCreator, in objectId, fieldId, value, serverTime);
             }
             catch (Exception e)
             {
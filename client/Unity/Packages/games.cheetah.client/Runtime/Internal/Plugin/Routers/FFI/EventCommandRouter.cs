@@ -24,11 +24,11 @@ namespace Games.Cheetah.Client.Internal.Plugin.Routers.FFI
         }
 
         [MonoPInvokeCallback(typeof(IEventServerAPI.Listener))]
-        private static void OnEvent(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer data)
+        private static void OnEvent(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer data, ulong serverTime)
         {
             try
             {
-                current.NewEventListener?.Invoke(commandCreator, in objectId, fieldId, ref data);
+                current.NewEventListener?.Invoke(commandCreator, in objectId, fieldId, ref data, serverTime);
             }
             catch (Exception e)
             {
@@ -25,11 +25,11 @@ namespace Games.Cheetah.Client.Internal.Plugin.Routers.FFI
         }
 
         [MonoPInvokeCallback(typeof(ILongServerAPI.Listener))]
-        private static void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, long value)
+        private static void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, long value, ulong serverTime)
         {
             try
             {
-                current.ChangeListener?.Invoke(commandCreator, in objectId, fieldId, value);
+                current.ChangeListener?.Invoke(commandCreator, in objectId, fieldId, value, serverTime);
             }
             catch (Exception e)
             {
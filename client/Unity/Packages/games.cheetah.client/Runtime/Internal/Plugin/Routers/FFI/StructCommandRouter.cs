@@ -24,11 +24,11 @@ namespace Games.Cheetah.Client.Internal.Plugin.Routers.FFI
         }
 
         [MonoPInvokeCallback(typeof(IStructureServerAPI.Listener))]
-        private static void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer data)
+        private static void OnChange(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer data, ulong serverTime)
         {
             try
             {
-                current.ChangeListener?.Invoke(commandCreator, in objectId, fieldId, ref data);
+                current.ChangeListener?.Invoke(commandCreator, in objectId, fieldId, ref data, serverTime);
             }
             catch (Exception e)
             {
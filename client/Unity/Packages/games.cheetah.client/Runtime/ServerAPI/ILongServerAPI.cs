@@ -6,7 +6,7 @@ namespace Games.Cheetah.Client.ServerAPI
     public interface ILongServerAPI
     {
         [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-        public delegate void Listener(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, long value);
+        public delegate void Listener(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, long value, ulong serverTime);
 
         byte SetListener(ushort clientId, Listener listener);
         byte Set(ushort clientId, in CheetahObjectId objectId, FieldId.Long fieldId, long value);
@@ -6,7 +6,7 @@ namespace Games.Cheetah.Client.ServerAPI
     public interface IStructureServerAPI
     {
         [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
-        public delegate void Listener(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer data);
+        public delegate void Listener(ushort commandCreator, in CheetahObjectId objectId, ushort fieldId, ref CheetahBuffer data, ulong serverTime);
 
         byte SetListener(ushort clientId, Listener listener);
         byte Set(ushort clientId, in CheetahObjectId objectId, FieldId.Structure fieldId, ref CheetahBuffer data);
@@ -17,7 +17,7 @@ namespace Games.Cheetah.Client.ServerAPI.Mock.Events
 
         public void Apply(CheetahClientMock cheetahClientMock)
         {
-            cheetahClientMock.doubles.listener?.Invoke(0, in objectId, fieldId.Id, value);
+            cheetahClientMock.doubles.listener?.Invoke(0, in objectId, fieldId.Id, value, 0);
         }
     }
 }
\ No newline at end of file
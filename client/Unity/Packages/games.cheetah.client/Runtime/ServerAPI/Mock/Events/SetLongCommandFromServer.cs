@@ -18,7 +18,7 @@ namespace Games.Cheetah.Client.ServerAPI.Mock.Events
 
         public void Apply(CheetahClientMock cheetahClientMock)
         {
-            cheetahClientMock.longs.listener?.Invoke(0, in objectId, fieldId.Id, value);
+            cheetahClientMock.longs.listener?.Invoke(0, in objectId, fieldId.Id, value, 0);
         }
     }
 }
\ No newline at end of file
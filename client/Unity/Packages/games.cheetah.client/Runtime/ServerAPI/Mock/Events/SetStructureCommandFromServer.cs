@@ -19,7 +19,7 @@ namespace Games.Cheetah.Client.ServerAPI.Mock.Events
         {
             var buffer = new CheetahBuffer();
             cheetahClientMock.codecRegistry.GetCodec<T>().Encode(in value, ref buffer);
-            cheetahClientMock.structures.listener?.Invoke(0, in objectId, fieldId.Id, ref buffer);
+            cheetahClientMock.structures.listener?.Invoke(0, in objectId, fieldId.Id, ref buffer, 0);
         }
     }
 }
\ No newline at end of file
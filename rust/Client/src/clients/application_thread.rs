@@ -1,13 +1,22 @@
+use std::collections::HashMap;
 use std::sync::mpsc::{Receiver, SendError, Sender};
 use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 use std::thread::JoinHandle;
 use std::time::Duration;
 
+use fnv::FnvBuildHasher;
+
 use cheetah_common::commands::binary_value::BinaryValue;
 use cheetah_common::commands::c2s::C2SCommand;
 use cheetah_common::commands::field::FieldId;
 use cheetah_common::commands::s2c::S2CCommand;
 use cheetah_common::commands::types::create::CreateGameObjectCommand;
+use cheetah_common::commands::types::list::ListIndex;
+use cheetah_common::commands::types::lockstep::LockstepTickId;
+use cheetah_common::commands::types::map::MapKey;
+use cheetah_common::commands::types::room_event::RoomEventId;
+use cheetah_common::commands::types::rpc::{RpcCallId, RpcRequestCommand, RpcStatus};
+use cheetah_common::commands::types::transaction::TransactionCommand;
 use cheetah_common::commands::{FieldType, FieldValue};
 use cheetah_common::network::client::ConnectionStatus;
 use cheetah_common::protocol::disconnect::command::DisconnectByCommandReason;
@@ -21,6 +30,10 @@ use cheetah_common::room::RoomMemberId;
 use crate::clients::network_thread::C2SCommandWithChannel;
 use crate::clients::{ClientRequest, SharedClientStatistics};
 use crate::ffi::channel::Channel;
+use crate::ffi::command::field_history::FieldHistoryValueFFI;
+use crate::ffi::command::map::MapValueFFI;
+use crate::ffi::command::rejected::CommandRejectedFFI;
+use crate::ffi::command::string::to_utf16;
 use crate::ffi::ForwardedCommandFFI;
 
 ///
@@ -35,17 +48,72 @@ pub struct ApplicationThreadClient {
 	request_to_client: Sender<ClientRequest>,
 	channel: ChannelType,
 	game_object_id_generator: u32,
+	rpc_call_id_generator: RpcCallId,
+	///
+	/// Последние полученные в канале `ReliableSequence` значения структур с группой канала - к ним применяется разница,
+	/// присланная сервером в канале той же группы
+	///
+	structures: HashMap<(GameObjectId, FieldId), (ChannelGroup, BinaryValue), FnvBuildHasher>,
 	pub shared_statistics: SharedClientStatistics,
-	pub listener_long_value: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, i64)>,
-	pub listener_float_value: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, f64)>,
-	pub listener_event: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, &BinaryValue)>,
-	pub listener_structure: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, &BinaryValue)>,
+	///
+	/// Последний аргумент слушателей полей и событий - время сервера в миллисекундах от создания комнаты,
+	/// 0 - если передача времени не включена в настройках комнаты
+	///
+	pub listener_long_value: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, i64, u64)>,
+	pub listener_float_value: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, f64, u64)>,
+	pub listener_event: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, &BinaryValue, u64)>,
+	pub listener_structure: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, &BinaryValue, u64)>,
 	pub listener_delete_field: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, FieldType)>,
 	pub listener_create_object: Option<extern "C" fn(&GameObjectId, u16)>,
 	pub listener_delete_object: Option<extern "C" fn(&GameObjectId)>,
 	pub listener_created_object: Option<extern "C" fn(&GameObjectId)>,
 	pub listener_forwarded_command: Option<extern "C" fn(ForwardedCommandFFI)>,
 	pub listener_member_connected: Option<extern "C" fn(RoomMemberId)>,
+	pub listener_transfer_ownership: Option<extern "C" fn(&GameObjectId, RoomMemberId)>,
+	pub listener_lockstep_input: Option<extern "C" fn(LockstepTickId, RoomMemberId, bool, &BinaryValue)>,
+	pub listener_lockstep_tick: Option<extern "C" fn(LockstepTickId, u16)>,
+	pub listener_command_rejected: Option<extern "C" fn(&CommandRejectedFFI)>,
+	///
+	/// Запрос от другого пользователя - вызывающий пользователь, идентификатор вызова, данные запроса
+	///
+	pub listener_rpc_request: Option<extern "C" fn(RoomMemberId, RpcCallId, &BinaryValue)>,
+	///
+	/// Ответ на запрос - вызываемый пользователь, идентификатор вызова, результат, данные ответа
+	///
+	pub listener_rpc_response: Option<extern "C" fn(RoomMemberId, RpcCallId, RpcStatus, &BinaryValue)>,
+	///
+	/// Событие комнаты - отправитель, идентификатор события, данные события
+	///
+	pub listener_room_event: Option<extern "C" fn(RoomMemberId, RoomEventId, &BinaryValue)>,
+	///
+	/// Нарушение правила проверки поля - нарушивший пользователь, объект, поле, тип поля
+	///
+	pub listener_field_violation: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, FieldType)>,
+	///
+	/// Ответ на запрос значения поля из истории
+	///
+	pub listener_field_history: Option<extern "C" fn(&FieldHistoryValueFFI)>,
+	///
+	/// Изменения списков, установка всего списка передается как очистка и добавление элементов
+	///
+	pub listener_list_push: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, &BinaryValue, u64)>,
+	pub listener_list_remove: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, ListIndex, u64)>,
+	pub listener_list_set_item: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, ListIndex, &BinaryValue, u64)>,
+	pub listener_list_clear: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, u64)>,
+	///
+	/// Изменения словарей, установка всего словаря передается как очистка и установка значений
+	///
+	pub listener_map_set: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, MapKey, &MapValueFFI, u64)>,
+	pub listener_map_delete: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, MapKey, u64)>,
+	pub listener_map_clear: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, u64)>,
+	///
+	/// Строка передается в UTF-16 с завершающим нулем, указатель действителен только во время вызова
+	///
+	pub listener_string: Option<extern "C" fn(RoomMemberId, &GameObjectId, FieldId, *const u16, u64)>,
+	///
+	/// Формируемая транзакция, отправляется на сервер целиком при завершении
+	///
+	pub transaction: Option<TransactionCommand>,
 }
 
 impl Drop for ApplicationThreadClient {
@@ -79,6 +147,8 @@ impl ApplicationThreadClient {
 			request_to_client: sender,
 			channel: ChannelType::ReliableSequence(ChannelGroup(0)),
 			game_object_id_generator: GameObjectId::CLIENT_OBJECT_ID_OFFSET,
+			rpc_call_id_generator: 0,
+			structures: Default::default(),
 			shared_statistics,
 			listener_long_value: None,
 			listener_float_value: None,
@@ -90,6 +160,24 @@ impl ApplicationThreadClient {
 			listener_delete_field: None,
 			listener_forwarded_command: None,
 			listener_member_connected: None,
+			listener_transfer_ownership: None,
+			listener_lockstep_input: None,
+			listener_lockstep_tick: None,
+			listener_command_rejected: None,
+			listener_rpc_request: None,
+			listener_rpc_response: None,
+			listener_room_event: None,
+			listener_field_violation: None,
+			listener_field_history: None,
+			listener_list_push: None,
+			listener_list_remove: None,
+			listener_list_set_item: None,
+			listener_list_clear: None,
+			listener_map_set: None,
+			listener_map_delete: None,
+			listener_map_clear: None,
+			listener_string: None,
+			transaction: None,
 		}
 	}
 
@@ -126,7 +214,9 @@ impl ApplicationThreadClient {
 
 	pub fn receive(&mut self) {
 		while let Ok(command) = self.commands_from_server.try_recv() {
+			let channel_type = ChannelType::from(&command.channel);
 			if let BothDirectionCommand::S2CWithCreator(member_with_creator) = command.both_direction_command {
+				let time = member_with_creator.time.unwrap_or_default();
 				match member_with_creator.command {
 					S2CCommand::Create(command) => {
 						if let Some(ref listener) = self.listener_create_object {
@@ -144,40 +234,111 @@ impl ApplicationThreadClient {
 						FieldValue::Long(v) => {
 							if let Some(ref listener) = self.listener_long_value {
 								let object_id = command.object_id;
-								listener(member_with_creator.creator, &object_id, command.field_id, v);
+								listener(member_with_creator.creator, &object_id, command.field_id, v, time);
 							}
 						}
 						FieldValue::Double(v) => {
 							if let Some(ref listener) = self.listener_float_value {
 								let object_id = command.object_id;
-								listener(member_with_creator.creator, &object_id, command.field_id, v);
+								listener(member_with_creator.creator, &object_id, command.field_id, v, time);
 							}
 						}
 						FieldValue::Structure(s) => {
+							let key = (command.object_id, command.field_id);
+							// сервер отправляет разницу только с последним значением в канале ReliableSequence той же группы
+							match channel_type {
+								ChannelType::ReliableSequence(group) => {
+									self.structures.insert(key, (group, s));
+								}
+								_ => {
+									self.structures.remove(&key);
+								}
+							}
 							if let Some(ref listener) = self.listener_structure {
 								let object_id = command.object_id;
-								listener(member_with_creator.creator, &object_id, command.field_id, &s.as_slice().into());
+								listener(member_with_creator.creator, &object_id, command.field_id, &s.as_slice().into(), time);
+							}
+						}
+						FieldValue::List(items) => {
+							if let Some(ref listener) = self.listener_list_clear {
+								listener(member_with_creator.creator, &command.object_id, command.field_id, time);
+							}
+							if let Some(ref listener) = self.listener_list_push {
+								for item in &items {
+									listener(member_with_creator.creator, &command.object_id, command.field_id, item, time);
+								}
+							}
+						}
+						FieldValue::String(value) => {
+							if let Some(ref listener) = self.listener_string {
+								let value = to_utf16(&value);
+								listener(member_with_creator.creator, &command.object_id, command.field_id, value.as_ptr(), time);
+							}
+						}
+						FieldValue::Map(items) => {
+							if let Some(ref listener) = self.listener_map_clear {
+								listener(member_with_creator.creator, &command.object_id, command.field_id, time);
+							}
+							if let Some(ref listener) = self.listener_map_set {
+								for (key, value) in &items {
+									listener(
+										member_with_creator.creator,
+										&command.object_id,
+										command.field_id,
+										*key,
+										&value.into(),
+										time,
+									);
+								}
 							}
 						}
 					},
+					S2CCommand::SetStructureDelta(command) => {
+						let key = (command.object_id, command.field_id);
+						let (group, base) = match (self.structures.get(&key), channel_type) {
+							(Some((base_group, base)), ChannelType::ReliableSequence(group)) if *base_group == group => (group, base),
+							_ => {
+								tracing::error!("Structure for delta not found {:?}", key);
+								continue;
+							}
+						};
+						let value = match command.apply(base) {
+							Ok(value) => value,
+							Err(e) => {
+								tracing::error!("Apply structure delta error {:?} for {:?}", e, key);
+								continue;
+							}
+						};
+						self.structures.insert(key, (group, value));
+						if let Some(ref listener) = self.listener_structure {
+							listener(member_with_creator.creator, &command.object_id, command.field_id, &value, time);
+						}
+					}
 					S2CCommand::Event(command) => {
 						if let Some(ref listener) = self.listener_event {
-							listener(member_with_creator.creator, &command.object_id, command.field_id, &command.event);
+							listener(member_with_creator.creator, &command.object_id, command.field_id, &command.event, time);
 						}
 					}
 					S2CCommand::Delete(command) => {
+						self.structures.retain(|(object_id, _), _| *object_id != command.object_id);
 						if let Some(ref listener) = self.listener_delete_object {
 							listener(&command.object_id);
 						}
 					}
 					S2CCommand::DeleteField(command) => {
+						if command.field_type == FieldType::Structure {
+							self.structures.remove(&(command.object_id, command.field_id));
+						}
 						if let Some(ref listener) = self.listener_delete_field {
 							listener(member_with_creator.creator, &command.object_id, command.field_id, command.field_type);
 						}
 					}
 					S2CCommand::Forwarded(command) => {
 						if let Some(ref listener) = self.listener_forwarded_command {
-							listener((*command).into());
+							match ForwardedCommandFFI::try_from(*command) {
+								Ok(command) => listener(command),
+								Err(e) => tracing::error!("Forwarded command error {:?}", e),
+							}
 						}
 					}
 					S2CCommand::MemberConnected(command) => {
@@ -185,23 +346,135 @@ impl ApplicationThreadClient {
 							listener(command.member_id);
 						}
 					}
+					S2CCommand::TransferOwnership(command) => {
+						if let Some(ref listener) = self.listener_transfer_ownership {
+							listener(&command.object_id, command.new_owner);
+						}
+					}
+					S2CCommand::LockstepInput(command) => {
+						if let Some(ref listener) = self.listener_lockstep_input {
+							listener(command.tick, command.member_id, command.filled, &command.input);
+						}
+					}
+					S2CCommand::LockstepTick(command) => {
+						if let Some(ref listener) = self.listener_lockstep_tick {
+							listener(command.tick, command.inputs);
+						}
+					}
+					S2CCommand::CommandRejected(command) => {
+						if let Some(ref listener) = self.listener_command_rejected {
+							listener(&command.into());
+						}
+					}
+					S2CCommand::ListPush(command) => {
+						if let Some(ref listener) = self.listener_list_push {
+							listener(member_with_creator.creator, &command.object_id, command.field_id, &command.value, time);
+						}
+					}
+					S2CCommand::ListRemove(command) => {
+						if let Some(ref listener) = self.listener_list_remove {
+							listener(member_with_creator.creator, &command.object_id, command.field_id, command.index, time);
+						}
+					}
+					S2CCommand::ListSetItem(command) => {
+						if let Some(ref listener) = self.listener_list_set_item {
+							listener(
+								member_with_creator.creator,
+								&command.object_id,
+								command.field_id,
+								command.index,
+								&command.value,
+								time,
+							);
+						}
+					}
+					S2CCommand::ListClear(command) => {
+						if let Some(ref listener) = self.listener_list_clear {
+							listener(member_with_creator.creator, &command.object_id, command.field_id, time);
+						}
+					}
+					S2CCommand::MapSet(command) => {
+						if let Some(ref listener) = self.listener_map_set {
+							listener(
+								member_with_creator.creator,
+								&command.object_id,
+								command.field_id,
+								command.key,
+								&(&command.value).into(),
+								time,
+							);
+						}
+					}
+					S2CCommand::MapDelete(command) => {
+						if let Some(ref listener) = self.listener_map_delete {
+							listener(member_with_creator.creator, &command.object_id, command.field_id, command.key, time);
+						}
+					}
+					S2CCommand::RpcRequest(command) => {
+						if let Some(ref listener) = self.listener_rpc_request {
+							listener(command.member_id, command.call_id, &command.payload);
+						}
+					}
+					S2CCommand::RpcResponse(command) => {
+						if let Some(ref listener) = self.listener_rpc_response {
+							listener(command.member_id, command.call_id, command.status, &command.payload);
+						}
+					}
+					S2CCommand::RoomEvent(command) => {
+						if let Some(ref listener) = self.listener_room_event {
+							listener(member_with_creator.creator, command.event_id, &command.event);
+						}
+					}
+					S2CCommand::FieldViolation(command) => {
+						if let Some(ref listener) = self.listener_field_violation {
+							listener(command.member_id, &command.object_id, command.field_id, command.field_type);
+						}
+					}
+					S2CCommand::FieldHistory(command) => {
+						if let Some(ref listener) = self.listener_field_history {
+							listener(&command.into());
+						}
+					}
 				}
 			}
 		}
 	}
 
-	pub fn create_game_object(&mut self, template: u16, access_group: u64) -> Result<GameObjectId, SendError<ClientRequest>> {
+	pub fn create_game_object(
+		&mut self,
+		template: u16,
+		access_group: u64,
+		parent: Option<GameObjectId>,
+		inherit_access_groups: bool,
+	) -> Result<GameObjectId, SendError<ClientRequest>> {
 		self.game_object_id_generator += 1;
 		let game_object_id = GameObjectId::new(self.game_object_id_generator, GameObjectOwner::Member(self.member_id));
 		self.send(C2SCommand::CreateGameObject(CreateGameObjectCommand {
 			object_id: game_object_id,
 			template,
 			access_groups: AccessGroups(access_group),
+			parent,
+			inherit_access_groups,
 		}))?;
 
 		Ok(game_object_id)
 	}
 
+	///
+	/// Отправить запрос пользователю, возвращается идентификатор вызова для сопоставления с ответом
+	///
+	pub fn rpc_request(&mut self, target: RoomMemberId, timeout: u32, payload: BinaryValue) -> Result<RpcCallId, SendError<ClientRequest>> {
+		self.rpc_call_id_generator = self.rpc_call_id_generator.wrapping_add(1);
+		let call_id = self.rpc_call_id_generator;
+		self.send(C2SCommand::RpcRequest(RpcRequestCommand {
+			member_id: target,
+			call_id,
+			timeout,
+			payload,
+		}))?;
+		Ok(call_id)
+	}
+
 	pub fn set_rtt_emulation(&mut self, rtt: Duration, rtt_dispersion: f64) -> Result<(), SendError<ClientRequest>> {
 		self.request_to_client.send(ClientRequest::ConfigureRttEmulation(rtt, rtt_dispersion))
 	}
@@ -218,6 +491,7 @@ impl ApplicationThreadClient {
 	pub fn attach_to_room(&mut self) -> Result<(), SendError<ClientRequest>> {
 		// удаляем все пришедшие команды (ситуация возникает при attach/detach)
 		while self.commands_from_server.try_recv().is_ok() {}
+		self.structures.clear();
 		self.send(C2SCommand::AttachToRoom)
 	}
 }
@@ -28,6 +28,7 @@ pub enum ConnectionStatusFFI {
 	DisconnectedByClientStopped,
 	DisconnectedByRoomDeleted,
 	DisconnectedByMemberDeleted,
+	DisconnectedByCommandRateLimitExceeded,
 }
 
 #[no_mangle]
@@ -49,6 +50,7 @@ pub extern "C" fn get_connection_status(client_id: ClientId, result: &mut Connec
 							DisconnectByCommandReason::ClientStopped => ConnectionStatusFFI::DisconnectedByClientStopped,
 							DisconnectByCommandReason::RoomDeleted => ConnectionStatusFFI::DisconnectedByRoomDeleted,
 							DisconnectByCommandReason::MemberDeleted => ConnectionStatusFFI::DisconnectedByMemberDeleted,
+							DisconnectByCommandReason::CommandRateLimitExceeded => ConnectionStatusFFI::DisconnectedByCommandRateLimitExceeded,
 						},
 					},
 				};
@@ -10,7 +10,7 @@ use crate::ffi::command::send_command;
 use crate::ffi::execute_with_client;
 
 #[no_mangle]
-pub extern "C" fn set_event_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, &BinaryValue)) -> u8 {
+pub extern "C" fn set_event_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, &BinaryValue, u64)) -> u8 {
 	execute_with_client(client_id, |client| {
 		client.listener_event = Some(listener);
 		Ok(())
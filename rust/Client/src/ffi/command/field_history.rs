@@ -0,0 +1,67 @@
+use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::field_history::{FieldHistoryQueryCommand, FieldHistoryValueCommand};
+use cheetah_common::commands::{FieldType, FieldValue};
+use cheetah_common::room::object::GameObjectId;
+
+use crate::clients::registry::ClientId;
+use crate::ffi::command::send_command;
+use crate::ffi::execute_with_client;
+
+///
+/// Значение поля объекта из истории, для полей типа long задается long_value, для double - double_value
+///
+#[repr(C)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldHistoryValueFFI {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub field_type: FieldType,
+	pub time: u64,
+	pub has_value: bool,
+	pub long_value: i64,
+	pub double_value: f64,
+}
+
+impl From<FieldHistoryValueCommand> for FieldHistoryValueFFI {
+	fn from(command: FieldHistoryValueCommand) -> Self {
+		let (long_value, double_value) = match command.value {
+			Some(FieldValue::Long(value)) => (value, 0.0),
+			Some(FieldValue::Double(value)) => (0, value),
+			_ => (0, 0.0),
+		};
+		Self {
+			object_id: command.object_id,
+			field_id: command.field_id,
+			field_type: command.field_type,
+			time: command.time,
+			has_value: command.value.is_some(),
+			long_value,
+			double_value,
+		}
+	}
+}
+
+#[no_mangle]
+pub extern "C" fn set_field_history_listener(client_id: ClientId, listener: extern "C" fn(&FieldHistoryValueFFI)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_field_history = Some(listener);
+		Ok(())
+	})
+}
+
+///
+/// Запросить значение поля объекта в момент времени сервера (в миллисекундах), доступно только супер-пользователям
+///
+#[no_mangle]
+pub extern "C" fn get_field_history_value(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, field_type: FieldType, time: u64) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::FieldHistory(FieldHistoryQueryCommand {
+			object_id: *object_id,
+			field_id,
+			field_type,
+			time,
+		}),
+	)
+}
@@ -0,0 +1,18 @@
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::FieldType;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::clients::registry::ClientId;
+use crate::ffi::execute_with_client;
+
+///
+/// Нарушения правил проверки полей другими пользователями, получают только супер-пользователи
+///
+#[no_mangle]
+pub extern "C" fn set_field_violation_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, FieldType)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_field_violation = Some(listener);
+		Ok(())
+	})
+}
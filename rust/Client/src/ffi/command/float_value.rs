@@ -1,7 +1,8 @@
 use cheetah_common::commands::c2s::C2SCommand;
 use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::bounds::{Bounds, BoundsMode};
 use cheetah_common::commands::types::field::SetFieldCommand;
-use cheetah_common::commands::types::float::IncrementDoubleC2SCommand;
+use cheetah_common::commands::types::float::{CompareAndSetDoubleCommand, IncrementDoubleC2SCommand, IncrementDoubleWithBoundsC2SCommand};
 use cheetah_common::room::object::GameObjectId;
 use cheetah_common::room::RoomMemberId;
 
@@ -10,7 +11,7 @@ use crate::ffi::command::send_command;
 use crate::ffi::execute_with_client;
 
 #[no_mangle]
-pub extern "C" fn set_double_value_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, f64)) -> u8 {
+pub extern "C" fn set_double_value_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, f64, u64)) -> u8 {
 	execute_with_client(client_id, |client| {
 		client.listener_float_value = Some(listener);
 		Ok(())
@@ -40,3 +41,49 @@ pub extern "C" fn inc_double_value(client_id: ClientId, object_id: &GameObjectId
 		}),
 	)
 }
+
+///
+/// Инкремент с ограничением итогового значения диапазоном min..=max
+///
+#[no_mangle]
+pub extern "C" fn inc_double_value_with_bounds(
+	client_id: ClientId,
+	object_id: &GameObjectId,
+	field_id: FieldId,
+	increment: f64,
+	min: f64,
+	max: f64,
+	mode: BoundsMode,
+) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::IncrementDoubleWithBounds(IncrementDoubleWithBoundsC2SCommand {
+			object_id: *object_id,
+			field_id,
+			increment,
+			bounds: Bounds { min, max, mode },
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn compare_and_set_double_value(
+	client_id: ClientId,
+	object_id: &GameObjectId,
+	field_id: FieldId,
+	current: f64,
+	new: f64,
+	has_reset: bool,
+	reset: f64,
+) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::CompareAndSetDouble(CompareAndSetDoubleCommand::new(
+			*object_id,
+			field_id,
+			current,
+			new,
+			has_reset.then_some(reset),
+		)),
+	)
+}
@@ -0,0 +1,110 @@
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::list::{
+	ListClearCommand, ListIndex, ListPushCommand, ListRemoveCommand, ListRemoveValueCommand, ListSetItemCommand,
+};
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::clients::registry::ClientId;
+use crate::ffi::command::send_command;
+use crate::ffi::execute_with_client;
+
+#[no_mangle]
+pub extern "C" fn set_list_push_listener(
+	client_id: ClientId,
+	listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, &BinaryValue, u64),
+) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_list_push = Some(listener);
+		Ok(())
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn set_list_remove_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, ListIndex, u64)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_list_remove = Some(listener);
+		Ok(())
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn set_list_set_item_listener(
+	client_id: ClientId,
+	listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, ListIndex, &BinaryValue, u64),
+) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_list_set_item = Some(listener);
+		Ok(())
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn set_list_clear_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, u64)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_list_clear = Some(listener);
+		Ok(())
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn list_push(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, value: &BinaryValue) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::ListPush(ListPushCommand {
+			object_id: *object_id,
+			field_id,
+			value: *value,
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn list_remove(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, index: ListIndex) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::ListRemove(ListRemoveCommand {
+			object_id: *object_id,
+			field_id,
+			index,
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn list_remove_value(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, value: &BinaryValue) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::ListRemoveValue(ListRemoveValueCommand {
+			object_id: *object_id,
+			field_id,
+			value: *value,
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn list_set_item(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, index: ListIndex, value: &BinaryValue) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::ListSetItem(ListSetItemCommand {
+			object_id: *object_id,
+			field_id,
+			index,
+			value: *value,
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn list_clear(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::ListClear(ListClearCommand {
+			object_id: *object_id,
+			field_id,
+		}),
+	)
+}
@@ -0,0 +1,34 @@
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::types::lockstep::{LockstepHistoryCommand, LockstepInputCommand, LockstepTickId};
+use cheetah_common::room::RoomMemberId;
+
+use crate::clients::registry::ClientId;
+use crate::ffi::command::send_command;
+use crate::ffi::execute_with_client;
+
+#[no_mangle]
+pub extern "C" fn set_lockstep_input_listener(client_id: ClientId, listener: extern "C" fn(LockstepTickId, RoomMemberId, bool, &BinaryValue)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_lockstep_input = Some(listener);
+		Ok(())
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn set_lockstep_tick_listener(client_id: ClientId, listener: extern "C" fn(LockstepTickId, u16)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_lockstep_tick = Some(listener);
+		Ok(())
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn send_lockstep_input(client_id: ClientId, tick: LockstepTickId, input: &BinaryValue) -> u8 {
+	send_command(client_id, C2SCommand::LockstepInput(LockstepInputCommand { tick, input: *input }))
+}
+
+#[no_mangle]
+pub extern "C" fn request_lockstep_history(client_id: ClientId, from_tick: LockstepTickId) -> u8 {
+	send_command(client_id, C2SCommand::LockstepHistory(LockstepHistoryCommand { from_tick }))
+}
@@ -1,7 +1,8 @@
 use cheetah_common::commands::c2s::C2SCommand;
 use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::bounds::{Bounds, BoundsMode};
 use cheetah_common::commands::types::field::SetFieldCommand;
-use cheetah_common::commands::types::long::{CompareAndSetLongCommand, IncrementLongC2SCommand};
+use cheetah_common::commands::types::long::{CompareAndSetLongCommand, IncrementLongC2SCommand, IncrementLongWithBoundsC2SCommand};
 use cheetah_common::room::object::GameObjectId;
 use cheetah_common::room::RoomMemberId;
 
@@ -10,7 +11,7 @@ use crate::ffi::command::send_command;
 use crate::ffi::execute_with_client;
 
 #[no_mangle]
-pub extern "C" fn set_long_value_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, i64)) -> u8 {
+pub extern "C" fn set_long_value_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, i64, u64)) -> u8 {
 	execute_with_client(client_id, |client| {
 		client.listener_long_value = Some(listener);
 		Ok(())
@@ -41,6 +42,30 @@ pub extern "C" fn inc_long_value(client_id: ClientId, object_id: &GameObjectId,
 	)
 }
 
+///
+/// Инкремент с ограничением итогового значения диапазоном min..=max
+///
+#[no_mangle]
+pub extern "C" fn inc_long_value_with_bounds(
+	client_id: ClientId,
+	object_id: &GameObjectId,
+	field_id: FieldId,
+	increment: i64,
+	min: i64,
+	max: i64,
+	mode: BoundsMode,
+) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::IncrementLongWithBounds(IncrementLongWithBoundsC2SCommand {
+			object_id: *object_id,
+			field_id,
+			increment,
+			bounds: Bounds { min, max, mode },
+		}),
+	)
+}
+
 #[no_mangle]
 pub extern "C" fn compare_and_set_long_value(
 	client_id: ClientId,
@@ -0,0 +1,141 @@
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::map::{MapDeleteCommand, MapIncrementCommand, MapKey, MapSetCommand, MapValue};
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::clients::registry::ClientId;
+use crate::ffi::command::send_command;
+use crate::ffi::execute_with_client;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MapValueType {
+	Long,
+	Double,
+	Binary,
+}
+
+///
+/// Значение элемента словаря, заполнено только поле соответствующего типа
+///
+#[repr(C)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapValueFFI {
+	pub value_type: MapValueType,
+	pub long_value: i64,
+	pub double_value: f64,
+	pub binary_value: BinaryValue,
+}
+
+impl From<&MapValue> for MapValueFFI {
+	fn from(value: &MapValue) -> Self {
+		let mut result = Self {
+			value_type: MapValueType::Long,
+			long_value: 0,
+			double_value: 0.0,
+			binary_value: Default::default(),
+		};
+		match value {
+			MapValue::Long(v) => result.long_value = *v,
+			MapValue::Double(v) => {
+				result.value_type = MapValueType::Double;
+				result.double_value = *v;
+			}
+			MapValue::Binary(v) => {
+				result.value_type = MapValueType::Binary;
+				result.binary_value = *v;
+			}
+		}
+		result
+	}
+}
+
+#[no_mangle]
+pub extern "C" fn set_map_set_listener(
+	client_id: ClientId,
+	listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, MapKey, &MapValueFFI, u64),
+) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_map_set = Some(listener);
+		Ok(())
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn set_map_delete_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, MapKey, u64)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_map_delete = Some(listener);
+		Ok(())
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn set_map_clear_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, u64)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_map_clear = Some(listener);
+		Ok(())
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn map_set_long(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, key: MapKey, value: i64) -> u8 {
+	map_set(client_id, object_id, field_id, key, MapValue::Long(value))
+}
+
+#[no_mangle]
+pub extern "C" fn map_set_double(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, key: MapKey, value: f64) -> u8 {
+	map_set(client_id, object_id, field_id, key, MapValue::Double(value))
+}
+
+#[no_mangle]
+pub extern "C" fn map_set_binary(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, key: MapKey, value: &BinaryValue) -> u8 {
+	map_set(client_id, object_id, field_id, key, MapValue::Binary(*value))
+}
+
+#[no_mangle]
+pub extern "C" fn map_delete(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, key: MapKey) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::MapDelete(MapDeleteCommand {
+			object_id: *object_id,
+			field_id,
+			key,
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn map_increment_long(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, key: MapKey, increment: i64) -> u8 {
+	map_increment(client_id, object_id, field_id, key, MapValue::Long(increment))
+}
+
+#[no_mangle]
+pub extern "C" fn map_increment_double(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, key: MapKey, increment: f64) -> u8 {
+	map_increment(client_id, object_id, field_id, key, MapValue::Double(increment))
+}
+
+fn map_set(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, key: MapKey, value: MapValue) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::MapSet(MapSetCommand {
+			object_id: *object_id,
+			field_id,
+			key,
+			value,
+		}),
+	)
+}
+
+fn map_increment(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, key: MapKey, increment: MapValue) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::MapIncrement(MapIncrementCommand {
+			object_id: *object_id,
+			field_id,
+			key,
+			increment,
+		}),
+	)
+}
@@ -5,12 +5,23 @@ use crate::ffi::execute_with_client;
 
 pub mod event;
 pub mod field;
+pub mod field_history;
+pub mod field_violation;
 pub mod float_value;
+pub mod list;
+pub mod lockstep;
 pub mod long_value;
+pub mod map;
 pub mod member_connected;
 pub mod object;
+pub mod rejected;
 pub mod room;
+pub mod room_event;
+pub mod rpc;
+pub mod string;
 pub mod structure;
+pub mod timer;
+pub mod transaction;
 
 fn send_command(client_id: ClientId, command: C2SCommand) -> u8 {
 	execute_with_client(client_id, |client| Ok(client.send(command)?))
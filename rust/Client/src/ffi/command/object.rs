@@ -1,8 +1,13 @@
 use cheetah_common::commands::binary_value::BinaryValue;
 use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::types::access_groups::SetObjectAccessGroupsCommand;
 use cheetah_common::commands::types::create::C2SCreatedGameObjectCommand;
 use cheetah_common::commands::types::delete::DeleteGameObjectCommand;
+use cheetah_common::commands::types::ownership::TransferOwnershipCommand;
+use cheetah_common::commands::types::ttl::SetObjectTtlCommand;
+use cheetah_common::room::access::AccessGroups;
 use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
 
 use crate::clients::registry::ClientId;
 use crate::ffi::command::send_command;
@@ -27,7 +32,28 @@ pub extern "C" fn set_created_object_listener(client_id: ClientId, listener: ext
 #[no_mangle]
 pub extern "C" fn create_object(client_id: ClientId, template: u16, access_group: u64, result: &mut GameObjectId) -> u8 {
 	execute_with_client(client_id, |client| {
-		let game_object_id = client.create_game_object(template, access_group)?;
+		let game_object_id = client.create_game_object(template, access_group, None, false)?;
+		*result = game_object_id;
+		Ok(())
+	})
+}
+
+///
+/// Создать дочерний объект, объект удаляется сервером вместе с родительским
+///
+/// - `inherit_access_groups` - использовать группы доступа родительского объекта вместо `access_group`
+///
+#[no_mangle]
+pub extern "C" fn create_child_object(
+	client_id: ClientId,
+	template: u16,
+	access_group: u64,
+	parent: &GameObjectId,
+	inherit_access_groups: bool,
+	result: &mut GameObjectId,
+) -> u8 {
+	execute_with_client(client_id, |client| {
+		let game_object_id = client.create_game_object(template, access_group, Some(*parent), inherit_access_groups)?;
 		*result = game_object_id;
 		Ok(())
 	})
@@ -55,6 +81,28 @@ pub extern "C" fn delete_object(client_id: ClientId, object_id: &GameObjectId) -
 	send_command(client_id, C2SCommand::Delete(DeleteGameObjectCommand { object_id: *object_id }))
 }
 
+///
+/// Установить время жизни объекта в миллисекундах, 0 - время жизни не ограничено
+///
+#[no_mangle]
+pub extern "C" fn set_object_ttl(client_id: ClientId, object_id: &GameObjectId, ttl: u64) -> u8 {
+	send_command(client_id, C2SCommand::SetObjectTtl(SetObjectTtlCommand { object_id: *object_id, ttl }))
+}
+
+///
+/// Изменить группы доступа объекта, пользователям, потерявшим доступ, объект удаляется, получившим - загружается
+///
+#[no_mangle]
+pub extern "C" fn set_object_access_groups(client_id: ClientId, object_id: &GameObjectId, access_group: u64) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::SetObjectAccessGroups(SetObjectAccessGroupsCommand {
+			object_id: *object_id,
+			access_groups: AccessGroups(access_group),
+		}),
+	)
+}
+
 #[no_mangle]
 pub extern "C" fn set_forwarded_command_listener(client_id: ClientId, listener: extern "C" fn(ForwardedCommandFFI)) -> u8 {
 	execute_with_client(client_id, |client| {
@@ -62,3 +110,25 @@ pub extern "C" fn set_forwarded_command_listener(client_id: ClientId, listener:
 		Ok(())
 	})
 }
+
+///
+/// Передать владение объектом пользователю, объект удаляется при выходе нового владельца из комнаты
+///
+#[no_mangle]
+pub extern "C" fn transfer_ownership(client_id: ClientId, object_id: &GameObjectId, new_owner: RoomMemberId) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::TransferOwnership(TransferOwnershipCommand {
+			object_id: *object_id,
+			new_owner,
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn set_transfer_ownership_listener(client_id: ClientId, listener: extern "C" fn(&GameObjectId, RoomMemberId)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_transfer_ownership = Some(listener);
+		Ok(())
+	})
+}
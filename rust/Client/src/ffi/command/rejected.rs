@@ -0,0 +1,42 @@
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::rejected::{CommandRejectReason, CommandRejectedCommand};
+use cheetah_common::commands::CommandTypeId;
+use cheetah_common::room::object::GameObjectId;
+
+use crate::clients::registry::ClientId;
+use crate::ffi::execute_with_client;
+
+///
+/// Информация о команде, которую не удалось выполнить на сервере
+///
+#[repr(C)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandRejectedFFI {
+	command_type_id: CommandTypeId,
+	reason: CommandRejectReason,
+	has_object_id: bool,
+	object_id: GameObjectId,
+	has_field_id: bool,
+	field_id: FieldId,
+}
+
+impl From<CommandRejectedCommand> for CommandRejectedFFI {
+	fn from(command: CommandRejectedCommand) -> Self {
+		Self {
+			command_type_id: command.command_type_id,
+			reason: command.reason,
+			has_object_id: command.object_id.is_some(),
+			object_id: command.object_id.unwrap_or_default(),
+			has_field_id: command.field_id.is_some(),
+			field_id: command.field_id.unwrap_or_default(),
+		}
+	}
+}
+
+#[no_mangle]
+pub extern "C" fn set_command_rejected_listener(client_id: ClientId, listener: extern "C" fn(&CommandRejectedFFI)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_command_rejected = Some(listener);
+		Ok(())
+	})
+}
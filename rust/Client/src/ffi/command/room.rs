@@ -1,4 +1,6 @@
 use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::subscription::SubscriptionCommand;
 
 use crate::clients::registry::ClientId;
 use crate::ffi::command::send_command;
@@ -13,3 +15,61 @@ pub extern "C" fn attach_to_room(client_id: ClientId) -> u8 {
 pub extern "C" fn detach_from_room(client_id: ClientId) -> u8 {
 	send_command(client_id, C2SCommand::DetachFromRoom)
 }
+
+///
+/// Получать только объекты указанного шаблона (и других шаблонов, на которые есть подписка)
+///
+#[no_mangle]
+pub extern "C" fn subscribe_template(client_id: ClientId, template: u16) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::Subscribe(SubscriptionCommand {
+			templates: vec![template],
+			fields: vec![],
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn unsubscribe_template(client_id: ClientId, template: u16) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::Unsubscribe(SubscriptionCommand {
+			templates: vec![template],
+			fields: vec![],
+		}),
+	)
+}
+
+///
+/// Получать только изменения указанного поля (и других полей, на которые есть подписка)
+///
+#[no_mangle]
+pub extern "C" fn subscribe_field(client_id: ClientId, field_id: FieldId) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::Subscribe(SubscriptionCommand {
+			templates: vec![],
+			fields: vec![field_id],
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn unsubscribe_field(client_id: ClientId, field_id: FieldId) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::Unsubscribe(SubscriptionCommand {
+			templates: vec![],
+			fields: vec![field_id],
+		}),
+	)
+}
+
+///
+/// Сбросить подписку, получать все доступные объекты и поля
+///
+#[no_mangle]
+pub extern "C" fn reset_subscription(client_id: ClientId) -> u8 {
+	send_command(client_id, C2SCommand::Unsubscribe(SubscriptionCommand::default()))
+}
@@ -0,0 +1,67 @@
+use std::slice;
+
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::types::room_event::{RoomEventCommand, RoomEventId, RoomEventTarget, MAX_ROOM_EVENT_MEMBERS};
+use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::RoomMemberId;
+
+use crate::clients::registry::ClientId;
+use crate::ffi::command::send_command;
+use crate::ffi::{execute_with_client, ClientError};
+
+#[no_mangle]
+pub extern "C" fn set_room_event_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, RoomEventId, &BinaryValue)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_room_event = Some(listener);
+		Ok(())
+	})
+}
+
+///
+/// Отправить событие пользователям с любой из групп `access_groups`
+///
+#[no_mangle]
+pub extern "C" fn send_room_event_to_groups(client_id: ClientId, event_id: RoomEventId, access_groups: u64, event: &BinaryValue) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::RoomEvent(RoomEventCommand {
+			event_id,
+			target: RoomEventTarget::Groups(AccessGroups(access_groups)),
+			event: *event,
+		}),
+	)
+}
+
+///
+/// Отправить событие пользователям из списка, members - массив из `count` идентификаторов, не более 64
+///
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn send_room_event_to_members(
+	client_id: ClientId,
+	event_id: RoomEventId,
+	members: *const RoomMemberId,
+	count: u8,
+	event: &BinaryValue,
+) -> u8 {
+	execute_with_client(client_id, |client| {
+		let count = usize::from(count);
+		if count > MAX_ROOM_EVENT_MEMBERS {
+			return Err(ClientError::RoomEvent(format!("members count more than {MAX_ROOM_EVENT_MEMBERS}")));
+		}
+		if members.is_null() && count > 0 {
+			return Err(ClientError::RoomEvent("null pointer".to_owned()));
+		}
+		let members = if count == 0 {
+			Vec::new()
+		} else {
+			unsafe { slice::from_raw_parts(members, count) }.to_vec()
+		};
+		Ok(client.send(C2SCommand::RoomEvent(RoomEventCommand {
+			event_id,
+			target: RoomEventTarget::Members(members),
+			event: *event,
+		}))?)
+	})
+}
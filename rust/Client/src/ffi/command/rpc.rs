@@ -0,0 +1,48 @@
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::types::rpc::{RpcCallId, RpcResponseCommand, RpcStatus};
+use cheetah_common::room::RoomMemberId;
+
+use crate::clients::registry::ClientId;
+use crate::ffi::command::send_command;
+use crate::ffi::execute_with_client;
+
+#[no_mangle]
+pub extern "C" fn set_rpc_request_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, RpcCallId, &BinaryValue)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_rpc_request = Some(listener);
+		Ok(())
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn set_rpc_response_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, RpcCallId, RpcStatus, &BinaryValue)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_rpc_response = Some(listener);
+		Ok(())
+	})
+}
+
+///
+/// Отправить запрос пользователю, если ответ не получен за `timeout` миллисекунд - сервер отправит ответ с `RpcStatus::Timeout`
+///
+#[no_mangle]
+pub extern "C" fn rpc_request(client_id: ClientId, target: RoomMemberId, timeout: u32, payload: &BinaryValue, result: &mut RpcCallId) -> u8 {
+	execute_with_client(client_id, |client| {
+		*result = client.rpc_request(target, timeout, *payload)?;
+		Ok(())
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn rpc_response(client_id: ClientId, caller: RoomMemberId, call_id: RpcCallId, status: RpcStatus, payload: &BinaryValue) -> u8 {
+	send_command(
+		client_id,
+		C2SCommand::RpcResponse(RpcResponseCommand {
+			member_id: caller,
+			call_id,
+			status,
+			payload: *payload,
+		}),
+	)
+}
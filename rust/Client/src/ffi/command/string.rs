@@ -0,0 +1,51 @@
+use widestring::{U16CStr, U16CString};
+
+use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::field::SetFieldCommand;
+use cheetah_common::commands::types::string::MAX_STRING_SIZE;
+use cheetah_common::commands::FieldValue;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::clients::registry::ClientId;
+use crate::ffi::{execute_with_client, ClientError};
+
+///
+/// Строка передается в обработчик в UTF-16 с завершающим нулем
+///
+#[no_mangle]
+pub extern "C" fn set_string_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, *const u16, u64)) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.listener_string = Some(listener);
+		Ok(())
+	})
+}
+
+///
+/// value - строка в UTF-16 с завершающим нулем, размер в UTF-8 не более 255 байт
+///
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn set_string(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, value: *const u16) -> u8 {
+	execute_with_client(client_id, |client| {
+		if value.is_null() {
+			return Err(ClientError::InvalidString("null pointer".to_owned()));
+		}
+		let value = unsafe { U16CStr::from_ptr_str(value) }
+			.to_string()
+			.map_err(|e| ClientError::InvalidString(format!("{e:?}")))?;
+		if value.len() > MAX_STRING_SIZE {
+			return Err(ClientError::InvalidString(format!("size {} more than {MAX_STRING_SIZE}", value.len())));
+		}
+		Ok(client.send(C2SCommand::SetField(SetFieldCommand {
+			object_id: *object_id,
+			field_id,
+			value: FieldValue::String(value),
+		}))?)
+	})
+}
+
+pub(crate) fn to_utf16(value: &str) -> U16CString {
+	U16CString::from_str_truncate(value)
+}
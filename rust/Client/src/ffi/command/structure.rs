@@ -13,7 +13,10 @@ use crate::ffi::execute_with_client;
 
 #[no_mangle]
 #[allow(unused_must_use)]
-pub extern "C" fn set_structure_listener(client_id: ClientId, listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, &BinaryValue)) -> u8 {
+pub extern "C" fn set_structure_listener(
+	client_id: ClientId,
+	listener: extern "C" fn(RoomMemberId, &GameObjectId, FieldId, &BinaryValue, u64),
+) -> u8 {
 	execute_with_client(client_id, |client| {
 		client.listener_structure = Some(listener);
 		Ok(())
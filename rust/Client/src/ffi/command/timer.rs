@@ -0,0 +1,143 @@
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::delete::DeleteGameObjectCommand;
+use cheetah_common::commands::types::event::EventCommand;
+use cheetah_common::commands::types::field::SetFieldCommand;
+use cheetah_common::commands::types::float::IncrementDoubleC2SCommand;
+use cheetah_common::commands::types::long::IncrementLongC2SCommand;
+use cheetah_common::commands::types::timer::{CancelTimerCommand, ScheduleTimerCommand, TimerAction, TimerId, TimerOwner, TimerTime};
+use cheetah_common::commands::types::transaction::TransactionMutation;
+use cheetah_common::commands::FieldValue;
+use cheetah_common::room::object::GameObjectId;
+
+use crate::clients::registry::ClientId;
+use crate::ffi::command::send_command;
+
+///
+/// Способ задания времени срабатывания таймера
+///
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TimerTimeKind {
+	///
+	/// Задержка в миллисекундах относительно времени получения команды сервером
+	///
+	Delay = 0,
+	///
+	/// Время сервера в миллисекундах от создания комнаты
+	///
+	ServerTime,
+}
+
+///
+/// Параметры таймера
+///
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct TimerFFI {
+	pub timer_id: TimerId,
+	pub time: u64,
+	pub time_kind: TimerTimeKind,
+	pub owner: TimerOwner,
+}
+
+#[no_mangle]
+pub extern "C" fn schedule_set_long_value(client_id: ClientId, timer: &TimerFFI, object_id: &GameObjectId, field_id: FieldId, value: i64) -> u8 {
+	schedule_set_field(client_id, timer, *object_id, field_id, value.into())
+}
+
+#[no_mangle]
+pub extern "C" fn schedule_set_double_value(client_id: ClientId, timer: &TimerFFI, object_id: &GameObjectId, field_id: FieldId, value: f64) -> u8 {
+	schedule_set_field(client_id, timer, *object_id, field_id, value.into())
+}
+
+#[no_mangle]
+pub extern "C" fn schedule_set_structure(
+	client_id: ClientId,
+	timer: &TimerFFI,
+	object_id: &GameObjectId,
+	field_id: FieldId,
+	value: &BinaryValue,
+) -> u8 {
+	schedule_set_field(client_id, timer, *object_id, field_id, (*value).into())
+}
+
+#[no_mangle]
+pub extern "C" fn schedule_inc_long_value(client_id: ClientId, timer: &TimerFFI, object_id: &GameObjectId, field_id: FieldId, increment: i64) -> u8 {
+	schedule(
+		client_id,
+		timer,
+		TimerAction::Mutation(TransactionMutation::IncrementLong(IncrementLongC2SCommand {
+			object_id: *object_id,
+			field_id,
+			increment,
+		})),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn schedule_inc_double_value(
+	client_id: ClientId,
+	timer: &TimerFFI,
+	object_id: &GameObjectId,
+	field_id: FieldId,
+	increment: f64,
+) -> u8 {
+	schedule(
+		client_id,
+		timer,
+		TimerAction::Mutation(TransactionMutation::IncrementDouble(IncrementDoubleC2SCommand {
+			object_id: *object_id,
+			field_id,
+			increment,
+		})),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn schedule_event(client_id: ClientId, timer: &TimerFFI, object_id: &GameObjectId, field_id: FieldId, event: &BinaryValue) -> u8 {
+	schedule(
+		client_id,
+		timer,
+		TimerAction::Event(EventCommand {
+			object_id: *object_id,
+			field_id,
+			event: *event,
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn schedule_delete_object(client_id: ClientId, timer: &TimerFFI, object_id: &GameObjectId) -> u8 {
+	schedule(client_id, timer, TimerAction::Delete(DeleteGameObjectCommand { object_id: *object_id }))
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_timer(client_id: ClientId, timer_id: TimerId) -> u8 {
+	send_command(client_id, C2SCommand::CancelTimer(CancelTimerCommand { timer_id }))
+}
+
+fn schedule_set_field(client_id: ClientId, timer: &TimerFFI, object_id: GameObjectId, field_id: FieldId, value: FieldValue) -> u8 {
+	schedule(
+		client_id,
+		timer,
+		TimerAction::Mutation(TransactionMutation::SetField(SetFieldCommand { object_id, field_id, value })),
+	)
+}
+
+fn schedule(client_id: ClientId, timer: &TimerFFI, action: TimerAction) -> u8 {
+	let time = match timer.time_kind {
+		TimerTimeKind::Delay => TimerTime::Delay(timer.time),
+		TimerTimeKind::ServerTime => TimerTime::ServerTime(timer.time),
+	};
+	send_command(
+		client_id,
+		C2SCommand::ScheduleTimer(ScheduleTimerCommand {
+			timer_id: timer.timer_id,
+			time,
+			owner: timer.owner,
+			action,
+		}),
+	)
+}
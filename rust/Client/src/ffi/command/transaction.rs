@@ -0,0 +1,174 @@
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::field::{DeleteFieldCommand, SetFieldCommand};
+use cheetah_common::commands::types::float::IncrementDoubleC2SCommand;
+use cheetah_common::commands::types::long::IncrementLongC2SCommand;
+use cheetah_common::commands::types::transaction::{
+	PreconditionKind, TransactionCommand, TransactionMutation, TransactionPrecondition, MAX_TRANSACTION_ENCODED_SIZE, MAX_TRANSACTION_MUTATIONS,
+	MAX_TRANSACTION_PRECONDITIONS,
+};
+use cheetah_common::commands::{FieldType, FieldValue};
+use cheetah_common::room::object::GameObjectId;
+
+use crate::clients::application_thread::ApplicationThreadClient;
+use crate::clients::registry::ClientId;
+use crate::ffi::{execute_with_client, ClientError};
+
+///
+/// Начать формирование транзакции, предыдущая незавершенная транзакция отменяется
+///
+#[no_mangle]
+pub extern "C" fn transaction_begin(client_id: ClientId) -> u8 {
+	execute_with_client(client_id, |client| {
+		client.transaction = Some(TransactionCommand::default());
+		Ok(())
+	})
+}
+
+///
+/// Отправить транзакцию на сервер
+///
+#[no_mangle]
+pub extern "C" fn transaction_commit(client_id: ClientId) -> u8 {
+	execute_with_client(client_id, |client| {
+		let transaction = client.transaction.take().ok_or_else(not_started)?;
+		Ok(client.send(C2SCommand::Transaction(transaction))?)
+	})
+}
+
+#[no_mangle]
+pub extern "C" fn transaction_precondition_long(
+	client_id: ClientId,
+	object_id: &GameObjectId,
+	field_id: FieldId,
+	kind: PreconditionKind,
+	value: i64,
+) -> u8 {
+	add_precondition(client_id, *object_id, field_id, kind, value.into())
+}
+
+#[no_mangle]
+pub extern "C" fn transaction_precondition_double(
+	client_id: ClientId,
+	object_id: &GameObjectId,
+	field_id: FieldId,
+	kind: PreconditionKind,
+	value: f64,
+) -> u8 {
+	add_precondition(client_id, *object_id, field_id, kind, value.into())
+}
+
+///
+/// Для структур поддерживается только проверка на равенство
+///
+#[no_mangle]
+pub extern "C" fn transaction_precondition_structure(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, value: &BinaryValue) -> u8 {
+	add_precondition(client_id, *object_id, field_id, PreconditionKind::Equal, (*value).into())
+}
+
+#[no_mangle]
+pub extern "C" fn transaction_set_long(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, value: i64) -> u8 {
+	add_set_field(client_id, *object_id, field_id, value.into())
+}
+
+#[no_mangle]
+pub extern "C" fn transaction_set_double(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, value: f64) -> u8 {
+	add_set_field(client_id, *object_id, field_id, value.into())
+}
+
+#[no_mangle]
+pub extern "C" fn transaction_set_structure(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, value: &BinaryValue) -> u8 {
+	add_set_field(client_id, *object_id, field_id, (*value).into())
+}
+
+#[no_mangle]
+pub extern "C" fn transaction_inc_long(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, increment: i64) -> u8 {
+	add_mutation(
+		client_id,
+		TransactionMutation::IncrementLong(IncrementLongC2SCommand {
+			object_id: *object_id,
+			field_id,
+			increment,
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn transaction_inc_double(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, increment: f64) -> u8 {
+	add_mutation(
+		client_id,
+		TransactionMutation::IncrementDouble(IncrementDoubleC2SCommand {
+			object_id: *object_id,
+			field_id,
+			increment,
+		}),
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn transaction_delete_field(client_id: ClientId, object_id: &GameObjectId, field_id: FieldId, field_type: FieldType) -> u8 {
+	add_mutation(
+		client_id,
+		TransactionMutation::DeleteField(DeleteFieldCommand {
+			field_id,
+			object_id: *object_id,
+			field_type,
+		}),
+	)
+}
+
+fn add_precondition(client_id: ClientId, object_id: GameObjectId, field_id: FieldId, kind: PreconditionKind, value: FieldValue) -> u8 {
+	execute_with_client(client_id, |client| {
+		let transaction = get_transaction(client)?;
+		if transaction.preconditions.len() >= MAX_TRANSACTION_PRECONDITIONS {
+			return Err(ClientError::Transaction(format!(
+				"preconditions count more than {MAX_TRANSACTION_PRECONDITIONS}"
+			)));
+		}
+		transaction.preconditions.push(TransactionPrecondition {
+			object_id,
+			field_id,
+			kind,
+			value,
+		});
+		// транзакция должна поместиться в один фрейм
+		if transaction.encoded_size().is_none() {
+			transaction.preconditions.pop();
+			return Err(too_big());
+		}
+		Ok(())
+	})
+}
+
+fn add_set_field(client_id: ClientId, object_id: GameObjectId, field_id: FieldId, value: FieldValue) -> u8 {
+	add_mutation(client_id, TransactionMutation::SetField(SetFieldCommand { object_id, field_id, value }))
+}
+
+fn add_mutation(client_id: ClientId, mutation: TransactionMutation) -> u8 {
+	execute_with_client(client_id, |client| {
+		let transaction = get_transaction(client)?;
+		if transaction.mutations.len() >= MAX_TRANSACTION_MUTATIONS {
+			return Err(ClientError::Transaction(format!("mutations count more than {MAX_TRANSACTION_MUTATIONS}")));
+		}
+		transaction.mutations.push(mutation);
+		// транзакция должна поместиться в один фрейм
+		if transaction.encoded_size().is_none() {
+			transaction.mutations.pop();
+			return Err(too_big());
+		}
+		Ok(())
+	})
+}
+
+fn get_transaction(client: &mut ApplicationThreadClient) -> Result<&mut TransactionCommand, ClientError> {
+	client.transaction.as_mut().ok_or_else(not_started)
+}
+
+fn not_started() -> ClientError {
+	ClientError::Transaction("transaction not started".to_owned())
+}
+
+fn too_big() -> ClientError {
+	ClientError::Transaction(format!("transaction size more than {MAX_TRANSACTION_ENCODED_SIZE} bytes"))
+}
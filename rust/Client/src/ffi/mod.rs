@@ -9,6 +9,7 @@ use cheetah_common::commands::binary_value::BinaryValue;
 use cheetah_common::commands::c2s::C2SCommand;
 use cheetah_common::commands::field::FieldId;
 use cheetah_common::commands::types::forwarded::ForwardedCommand;
+use cheetah_common::commands::types::map::{MapKey, MapValue};
 use cheetah_common::commands::{CommandTypeId, FieldType, FieldValue};
 use cheetah_common::constants::GameObjectTemplateId;
 use cheetah_common::room::object::GameObjectId;
@@ -36,6 +37,14 @@ pub enum ClientError {
 	ConnectionStatusMutexError(String),
 	#[error("Send task error {0}")]
 	SendTaskError(#[from] SendError<ClientRequest>),
+	#[error("Invalid string {0}")]
+	InvalidString(String),
+	#[error("Transaction error {0}")]
+	Transaction(String),
+	#[error("Room event error {0}")]
+	RoomEvent(String),
+	#[error("Forwarded command error {0}")]
+	ForwardedCommand(String),
 }
 
 impl ClientError {
@@ -50,6 +59,10 @@ impl ClientError {
 			ClientError::ConnectionStatusMutexError { .. } => 3,
 			ClientError::SendTaskError { .. } => 4,
 			ClientError::CreateClientError(_) => 5,
+			ClientError::InvalidString(_) => 6,
+			ClientError::Transaction(_) => 7,
+			ClientError::RoomEvent(_) => 8,
+			ClientError::ForwardedCommand(_) => 9,
 		}
 	}
 }
@@ -99,10 +112,13 @@ pub struct ForwardedCommandFFI {
 	long_value_old: i64,
 	long_value_new: i64,
 	long_value_reset: i64,
+	float_value_old: f64,
 	float_value_new: f64,
+	float_value_reset: f64,
 	binary_value_old: BinaryValue,
 	binary_value_new: BinaryValue,
 	binary_value_reset: BinaryValue,
+	map_key: MapKey,
 }
 
 impl Default for ForwardedCommandFFI {
@@ -118,16 +134,24 @@ impl Default for ForwardedCommandFFI {
 			long_value_old: 0,
 			long_value_new: 0,
 			long_value_reset: 0,
+			float_value_old: 0.0,
 			float_value_new: 0.0,
+			float_value_reset: 0.0,
 			binary_value_old: Default::default(),
 			binary_value_new: Default::default(),
 			binary_value_reset: Default::default(),
+			map_key: 0,
 		}
 	}
 }
 
-impl From<ForwardedCommand> for ForwardedCommandFFI {
-	fn from(c: ForwardedCommand) -> Self {
+///
+/// Значения полей типа список, словарь и строка не передаются через [`ForwardedCommandFFI`], такие команды не преобразуются
+///
+impl TryFrom<ForwardedCommand> for ForwardedCommandFFI {
+	type Error = ClientError;
+
+	fn try_from(c: ForwardedCommand) -> Result<Self, ClientError> {
 		let mut ffi_command = ForwardedCommandFFI {
 			command_type_id: c.c2s.get_type_id(),
 			creator: c.creator,
@@ -150,6 +174,11 @@ impl From<ForwardedCommand> for ForwardedCommandFFI {
 				ffi_command.long_value_new = c.new;
 				ffi_command.long_value_reset = c.get_reset().unwrap_or_default();
 			}
+			C2SCommand::CompareAndSetDouble(c) => {
+				ffi_command.float_value_old = c.current;
+				ffi_command.float_value_new = c.new;
+				ffi_command.float_value_reset = c.get_reset().unwrap_or_default();
+			}
 			C2SCommand::SetField(c) => match c.value {
 				FieldValue::Long(v) => {
 					ffi_command.long_value_new = v;
@@ -160,10 +189,23 @@ impl From<ForwardedCommand> for ForwardedCommandFFI {
 				FieldValue::Structure(v) => {
 					ffi_command.binary_value_new = v;
 				}
+				value @ (FieldValue::List(_) | FieldValue::Map(_) | FieldValue::String(_)) => {
+					return Err(ClientError::ForwardedCommand(format!(
+						"unsupported value type {:?} for field {}",
+						value.field_type(),
+						ffi_command.field_id
+					)));
+				}
 			},
 			C2SCommand::IncrementDouble(c) => {
 				ffi_command.float_value_new = c.increment;
 			}
+			C2SCommand::IncrementLongWithBounds(c) => {
+				ffi_command.long_value_new = c.increment;
+			}
+			C2SCommand::IncrementDoubleWithBounds(c) => {
+				ffi_command.float_value_new = c.increment;
+			}
 			C2SCommand::CompareAndSetStructure(c) => {
 				ffi_command.binary_value_old = c.current;
 				ffi_command.binary_value_new = c.new;
@@ -180,10 +222,86 @@ impl From<ForwardedCommand> for ForwardedCommandFFI {
 			C2SCommand::DeleteField(_) => {}
 			C2SCommand::AttachToRoom => {}
 			C2SCommand::DetachFromRoom => {}
+			#[allow(clippy::cast_possible_wrap)]
+			C2SCommand::LockstepInput(c) => {
+				ffi_command.long_value_new = c.tick as i64;
+				ffi_command.binary_value_new = c.input;
+			}
+			#[allow(clippy::cast_possible_wrap)]
+			C2SCommand::LockstepHistory(c) => {
+				ffi_command.long_value_new = c.from_tick as i64;
+			}
+			C2SCommand::ListPush(c) => {
+				ffi_command.binary_value_new = c.value;
+			}
+			C2SCommand::ListRemove(c) => {
+				ffi_command.long_value_new = i64::from(c.index);
+			}
+			C2SCommand::ListRemoveValue(c) => {
+				ffi_command.binary_value_new = c.value;
+			}
+			C2SCommand::ListSetItem(c) => {
+				ffi_command.long_value_new = i64::from(c.index);
+				ffi_command.binary_value_new = c.value;
+			}
+			C2SCommand::ListClear(_) => {}
+			C2SCommand::MapSet(c) => {
+				ffi_command.map_key = c.key;
+				ffi_command.set_map_value(&c.value);
+			}
+			C2SCommand::MapDelete(c) => {
+				ffi_command.map_key = c.key;
+			}
+			C2SCommand::MapIncrement(c) => {
+				ffi_command.map_key = c.key;
+				ffi_command.set_map_value(&c.increment);
+			}
+			C2SCommand::Transaction(_)
+			| C2SCommand::ScheduleTimer(_)
+			| C2SCommand::CancelTimer(_)
+			| C2SCommand::Subscribe(_)
+			| C2SCommand::Unsubscribe(_) => {}
+			C2SCommand::RpcRequest(c) => {
+				ffi_command.target = c.member_id;
+				ffi_command.binary_value_new = c.payload;
+			}
+			C2SCommand::RpcResponse(c) => {
+				ffi_command.target = c.member_id;
+				ffi_command.binary_value_new = c.payload;
+			}
+			C2SCommand::RoomEvent(c) => {
+				ffi_command.long_value_new = i64::from(c.event_id);
+				ffi_command.binary_value_new = c.event;
+			}
+			#[allow(clippy::cast_possible_wrap)]
+			C2SCommand::FieldHistory(c) => {
+				ffi_command.long_value_new = c.time as i64;
+			}
+			#[allow(clippy::cast_possible_wrap)]
+			C2SCommand::SetObjectTtl(c) => {
+				ffi_command.long_value_new = c.ttl as i64;
+			}
+			C2SCommand::TransferOwnership(c) => {
+				ffi_command.target = c.new_owner;
+			}
+			#[allow(clippy::cast_possible_wrap)]
+			C2SCommand::SetObjectAccessGroups(c) => {
+				ffi_command.long_value_new = c.access_groups.0 as i64;
+			}
 			C2SCommand::Forwarded(_) => panic!("received invalid nested ForwardedCommand"),
 		};
 
-		ffi_command
+		Ok(ffi_command)
+	}
+}
+
+impl ForwardedCommandFFI {
+	fn set_map_value(&mut self, value: &MapValue) {
+		match value {
+			MapValue::Long(v) => self.long_value_new = *v,
+			MapValue::Double(v) => self.float_value_new = *v,
+			MapValue::Binary(v) => self.binary_value_new = *v,
+		}
 	}
 }
 
@@ -204,7 +322,7 @@ mod tests {
 	use cheetah_common::room::object::GameObjectId;
 	use cheetah_common::room::owner::GameObjectOwner;
 
-	use crate::ffi::ForwardedCommandFFI;
+	use crate::ffi::{ClientError, ForwardedCommandFFI};
 
 	#[test]
 	fn should_convert_forwarded_to_ffi() {
@@ -246,6 +364,8 @@ mod tests {
 						object_id,
 						template: 1,
 						access_groups: AccessGroups::super_group(),
+						parent: None,
+						inherit_access_groups: false,
 					}),
 				},
 				ForwardedCommandFFI {
@@ -478,14 +598,27 @@ mod tests {
 		];
 
 		for (from, want) in tests {
-			assert_eq!(want, from.into());
+			assert_eq!(want, from.try_into().unwrap());
 		}
 	}
 
+	#[test]
+	fn should_not_convert_forwarded_string_value() {
+		let result = ForwardedCommandFFI::try_from(ForwardedCommand {
+			creator: 0,
+			c2s: C2SCommand::SetField(SetFieldCommand {
+				object_id: GameObjectId::new(1, GameObjectOwner::Room),
+				field_id: 1,
+				value: FieldValue::String("value".into()),
+			}),
+		});
+		assert!(matches!(result, Err(ClientError::ForwardedCommand(_))));
+	}
+
 	#[test]
 	#[should_panic(expected = "received invalid nested ForwardedCommand")]
 	fn should_panic_on_nested_forwarded_command() {
-		let _ = ForwardedCommandFFI::from(ForwardedCommand {
+		let _ = ForwardedCommandFFI::try_from(ForwardedCommand {
 			creator: 0,
 			c2s: C2SCommand::Forwarded(Box::new(ForwardedCommand {
 				creator: 0,
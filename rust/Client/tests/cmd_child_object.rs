@@ -0,0 +1,48 @@
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use cheetah_client::ffi;
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::room::object::GameObjectId;
+
+use crate::helpers::helper::setup;
+use crate::helpers::server::IntegrationTestServerBuilder;
+
+pub mod helpers;
+
+///
+/// Дочерний объект удаляется вместе с родительским
+///
+#[test]
+fn should_delete_child_with_parent() {
+	let (helper, [client1, client2]) = setup(Default::default());
+
+	ffi::command::object::set_delete_object_listener(client2, on_object_delete);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	let access_group = IntegrationTestServerBuilder::DEFAULT_ACCESS_GROUP.0;
+	let mut parent_id = GameObjectId::default();
+	ffi::command::object::create_object(client1, 1, access_group, &mut parent_id);
+	ffi::command::object::created_object(client1, &parent_id, false, &BinaryValue::default());
+
+	let mut child_id = GameObjectId::default();
+	ffi::command::object::create_child_object(client1, 2, access_group, &parent_id, true, &mut child_id);
+	ffi::command::object::created_object(client1, &child_id, false, &BinaryValue::default());
+
+	ffi::command::object::delete_object(client1, &parent_id);
+
+	helper.wait_udp();
+	ffi::client::receive(client2);
+
+	assert_eq!(*DELETED_OBJECT_IDS.lock().unwrap(), vec![child_id, parent_id]);
+}
+
+lazy_static! {
+	static ref DELETED_OBJECT_IDS: Mutex<Vec<GameObjectId>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_object_delete(object_id: &GameObjectId) {
+	DELETED_OBJECT_IDS.lock().unwrap().push(*object_id);
+}
@@ -58,6 +58,6 @@ extern "C" fn delete_listener(_: RoomMemberId, _object_id: &GameObjectId, field_
 	DELETED_FIELD.lock().unwrap().replace((field_id, field_type));
 }
 
-extern "C" fn set_listener<T>(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, _: T) {
+extern "C" fn set_listener<T>(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, _: T, _: u64) {
 	SET_FIELDS.lock().unwrap().push(field_id);
 }
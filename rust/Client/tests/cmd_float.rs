@@ -53,10 +53,10 @@ lazy_static! {
 	static ref SET: Mutex<Option<(FieldId, f64)>> = Mutex::new(Default::default());
 }
 
-extern "C" fn listener_for_set(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: f64) {
+extern "C" fn listener_for_set(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: f64, _: u64) {
 	SET.lock().unwrap().replace((field_id, value));
 }
 
-extern "C" fn listener_for_inc(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: f64) {
+extern "C" fn listener_for_inc(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: f64, _: u64) {
 	INCR.lock().unwrap().replace((field_id, value));
 }
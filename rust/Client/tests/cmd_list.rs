@@ -0,0 +1,60 @@
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use cheetah_client::ffi;
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::list::ListIndex;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::helpers::helper::setup;
+
+pub mod helpers;
+
+#[test]
+fn should_change_list() {
+	let (helper, [client1, client2]) = setup(Default::default());
+
+	ffi::command::list::set_list_push_listener(client2, on_list_push);
+	ffi::command::list::set_list_remove_listener(client2, on_list_remove);
+	ffi::command::list::set_list_set_item_listener(client2, on_list_set_item);
+	ffi::command::list::set_list_clear_listener(client2, on_list_clear);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	let object_id = helper.create_member_object(client1);
+	let field_id = 10;
+	for value in [1, 2, 3] {
+		ffi::command::list::list_push(client1, &object_id, field_id, &vec![value].as_slice().into());
+	}
+	ffi::command::list::list_set_item(client1, &object_id, field_id, 0, &vec![10].as_slice().into());
+	ffi::command::list::list_remove_value(client1, &object_id, field_id, &vec![2].as_slice().into());
+
+	helper.wait_udp();
+	ffi::client::receive(client2);
+
+	let expected: Vec<BinaryValue> = vec![vec![10].as_slice().into(), vec![3].as_slice().into()];
+	assert_eq!(*LIST.lock().unwrap(), expected);
+}
+
+lazy_static! {
+	static ref LIST: Mutex<Vec<BinaryValue>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_list_push(_: RoomMemberId, _object_id: &GameObjectId, _field_id: FieldId, value: &BinaryValue, _: u64) {
+	LIST.lock().unwrap().push(*value);
+}
+
+extern "C" fn on_list_remove(_: RoomMemberId, _object_id: &GameObjectId, _field_id: FieldId, index: ListIndex, _: u64) {
+	LIST.lock().unwrap().remove(usize::from(index));
+}
+
+extern "C" fn on_list_set_item(_: RoomMemberId, _object_id: &GameObjectId, _field_id: FieldId, index: ListIndex, value: &BinaryValue, _: u64) {
+	LIST.lock().unwrap()[usize::from(index)] = *value;
+}
+
+extern "C" fn on_list_clear(_: RoomMemberId, _object_id: &GameObjectId, _field_id: FieldId, _: u64) {
+	LIST.lock().unwrap().clear();
+}
@@ -109,14 +109,14 @@ lazy_static! {
 	static ref COMPARE_AND_SET: Mutex<HashMap<FieldId, i64>> = Mutex::new(Default::default());
 }
 
-extern "C" fn listener_for_set(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64) {
+extern "C" fn listener_for_set(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64, _: u64) {
 	SET.lock().unwrap().replace((field_id, value));
 }
 
-extern "C" fn listener_for_inc(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64) {
+extern "C" fn listener_for_inc(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64, _: u64) {
 	INCR.lock().unwrap().replace((field_id, value));
 }
 
-extern "C" fn listener_for_compare_and_set(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64) {
+extern "C" fn listener_for_compare_and_set(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64, _: u64) {
 	COMPARE_AND_SET.lock().unwrap().insert(field_id, value);
 }
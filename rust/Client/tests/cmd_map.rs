@@ -0,0 +1,50 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use cheetah_client::ffi;
+use cheetah_client::ffi::command::map::MapValueFFI;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::map::MapKey;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::helpers::helper::setup;
+
+pub mod helpers;
+
+#[test]
+fn should_change_map() {
+	let (helper, [client1, client2]) = setup(Default::default());
+
+	ffi::command::map::set_map_set_listener(client2, on_map_set);
+	ffi::command::map::set_map_delete_listener(client2, on_map_delete);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	let object_id = helper.create_member_object(client1);
+	let field_id = 10;
+	ffi::command::map::map_set_long(client1, &object_id, field_id, 1, 100);
+	ffi::command::map::map_set_long(client1, &object_id, field_id, 2, 200);
+	ffi::command::map::map_increment_long(client1, &object_id, field_id, 1, 5);
+	ffi::command::map::map_delete(client1, &object_id, field_id, 2);
+
+	helper.wait_udp();
+	ffi::client::receive(client2);
+
+	let expected: BTreeMap<MapKey, i64> = [(1, 105)].into_iter().collect();
+	assert_eq!(*MAP.lock().unwrap(), expected);
+}
+
+lazy_static! {
+	static ref MAP: Mutex<BTreeMap<MapKey, i64>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_map_set(_: RoomMemberId, _object_id: &GameObjectId, _field_id: FieldId, key: MapKey, value: &MapValueFFI, _: u64) {
+	MAP.lock().unwrap().insert(key, value.long_value);
+}
+
+extern "C" fn on_map_delete(_: RoomMemberId, _object_id: &GameObjectId, _field_id: FieldId, key: MapKey, _: u64) {
+	MAP.lock().unwrap().remove(&key);
+}
@@ -73,6 +73,6 @@ extern "C" fn on_object_created(object_id: &GameObjectId) {
 extern "C" fn on_object_delete(object_id: &GameObjectId) {
 	DELETED_OBJECT_ID.lock().unwrap().replace((*object_id).clone());
 }
-extern "C" fn on_structure_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, buffer: &BinaryValue) {
+extern "C" fn on_structure_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, buffer: &BinaryValue, _: u64) {
 	STRUCTURE.lock().unwrap().replace((field_id, (*buffer).clone()));
 }
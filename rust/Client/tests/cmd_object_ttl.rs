@@ -0,0 +1,42 @@
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use lazy_static::lazy_static;
+
+use cheetah_client::ffi;
+use cheetah_common::room::object::GameObjectId;
+
+use crate::helpers::helper::setup;
+
+pub mod helpers;
+
+///
+/// Объект удаляется сервером по истечении времени жизни
+///
+#[test]
+fn should_delete_object_when_ttl_expired() {
+	let (helper, [client1, client2]) = setup(Default::default());
+
+	ffi::command::object::set_delete_object_listener(client2, on_object_delete);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	let object_id = helper.create_member_object(client1);
+	ffi::command::object::set_object_ttl(client1, &object_id, 50);
+
+	helper.wait_udp();
+	thread::sleep(Duration::from_millis(100));
+	helper.wait_udp();
+	ffi::client::receive(client2);
+
+	assert!(matches!(DELETED_OBJECT_ID.lock().unwrap().as_ref(), Some(id) if *id == object_id));
+}
+
+lazy_static! {
+	static ref DELETED_OBJECT_ID: Mutex<Option<GameObjectId>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_object_delete(object_id: &GameObjectId) {
+	DELETED_OBJECT_ID.lock().unwrap().replace(*object_id);
+}
@@ -0,0 +1,43 @@
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use cheetah_client::ffi;
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::types::room_event::RoomEventId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::helpers::helper::IntegrationTestHelper;
+
+pub mod helpers;
+
+///
+/// Событие комнаты доставляется пользователям из списка без создания объекта
+///
+#[test]
+fn should_send_room_event_to_members() {
+	let mut helper = IntegrationTestHelper::new(Default::default());
+	let (member1, private_key1) = helper.create_member();
+	let (member2, private_key2) = helper.create_member();
+	let client1 = helper.create_client(member1, &private_key1);
+	let client2 = helper.create_client(member2, &private_key2);
+
+	ffi::command::room::attach_to_room(client1);
+	ffi::command::room_event::set_room_event_listener(client2, on_room_event);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	let members = [member2];
+	ffi::command::room_event::send_room_event_to_members(client1, 7, members.as_ptr(), 1, &BinaryValue::from([1, 2, 3].as_ref()));
+	helper.wait_udp();
+	ffi::client::receive(client2);
+	assert_eq!(*ROOM_EVENT.lock().unwrap(), Some((member1, 7, BinaryValue::from([1, 2, 3].as_ref()))));
+}
+
+lazy_static! {
+	static ref ROOM_EVENT: Mutex<Option<(RoomMemberId, RoomEventId, BinaryValue)>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_room_event(member_id: RoomMemberId, event_id: RoomEventId, event: &BinaryValue) {
+	ROOM_EVENT.lock().unwrap().replace((member_id, event_id, *event));
+}
@@ -0,0 +1,85 @@
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use cheetah_client::ffi;
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::types::rpc::{RpcCallId, RpcStatus};
+use cheetah_common::room::RoomMemberId;
+
+use crate::helpers::helper::IntegrationTestHelper;
+
+pub mod helpers;
+
+///
+/// Запрос доставляется вызываемому пользователю, ответ - вызывающему
+///
+#[test]
+fn should_route_rpc_request_and_response() {
+	let mut helper = IntegrationTestHelper::new(Default::default());
+	let (member1, private_key1) = helper.create_member();
+	let (member2, private_key2) = helper.create_member();
+	let client1 = helper.create_client(member1, &private_key1);
+	let client2 = helper.create_client(member2, &private_key2);
+
+	ffi::command::rpc::set_rpc_response_listener(client1, on_response);
+	ffi::command::room::attach_to_room(client1);
+	ffi::command::rpc::set_rpc_request_listener(client2, on_request);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	let mut call_id = 0;
+	ffi::command::rpc::rpc_request(client1, member2, 5000, &BinaryValue::from([1, 2, 3].as_ref()), &mut call_id);
+	helper.wait_udp();
+	ffi::client::receive(client2);
+	assert_eq!(*REQUEST.lock().unwrap(), Some((member1, call_id, BinaryValue::from([1, 2, 3].as_ref()))));
+
+	ffi::command::rpc::rpc_response(client2, member1, call_id, RpcStatus::Ok, &BinaryValue::from([4, 5].as_ref()));
+	helper.wait_udp();
+	ffi::client::receive(client1);
+	assert_eq!(
+		*RESPONSE.lock().unwrap(),
+		Some((member2, call_id, RpcStatus::Ok, BinaryValue::from([4, 5].as_ref())))
+	);
+}
+
+///
+/// Вызывающий пользователь получает ошибку, если вызываемый не ответил за время ожидания
+///
+#[test]
+fn should_receive_rpc_timeout() {
+	let mut helper = IntegrationTestHelper::new(Default::default());
+	let (member1, private_key1) = helper.create_member();
+	let (member2, private_key2) = helper.create_member();
+	let client1 = helper.create_client(member1, &private_key1);
+	let client2 = helper.create_client(member2, &private_key2);
+
+	ffi::command::rpc::set_rpc_response_listener(client1, on_timeout_response);
+	ffi::command::room::attach_to_room(client1);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	let mut call_id = 0;
+	ffi::command::rpc::rpc_request(client1, member2, 0, &BinaryValue::default(), &mut call_id);
+	helper.wait_udp();
+	ffi::client::receive(client1);
+	assert_eq!(*TIMEOUT_RESPONSE.lock().unwrap(), Some((member2, call_id, RpcStatus::Timeout)));
+}
+
+lazy_static! {
+	static ref REQUEST: Mutex<Option<(RoomMemberId, RpcCallId, BinaryValue)>> = Mutex::new(Default::default());
+	static ref RESPONSE: Mutex<Option<(RoomMemberId, RpcCallId, RpcStatus, BinaryValue)>> = Mutex::new(Default::default());
+	static ref TIMEOUT_RESPONSE: Mutex<Option<(RoomMemberId, RpcCallId, RpcStatus)>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_request(caller: RoomMemberId, call_id: RpcCallId, payload: &BinaryValue) {
+	REQUEST.lock().unwrap().replace((caller, call_id, *payload));
+}
+
+extern "C" fn on_response(target: RoomMemberId, call_id: RpcCallId, status: RpcStatus, payload: &BinaryValue) {
+	RESPONSE.lock().unwrap().replace((target, call_id, status, *payload));
+}
+
+extern "C" fn on_timeout_response(target: RoomMemberId, call_id: RpcCallId, status: RpcStatus, _: &BinaryValue) {
+	TIMEOUT_RESPONSE.lock().unwrap().replace((target, call_id, status));
+}
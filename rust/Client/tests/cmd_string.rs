@@ -0,0 +1,48 @@
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+use widestring::{U16CStr, U16CString};
+
+use cheetah_client::ffi;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::helpers::helper::setup;
+
+pub mod helpers;
+
+#[test]
+fn should_set() {
+	let (helper, [client1, client2]) = setup(Default::default());
+
+	ffi::command::string::set_string_listener(client2, on_string_listener);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	let object_id = helper.create_member_object(client1);
+	let value = U16CString::from_str("Игрок 🚀").unwrap();
+	ffi::command::string::set_string(client1, &object_id, 10, value.as_ptr());
+
+	helper.wait_udp();
+	ffi::client::receive(client2);
+
+	assert_eq!(*STRING.lock().unwrap(), Some((10, "Игрок 🚀".to_owned())));
+}
+
+#[test]
+fn should_not_set_too_big_string() {
+	let (helper, [client1, _client2]) = setup(Default::default());
+	let object_id = helper.create_member_object(client1);
+	let value = U16CString::from_str("a".repeat(256)).unwrap();
+	assert_ne!(ffi::command::string::set_string(client1, &object_id, 10, value.as_ptr()), 0);
+}
+
+lazy_static! {
+	static ref STRING: Mutex<Option<(FieldId, String)>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_string_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: *const u16, _: u64) {
+	let value = unsafe { U16CStr::from_ptr_str(value) }.to_string().unwrap();
+	STRING.lock().unwrap().replace((field_id, value));
+}
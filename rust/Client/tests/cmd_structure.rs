@@ -127,10 +127,10 @@ lazy_static! {
 	static ref COMPARE_AND_SET: Mutex<HashMap<FieldId, BinaryValue>> = Mutex::new(Default::default());
 }
 
-extern "C" fn on_structure_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, buffer: &BinaryValue) {
+extern "C" fn on_structure_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, buffer: &BinaryValue, _: u64) {
 	STRUCTURE.lock().unwrap().replace((field_id, (*buffer).clone()));
 }
 
-extern "C" fn on_compare_and_set_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: &BinaryValue) {
+extern "C" fn on_compare_and_set_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: &BinaryValue, _: u64) {
 	COMPARE_AND_SET.lock().unwrap().insert(field_id, value.clone());
 }
@@ -0,0 +1,38 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use cheetah_client::ffi;
+use cheetah_common::room::object::GameObjectId;
+
+use crate::helpers::helper::setup;
+use crate::helpers::server::IntegrationTestServerBuilder;
+
+pub mod helpers;
+
+///
+/// Клиент получает только объекты шаблонов, на которые подписан
+///
+#[test]
+fn should_receive_only_subscribed_templates() {
+	let (helper, [client1, client2]) = setup(Default::default());
+
+	ffi::command::object::set_create_object_listener(client2, on_object_create);
+	ffi::command::room::subscribe_template(client2, IntegrationTestServerBuilder::DEFAULT_TEMPLATE + 1);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	helper.create_member_object(client1);
+	helper.wait_udp();
+	ffi::client::receive(client2);
+	assert_eq!(CREATED_OBJECTS.load(Ordering::Relaxed), 0);
+
+	ffi::command::room::reset_subscription(client2);
+	helper.wait_udp();
+	ffi::client::receive(client2);
+	assert_eq!(CREATED_OBJECTS.load(Ordering::Relaxed), 1);
+}
+
+static CREATED_OBJECTS: AtomicU32 = AtomicU32::new(0);
+
+extern "C" fn on_object_create(_object_id: &GameObjectId, _template: u16) {
+	CREATED_OBJECTS.fetch_add(1, Ordering::Relaxed);
+}
@@ -58,6 +58,6 @@ lazy_static! {
 	static ref EVENT: Mutex<Option<(FieldId, BinaryValue)>> = Mutex::new(Default::default());
 }
 
-extern "C" fn on_event_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, buffer: &BinaryValue) {
+extern "C" fn on_event_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, buffer: &BinaryValue, _: u64) {
 	EVENT.lock().unwrap().replace((field_id, (*buffer).clone()));
 }
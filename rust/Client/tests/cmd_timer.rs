@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use lazy_static::lazy_static;
+
+use cheetah_client::ffi;
+use cheetah_client::ffi::command::timer::{TimerFFI, TimerTimeKind};
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::timer::TimerOwner;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::helpers::helper::setup;
+
+pub mod helpers;
+
+const SCHEDULED_FIELD: FieldId = 1;
+const CANCELED_FIELD: FieldId = 2;
+
+#[test]
+fn should_set_field_by_timer() {
+	let (helper, [client1, client2]) = setup(Default::default());
+
+	ffi::command::long_value::set_long_value_listener(client2, on_long_listener);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	let object_id = helper.create_member_object(client1);
+	ffi::command::timer::schedule_set_long_value(client1, &timer(1, 50), &object_id, SCHEDULED_FIELD, 100);
+	ffi::command::timer::schedule_set_long_value(client1, &timer(2, 50), &object_id, CANCELED_FIELD, 100);
+	ffi::command::timer::cancel_timer(client1, 2);
+
+	helper.wait_udp();
+	thread::sleep(Duration::from_millis(100));
+	helper.wait_udp();
+	ffi::client::receive(client2);
+
+	let values = VALUES.lock().unwrap();
+	assert_eq!(values.get(&SCHEDULED_FIELD), Some(&100));
+	assert_eq!(values.get(&CANCELED_FIELD), None);
+}
+
+fn timer(timer_id: u32, delay: u64) -> TimerFFI {
+	TimerFFI {
+		timer_id,
+		time: delay,
+		time_kind: TimerTimeKind::Delay,
+		owner: TimerOwner::Member,
+	}
+}
+
+lazy_static! {
+	static ref VALUES: Mutex<HashMap<FieldId, i64>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_long_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64, _: u64) {
+	VALUES.lock().unwrap().insert(field_id, value);
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use cheetah_client::ffi;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::transaction::PreconditionKind;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::helpers::helper::setup;
+
+pub mod helpers;
+
+const GOLD: FieldId = 1;
+const BUILDINGS: FieldId = 2;
+
+#[test]
+fn should_apply_transaction() {
+	let (helper, [client1, client2]) = setup(Default::default());
+
+	ffi::command::long_value::set_long_value_listener(client2, on_long_listener);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	let object_id = helper.create_member_object(client1);
+	ffi::command::long_value::set_long_value(client1, &object_id, GOLD, 100);
+
+	ffi::command::transaction::transaction_begin(client1);
+	ffi::command::transaction::transaction_precondition_long(client1, &object_id, GOLD, PreconditionKind::GreaterThan, 49);
+	ffi::command::transaction::transaction_inc_long(client1, &object_id, GOLD, -50);
+	ffi::command::transaction::transaction_set_long(client1, &object_id, BUILDINGS, 1);
+	ffi::command::transaction::transaction_commit(client1);
+
+	// второе строительство невозможно - золота недостаточно
+	ffi::command::transaction::transaction_begin(client1);
+	ffi::command::transaction::transaction_precondition_long(client1, &object_id, GOLD, PreconditionKind::GreaterThan, 49);
+	ffi::command::transaction::transaction_inc_long(client1, &object_id, GOLD, -50);
+	ffi::command::transaction::transaction_set_long(client1, &object_id, BUILDINGS, 2);
+	ffi::command::transaction::transaction_commit(client1);
+
+	helper.wait_udp();
+	ffi::client::receive(client2);
+
+	let values = VALUES.lock().unwrap();
+	assert_eq!(values.get(&GOLD), Some(&50));
+	assert_eq!(values.get(&BUILDINGS), Some(&1));
+}
+
+#[test]
+fn should_not_commit_without_begin() {
+	let (_helper, [client1, _client2]) = setup(Default::default());
+	assert_ne!(ffi::command::transaction::transaction_commit(client1), 0);
+}
+
+lazy_static! {
+	static ref VALUES: Mutex<HashMap<FieldId, i64>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_long_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64, _: u64) {
+	VALUES.lock().unwrap().insert(field_id, value);
+}
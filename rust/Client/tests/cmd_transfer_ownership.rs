@@ -0,0 +1,43 @@
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use cheetah_client::ffi;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::helpers::helper::IntegrationTestHelper;
+
+pub mod helpers;
+
+///
+/// Новый владелец получает оповещение о передаче владения объектом
+///
+#[test]
+fn should_transfer_ownership() {
+	let mut helper = IntegrationTestHelper::new(Default::default());
+	let (member1, private_key1) = helper.create_member();
+	let (member2, private_key2) = helper.create_member();
+	let client1 = helper.create_client(member1, &private_key1);
+	let client2 = helper.create_client(member2, &private_key2);
+
+	ffi::command::object::set_transfer_ownership_listener(client2, on_transfer_ownership);
+	ffi::command::room::attach_to_room(client2);
+	helper.wait_udp();
+
+	let object_id = helper.create_member_object(client1);
+	ffi::command::object::transfer_ownership(client1, &object_id, member2);
+
+	helper.wait_udp();
+	ffi::client::receive(client2);
+
+	assert_eq!(*TRANSFERRED.lock().unwrap(), Some((object_id, member2)));
+}
+
+lazy_static! {
+	static ref TRANSFERRED: Mutex<Option<(GameObjectId, RoomMemberId)>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_transfer_ownership(object_id: &GameObjectId, new_owner: RoomMemberId) {
+	TRANSFERRED.lock().unwrap().replace((*object_id, new_owner));
+}
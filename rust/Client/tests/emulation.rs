@@ -48,7 +48,7 @@ lazy_static! {
 }
 
 #[allow(dead_code)]
-extern "C" fn should_drop_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64) {
+extern "C" fn should_drop_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64, _: u64) {
 	SHOULD_DROP_SET.lock().unwrap().replace((field_id, value));
 }
 
@@ -78,6 +78,6 @@ lazy_static! {
 	static ref SHOULD_RTT_SET: Mutex<Option<(FieldId, i64)>> = Mutex::new(Default::default());
 }
 
-extern "C" fn should_rtt_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64) {
+extern "C" fn should_rtt_listener(_: RoomMemberId, _object_id: &GameObjectId, field_id: FieldId, value: i64, _: u64) {
 	SHOULD_RTT_SET.lock().unwrap().replace((field_id, value));
 }
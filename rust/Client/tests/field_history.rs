@@ -0,0 +1,65 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use lazy_static::lazy_static;
+
+use cheetah_client::ffi;
+use cheetah_client::ffi::command::field_history::FieldHistoryValueFFI;
+use cheetah_common::commands::field::Field;
+use cheetah_common::commands::FieldType;
+use cheetah_common::room::MemberPrivateKey;
+use cheetah_server::room::template::config::{FieldHistoryTemplate, MemberTemplate};
+
+use crate::helpers::helper::IntegrationTestHelper;
+use crate::helpers::server::IntegrationTestServerBuilder;
+
+pub mod helpers;
+
+///
+/// Супер-пользователь получает значение поля из истории
+///
+#[test]
+fn should_get_field_history_value() {
+	let mut builder = IntegrationTestServerBuilder::default();
+	builder.add_field_history(FieldHistoryTemplate {
+		template: IntegrationTestServerBuilder::DEFAULT_TEMPLATE,
+		field: Field {
+			id: 1,
+			field_type: FieldType::Double,
+		},
+		depth: Duration::from_secs(1),
+	});
+
+	let mut helper = IntegrationTestHelper::new(builder);
+	let (member_id, private_key) = helper.create_member();
+	let client = helper.create_client(member_id, &private_key);
+	let super_private_key = MemberPrivateKey::new_random();
+	let super_member_id = helper
+		.server
+		.create_member(helper.room_id, MemberTemplate::new_super_member_with_key(super_private_key.clone()))
+		.unwrap();
+	let super_client = helper.create_client(super_member_id, &super_private_key);
+
+	ffi::command::field_history::set_field_history_listener(super_client, on_field_history);
+	ffi::command::room::attach_to_room(super_client);
+	ffi::command::room::attach_to_room(client);
+	let object_id = helper.create_member_object(client);
+	ffi::command::float_value::set_double_value(client, &object_id, 1, 1.5);
+	helper.wait_udp();
+	ffi::command::field_history::get_field_history_value(super_client, &object_id, 1, FieldType::Double, u64::MAX);
+	helper.wait_udp();
+	ffi::client::receive(super_client);
+
+	let value = VALUE.lock().unwrap().take().unwrap();
+	assert_eq!(value.object_id, object_id);
+	assert!(value.has_value);
+	assert!((value.double_value - 1.5).abs() < f64::EPSILON);
+}
+
+lazy_static! {
+	static ref VALUE: Mutex<Option<FieldHistoryValueFFI>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_field_history(value: &FieldHistoryValueFFI) {
+	VALUE.lock().unwrap().replace(value.clone());
+}
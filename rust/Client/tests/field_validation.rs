@@ -0,0 +1,61 @@
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use cheetah_client::ffi;
+use cheetah_common::commands::field::{Field, FieldId};
+use cheetah_common::commands::FieldType;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::{MemberPrivateKey, RoomMemberId};
+use cheetah_server::room::template::config::{FieldValidationReaction, FieldValidationRule, FieldValidationTemplate, MemberTemplate};
+
+use crate::helpers::helper::IntegrationTestHelper;
+use crate::helpers::server::IntegrationTestServerBuilder;
+
+pub mod helpers;
+
+///
+/// Супер-пользователь получает сообщение о нарушении правила проверки поля
+///
+#[test]
+fn should_report_field_violation() {
+	let mut builder = IntegrationTestServerBuilder::default();
+	builder.add_field_validation(FieldValidationTemplate {
+		template: IntegrationTestServerBuilder::DEFAULT_TEMPLATE,
+		field: Field {
+			id: 1,
+			field_type: FieldType::Long,
+		},
+		rule: FieldValidationRule::Range { min: 0.0, max: 3.0 },
+		reaction: FieldValidationReaction::Report,
+	});
+
+	let mut helper = IntegrationTestHelper::new(builder);
+	let (member_id, private_key) = helper.create_member();
+	let client = helper.create_client(member_id, &private_key);
+	let super_private_key = MemberPrivateKey::new_random();
+	let super_member_id = helper
+		.server
+		.create_member(helper.room_id, MemberTemplate::new_super_member_with_key(super_private_key.clone()))
+		.unwrap();
+	let super_client = helper.create_client(super_member_id, &super_private_key);
+
+	ffi::command::field_violation::set_field_violation_listener(super_client, on_violation);
+	ffi::command::room::attach_to_room(super_client);
+	ffi::command::room::attach_to_room(client);
+	let object_id = helper.create_member_object(client);
+	ffi::command::long_value::set_long_value(client, &object_id, 1, 2);
+	ffi::command::long_value::set_long_value(client, &object_id, 1, 5);
+	helper.wait_udp();
+	ffi::client::receive(super_client);
+
+	assert_eq!(*VIOLATION.lock().unwrap(), Some((member_id, object_id, 1, FieldType::Long)));
+}
+
+lazy_static! {
+	static ref VIOLATION: Mutex<Option<(RoomMemberId, GameObjectId, FieldId, FieldType)>> = Mutex::new(Default::default());
+}
+
+extern "C" fn on_violation(member_id: RoomMemberId, object_id: &GameObjectId, field_id: FieldId, field_type: FieldType) {
+	VIOLATION.lock().unwrap().replace((member_id, *object_id, field_id, field_type));
+}
@@ -8,7 +8,10 @@ use cheetah_common::constants::GameObjectTemplateId;
 use cheetah_common::network::bind_to_free_socket;
 use cheetah_common::room::access::AccessGroups;
 use cheetah_common::room::RoomId;
-use cheetah_server::room::template::config::{GameObjectTemplatePermission, GroupsPermissionRule, Permission, PermissionField, RoomTemplate};
+use cheetah_server::room::template::config::{
+	FieldHistoryTemplate, FieldValidationTemplate, GameObjectTemplatePermission, GroupsPermissionRule, Permission, PermissionField,
+	RateLimitsTemplate, RoomTemplate,
+};
 use cheetah_server::server::manager::RoomsServerManager;
 
 ///
@@ -47,6 +50,18 @@ impl IntegrationTestServerBuilder {
 		}
 	}
 
+	pub fn set_rate_limits(&mut self, rate_limits: RateLimitsTemplate) {
+		self.template.rate_limits = Some(rate_limits);
+	}
+
+	pub fn add_field_validation(&mut self, field_validation: FieldValidationTemplate) {
+		self.template.field_validations.push(field_validation);
+	}
+
+	pub fn add_field_history(&mut self, field_history: FieldHistoryTemplate) {
+		self.template.field_history.push(field_history);
+	}
+
 	#[must_use]
 	pub fn build(self) -> (SocketAddr, RoomsServerManager, RoomId) {
 		let socket = bind_to_free_socket().unwrap();
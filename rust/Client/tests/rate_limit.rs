@@ -0,0 +1,50 @@
+use cheetah_client::ffi;
+use cheetah_client::ffi::execute_with_client;
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::CommandTypeId;
+use cheetah_common::network::client::{ConnectionStatus, DisconnectedReason};
+use cheetah_common::protocol::disconnect::command::DisconnectByCommandReason;
+use cheetah_server::room::template::config::{RateLimitTemplate, RateLimitsTemplate};
+
+use crate::helpers::helper::IntegrationTestHelper;
+use crate::helpers::server::IntegrationTestServerBuilder;
+
+pub mod helpers;
+
+///
+/// Пользователь отключается сервером при превышении лимита частоты команд
+///
+#[test]
+fn should_disconnect_on_rate_limit_exceeded() {
+	let mut builder = IntegrationTestServerBuilder::default();
+	builder.set_rate_limits(RateLimitsTemplate {
+		limits: vec![RateLimitTemplate {
+			command_type_id: CommandTypeId::Event,
+			field_id: None,
+			burst: 1,
+			per_second: 0,
+		}],
+		warn_after: None,
+		disconnect_after: Some(3),
+	});
+
+	let mut helper = IntegrationTestHelper::new(builder);
+	let (member_id, private_key) = helper.create_member();
+	let client = helper.create_client(member_id, &private_key);
+	ffi::command::room::attach_to_room(client);
+	helper.wait_udp();
+
+	for _ in 0..5 {
+		ffi::command::event::send_event(client, &Default::default(), 1, &BinaryValue::from([1].as_ref()));
+	}
+	helper.wait_udp();
+
+	execute_with_client(client, |api| {
+		let status = api.get_connection_status().unwrap();
+		assert!(matches!(
+			status,
+			ConnectionStatus::Disconnected(DisconnectedReason::ByCommand(DisconnectByCommandReason::CommandRateLimitExceeded))
+		));
+		Ok(())
+	});
+}
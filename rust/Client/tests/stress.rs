@@ -97,6 +97,6 @@ lazy_static! {
 	static ref LONG_VALUE: Mutex<Option<(GameObjectId, FieldId, i64)>> = Mutex::new(Default::default());
 }
 
-extern "C" fn listener(_: RoomMemberId, object_id: &GameObjectId, field_id: FieldId, value: i64) {
+extern "C" fn listener(_: RoomMemberId, object_id: &GameObjectId, field_id: FieldId, value: i64, _: u64) {
 	LONG_VALUE.lock().unwrap().replace(((*object_id).clone(), field_id, value));
 }
@@ -4,15 +4,27 @@ use strum_macros::AsRefStr;
 
 use crate::commands::binary_value::BinaryValue;
 use crate::commands::field::{Field, FieldId};
+use crate::commands::types::access_groups::SetObjectAccessGroupsCommand;
 use crate::commands::types::create::{C2SCreatedGameObjectCommand, CreateGameObjectCommand};
 use crate::commands::types::delete::DeleteGameObjectCommand;
 use crate::commands::types::event::{EventCommand, TargetEventCommand};
 use crate::commands::types::field::{DeleteFieldCommand, SetFieldCommand};
-use crate::commands::types::float::IncrementDoubleC2SCommand;
+use crate::commands::types::field_history::FieldHistoryQueryCommand;
+use crate::commands::types::float::{CompareAndSetDoubleCommand, IncrementDoubleC2SCommand, IncrementDoubleWithBoundsC2SCommand};
 use crate::commands::types::forwarded::ForwardedCommand;
-use crate::commands::types::long::{CompareAndSetLongCommand, IncrementLongC2SCommand};
+use crate::commands::types::list::{ListClearCommand, ListPushCommand, ListRemoveCommand, ListRemoveValueCommand, ListSetItemCommand};
+use crate::commands::types::lockstep::{LockstepHistoryCommand, LockstepInputCommand};
+use crate::commands::types::long::{CompareAndSetLongCommand, IncrementLongC2SCommand, IncrementLongWithBoundsC2SCommand};
+use crate::commands::types::map::{MapDeleteCommand, MapIncrementCommand, MapItems, MapSetCommand};
+use crate::commands::types::ownership::TransferOwnershipCommand;
+use crate::commands::types::room_event::RoomEventCommand;
+use crate::commands::types::rpc::{RpcRequestCommand, RpcResponseCommand};
 use crate::commands::types::structure::CompareAndSetStructureCommand;
-use crate::commands::{CommandDecodeError, CommandTypeId, FieldType, FieldValue};
+use crate::commands::types::subscription::SubscriptionCommand;
+use crate::commands::types::timer::{CancelTimerCommand, ScheduleTimerCommand};
+use crate::commands::types::transaction::TransactionCommand;
+use crate::commands::types::ttl::SetObjectTtlCommand;
+use crate::commands::{CommandDecodeError, CommandTypeId, FieldType};
 use crate::protocol::codec::commands::context::CommandContextError;
 use crate::room::object::GameObjectId;
 
@@ -36,6 +48,31 @@ pub enum C2SCommand {
 	AttachToRoom,
 	DetachFromRoom,
 	Forwarded(Box<ForwardedCommand>),
+	LockstepInput(LockstepInputCommand),
+	LockstepHistory(LockstepHistoryCommand),
+	ListPush(ListPushCommand),
+	ListRemove(ListRemoveCommand),
+	ListRemoveValue(ListRemoveValueCommand),
+	ListSetItem(ListSetItemCommand),
+	ListClear(ListClearCommand),
+	MapSet(MapSetCommand),
+	MapDelete(MapDeleteCommand),
+	MapIncrement(MapIncrementCommand),
+	IncrementLongWithBounds(IncrementLongWithBoundsC2SCommand),
+	IncrementDoubleWithBounds(IncrementDoubleWithBoundsC2SCommand),
+	CompareAndSetDouble(CompareAndSetDoubleCommand),
+	Transaction(TransactionCommand),
+	ScheduleTimer(ScheduleTimerCommand),
+	CancelTimer(CancelTimerCommand),
+	SetObjectTtl(SetObjectTtlCommand),
+	TransferOwnership(TransferOwnershipCommand),
+	SetObjectAccessGroups(SetObjectAccessGroupsCommand),
+	Subscribe(SubscriptionCommand),
+	Unsubscribe(SubscriptionCommand),
+	RpcRequest(RpcRequestCommand),
+	RpcResponse(RpcResponseCommand),
+	RoomEvent(RoomEventCommand),
+	FieldHistory(FieldHistoryQueryCommand),
 }
 
 impl C2SCommand {
@@ -47,8 +84,11 @@ impl C2SCommand {
 			C2SCommand::SetField(command) => Some(command.field_id),
 			C2SCommand::IncrementLongValue(command) => Some(command.field_id),
 			C2SCommand::CompareAndSetLong(command) => Some(command.field_id),
+			C2SCommand::CompareAndSetDouble(command) => Some(command.field_id),
 			C2SCommand::CompareAndSetStructure(command) => Some(command.field_id),
 			C2SCommand::IncrementDouble(command) => Some(command.field_id),
+			C2SCommand::IncrementLongWithBounds(command) => Some(command.field_id),
+			C2SCommand::IncrementDoubleWithBounds(command) => Some(command.field_id),
 			C2SCommand::Event(command) => Some(command.field_id),
 			C2SCommand::TargetEvent(command) => Some(command.event.field_id),
 			C2SCommand::Delete(_) => None,
@@ -56,6 +96,28 @@ impl C2SCommand {
 			C2SCommand::DetachFromRoom => None,
 			C2SCommand::DeleteField(command) => Some(command.field_id),
 			C2SCommand::Forwarded(command) => command.c2s.get_field_id(),
+			C2SCommand::LockstepInput(_) => None,
+			C2SCommand::LockstepHistory(_) => None,
+			C2SCommand::ListPush(command) => Some(command.field_id),
+			C2SCommand::ListRemove(command) => Some(command.field_id),
+			C2SCommand::ListRemoveValue(command) => Some(command.field_id),
+			C2SCommand::ListSetItem(command) => Some(command.field_id),
+			C2SCommand::ListClear(command) => Some(command.field_id),
+			C2SCommand::MapSet(command) => Some(command.field_id),
+			C2SCommand::MapDelete(command) => Some(command.field_id),
+			C2SCommand::MapIncrement(command) => Some(command.field_id),
+			C2SCommand::Transaction(_) => None,
+			C2SCommand::ScheduleTimer(command) => command.action.get_field().map(|field| field.id),
+			C2SCommand::CancelTimer(_) => None,
+			C2SCommand::SetObjectTtl(_) => None,
+			C2SCommand::TransferOwnership(_) => None,
+			C2SCommand::SetObjectAccessGroups(_) => None,
+			C2SCommand::Subscribe(_) => None,
+			C2SCommand::Unsubscribe(_) => None,
+			C2SCommand::RpcRequest(_) => None,
+			C2SCommand::RpcResponse(_) => None,
+			C2SCommand::RoomEvent(_) => None,
+			C2SCommand::FieldHistory(_) => None,
 		}
 	}
 	#[must_use]
@@ -66,7 +128,10 @@ impl C2SCommand {
 			C2SCommand::SetField(command) => Some(command.object_id),
 			C2SCommand::IncrementLongValue(command) => Some(command.object_id),
 			C2SCommand::CompareAndSetLong(command) => Some(command.object_id),
+			C2SCommand::CompareAndSetDouble(command) => Some(command.object_id),
 			C2SCommand::IncrementDouble(command) => Some(command.object_id),
+			C2SCommand::IncrementLongWithBounds(command) => Some(command.object_id),
+			C2SCommand::IncrementDoubleWithBounds(command) => Some(command.object_id),
 			C2SCommand::Event(command) => Some(command.object_id),
 			C2SCommand::TargetEvent(command) => Some(command.event.object_id),
 			C2SCommand::Delete(command) => Some(command.object_id),
@@ -75,6 +140,28 @@ impl C2SCommand {
 			C2SCommand::DeleteField(command) => Some(command.object_id),
 			C2SCommand::CompareAndSetStructure(command) => Some(command.object_id),
 			C2SCommand::Forwarded(command) => command.c2s.get_object_id(),
+			C2SCommand::LockstepInput(_) => None,
+			C2SCommand::LockstepHistory(_) => None,
+			C2SCommand::ListPush(command) => Some(command.object_id),
+			C2SCommand::ListRemove(command) => Some(command.object_id),
+			C2SCommand::ListRemoveValue(command) => Some(command.object_id),
+			C2SCommand::ListSetItem(command) => Some(command.object_id),
+			C2SCommand::ListClear(command) => Some(command.object_id),
+			C2SCommand::MapSet(command) => Some(command.object_id),
+			C2SCommand::MapDelete(command) => Some(command.object_id),
+			C2SCommand::MapIncrement(command) => Some(command.object_id),
+			C2SCommand::Transaction(_) => None,
+			C2SCommand::ScheduleTimer(command) => Some(command.action.get_object_id()),
+			C2SCommand::CancelTimer(_) => None,
+			C2SCommand::SetObjectTtl(command) => Some(command.object_id),
+			C2SCommand::TransferOwnership(command) => Some(command.object_id),
+			C2SCommand::SetObjectAccessGroups(command) => Some(command.object_id),
+			C2SCommand::Subscribe(_) => None,
+			C2SCommand::Unsubscribe(_) => None,
+			C2SCommand::RpcRequest(_) => None,
+			C2SCommand::RpcResponse(_) => None,
+			C2SCommand::RoomEvent(_) => None,
+			C2SCommand::FieldHistory(_) => None,
 		}
 	}
 
@@ -86,7 +173,10 @@ impl C2SCommand {
 			C2SCommand::SetField(command) => Some(command.value.field_type()),
 			C2SCommand::IncrementLongValue(_) => Some(FieldType::Long),
 			C2SCommand::CompareAndSetLong(_) => Some(FieldType::Long),
+			C2SCommand::CompareAndSetDouble(_) => Some(FieldType::Double),
 			C2SCommand::IncrementDouble(_) => Some(FieldType::Double),
+			C2SCommand::IncrementLongWithBounds(_) => Some(FieldType::Long),
+			C2SCommand::IncrementDoubleWithBounds(_) => Some(FieldType::Double),
 			C2SCommand::Event(_) => Some(FieldType::Event),
 			C2SCommand::TargetEvent(_) => Some(FieldType::Event),
 			C2SCommand::Delete(_) => None,
@@ -95,6 +185,28 @@ impl C2SCommand {
 			C2SCommand::DeleteField(command) => Some(command.field_type),
 			C2SCommand::CompareAndSetStructure(_) => Some(FieldType::Structure),
 			C2SCommand::Forwarded(command) => command.c2s.get_field_type(),
+			C2SCommand::LockstepInput(_) => None,
+			C2SCommand::LockstepHistory(_) => None,
+			C2SCommand::ListPush(_) => Some(FieldType::List),
+			C2SCommand::ListRemove(_) => Some(FieldType::List),
+			C2SCommand::ListRemoveValue(_) => Some(FieldType::List),
+			C2SCommand::ListSetItem(_) => Some(FieldType::List),
+			C2SCommand::ListClear(_) => Some(FieldType::List),
+			C2SCommand::MapSet(_) => Some(FieldType::Map),
+			C2SCommand::MapDelete(_) => Some(FieldType::Map),
+			C2SCommand::MapIncrement(_) => Some(FieldType::Map),
+			C2SCommand::Transaction(_) => None,
+			C2SCommand::ScheduleTimer(command) => command.action.get_field().map(|field| field.field_type),
+			C2SCommand::CancelTimer(_) => None,
+			C2SCommand::SetObjectTtl(_) => None,
+			C2SCommand::TransferOwnership(_) => None,
+			C2SCommand::SetObjectAccessGroups(_) => None,
+			C2SCommand::Subscribe(_) => None,
+			C2SCommand::Unsubscribe(_) => None,
+			C2SCommand::RpcRequest(_) => None,
+			C2SCommand::RpcResponse(_) => None,
+			C2SCommand::RoomEvent(_) => None,
+			C2SCommand::FieldHistory(_) => None,
 		}
 	}
 
@@ -103,15 +215,14 @@ impl C2SCommand {
 		match self {
 			C2SCommand::CreateGameObject(_) => CommandTypeId::CreateGameObject,
 			C2SCommand::CreatedGameObject(_) => CommandTypeId::CreatedGameObject,
-			C2SCommand::SetField(command) => match command.value {
-				FieldValue::Long(_) => CommandTypeId::SetLong,
-				FieldValue::Double(_) => CommandTypeId::SetDouble,
-				FieldValue::Structure(_) => CommandTypeId::SetStructure,
-			},
+			C2SCommand::SetField(command) => command.get_type_id(),
 			C2SCommand::IncrementLongValue(_) => CommandTypeId::IncrementLong,
 			C2SCommand::CompareAndSetLong(_) => CommandTypeId::CompareAndSetLong,
+			C2SCommand::CompareAndSetDouble(_) => CommandTypeId::CompareAndSetDouble,
 			C2SCommand::CompareAndSetStructure(_) => CommandTypeId::CompareAndSetStructure,
 			C2SCommand::IncrementDouble(_) => CommandTypeId::IncrementDouble,
+			C2SCommand::IncrementLongWithBounds(_) => CommandTypeId::IncrementLongWithBounds,
+			C2SCommand::IncrementDoubleWithBounds(_) => CommandTypeId::IncrementDoubleWithBounds,
 			C2SCommand::Event(_) => CommandTypeId::Event,
 			C2SCommand::TargetEvent(_) => CommandTypeId::TargetEvent,
 			C2SCommand::Delete(_) => CommandTypeId::Delete,
@@ -119,6 +230,44 @@ impl C2SCommand {
 			C2SCommand::DetachFromRoom => CommandTypeId::DetachFromRoom,
 			C2SCommand::DeleteField(_) => CommandTypeId::DeleteField,
 			C2SCommand::Forwarded(_) => CommandTypeId::Forwarded,
+			C2SCommand::LockstepInput(_) => CommandTypeId::LockstepInput,
+			C2SCommand::LockstepHistory(_) => CommandTypeId::LockstepHistory,
+			C2SCommand::ListPush(_) => CommandTypeId::ListPush,
+			C2SCommand::ListRemove(_) => CommandTypeId::ListRemove,
+			C2SCommand::ListRemoveValue(_) => CommandTypeId::ListRemoveValue,
+			C2SCommand::ListSetItem(_) => CommandTypeId::ListSetItem,
+			C2SCommand::ListClear(_) => CommandTypeId::ListClear,
+			C2SCommand::MapSet(_) => CommandTypeId::MapSet,
+			C2SCommand::MapDelete(_) => CommandTypeId::MapDelete,
+			C2SCommand::MapIncrement(_) => CommandTypeId::MapIncrement,
+			C2SCommand::Transaction(_) => CommandTypeId::Transaction,
+			C2SCommand::ScheduleTimer(_) => CommandTypeId::ScheduleTimer,
+			C2SCommand::CancelTimer(_) => CommandTypeId::CancelTimer,
+			C2SCommand::SetObjectTtl(_) => CommandTypeId::SetObjectTtl,
+			C2SCommand::TransferOwnership(_) => CommandTypeId::TransferOwnership,
+			C2SCommand::SetObjectAccessGroups(_) => CommandTypeId::SetObjectAccessGroups,
+			C2SCommand::Subscribe(_) => CommandTypeId::Subscribe,
+			C2SCommand::Unsubscribe(_) => CommandTypeId::Unsubscribe,
+			C2SCommand::RpcRequest(_) => CommandTypeId::RpcRequest,
+			C2SCommand::RpcResponse(_) => CommandTypeId::RpcResponse,
+			C2SCommand::RoomEvent(_) => CommandTypeId::RoomEvent,
+			C2SCommand::FieldHistory(_) => CommandTypeId::FieldHistory,
+		}
+	}
+
+	///
+	/// Типы и поля вложенных команд транзакции или таймера
+	///
+	#[must_use]
+	pub fn get_nested_commands(&self) -> Vec<(CommandTypeId, Option<FieldId>)> {
+		match self {
+			C2SCommand::Transaction(command) => command
+				.mutations
+				.iter()
+				.map(|mutation| (mutation.get_type_id(), Some(mutation.get_field().id)))
+				.collect(),
+			C2SCommand::ScheduleTimer(command) => vec![(command.action.get_type_id(), command.action.get_field().map(|field| field.id))],
+			_ => Vec::new(),
 		}
 	}
 
@@ -138,7 +287,10 @@ impl C2SCommand {
 			C2SCommand::SetField(command) => command.encode(out),
 			C2SCommand::IncrementLongValue(command) => command.encode(out),
 			C2SCommand::CompareAndSetLong(command) => command.encode(out),
+			C2SCommand::CompareAndSetDouble(command) => command.encode(out),
 			C2SCommand::IncrementDouble(command) => command.encode(out),
+			C2SCommand::IncrementLongWithBounds(command) => command.encode(out),
+			C2SCommand::IncrementDoubleWithBounds(command) => command.encode(out),
 			C2SCommand::Event(command) => command.encode(out),
 			C2SCommand::TargetEvent(command) => command.encode(out),
 			C2SCommand::Delete(_) => Ok(()),
@@ -147,6 +299,28 @@ impl C2SCommand {
 			C2SCommand::DeleteField(command) => command.encode(out),
 			C2SCommand::CompareAndSetStructure(command) => command.encode(out),
 			C2SCommand::Forwarded(command) => command.encode(out),
+			C2SCommand::LockstepInput(command) => command.encode(out),
+			C2SCommand::LockstepHistory(command) => command.encode(out),
+			C2SCommand::ListPush(command) => command.encode(out),
+			C2SCommand::ListRemove(command) => command.encode(out),
+			C2SCommand::ListRemoveValue(command) => command.encode(out),
+			C2SCommand::ListSetItem(command) => command.encode(out),
+			C2SCommand::ListClear(_) => Ok(()),
+			C2SCommand::MapSet(command) => command.encode(out),
+			C2SCommand::MapDelete(command) => command.encode(out),
+			C2SCommand::MapIncrement(command) => command.encode(out),
+			C2SCommand::Transaction(command) => command.encode(out),
+			C2SCommand::ScheduleTimer(command) => command.encode(out),
+			C2SCommand::CancelTimer(command) => command.encode(out),
+			C2SCommand::SetObjectTtl(command) => command.encode(out),
+			C2SCommand::TransferOwnership(command) => command.encode(out),
+			C2SCommand::SetObjectAccessGroups(command) => command.encode(out),
+			C2SCommand::Subscribe(command) => command.encode(out),
+			C2SCommand::Unsubscribe(command) => command.encode(out),
+			C2SCommand::RpcRequest(command) => command.encode(out),
+			C2SCommand::RpcResponse(command) => command.encode(out),
+			C2SCommand::RoomEvent(command) => command.encode(out),
+			C2SCommand::FieldHistory(command) => command.encode(out),
 		}
 	}
 
@@ -158,6 +332,14 @@ impl C2SCommand {
 				format!("room_owner({:?}), singleton_key ({:?}) ", command.room_owner, command.get_singleton_key())
 			}
 			C2SCommand::IncrementLongValue(command) => format!("{:?}", command.increment),
+			C2SCommand::CompareAndSetDouble(command) => {
+				format!(
+					"new = {:?}, current = {:?}, reset = {:?}",
+					command.new,
+					command.current,
+					command.get_reset()
+				)
+			}
 			C2SCommand::CompareAndSetLong(command) => {
 				format!(
 					"new = {:?}, current = {:?}, reset = {:?}",
@@ -168,6 +350,8 @@ impl C2SCommand {
 			}
 			C2SCommand::SetField(command) => format!("{:?}", command.value),
 			C2SCommand::IncrementDouble(command) => format!("{:?}", command.increment),
+			C2SCommand::IncrementLongWithBounds(command) => format!("increment = {:?}, bounds = {:?}", command.increment, command.bounds),
+			C2SCommand::IncrementDoubleWithBounds(command) => format!("increment = {:?}, bounds = {:?}", command.increment, command.bounds),
 			C2SCommand::CompareAndSetStructure(command) => {
 				format!(
 					"new = {:?}, current = {:?}, reset = {:?}",
@@ -183,6 +367,44 @@ impl C2SCommand {
 			C2SCommand::AttachToRoom => String::new(),
 			C2SCommand::DetachFromRoom => String::new(),
 			C2SCommand::Forwarded(command) => format!("forward: member({:?}) command({:?})", command.creator, command.c2s.get_trace_string()),
+			C2SCommand::LockstepInput(command) => format!("tick = {:?}, input = {:?}", command.tick, command.input),
+			C2SCommand::LockstepHistory(command) => format!("from_tick = {:?}", command.from_tick),
+			C2SCommand::ListPush(command) => format!("{:?}", command.value),
+			C2SCommand::ListRemove(command) => format!("index = {:?}", command.index),
+			C2SCommand::ListRemoveValue(command) => format!("{:?}", command.value),
+			C2SCommand::ListSetItem(command) => format!("index = {:?}, value = {:?}", command.index, command.value),
+			C2SCommand::ListClear(_) => String::new(),
+			C2SCommand::MapSet(command) => format!("key = {:?}, value = {:?}", command.key, command.value),
+			C2SCommand::MapDelete(command) => format!("key = {:?}", command.key),
+			C2SCommand::MapIncrement(command) => format!("key = {:?}, increment = {:?}", command.key, command.increment),
+			C2SCommand::Transaction(command) => format!("preconditions = {:?}, mutations = {:?}", command.preconditions, command.mutations),
+			C2SCommand::ScheduleTimer(command) => format!(
+				"timer_id = {:?}, time = {:?}, owner = {:?}, action = {:?}",
+				command.timer_id, command.time, command.owner, command.action
+			),
+			C2SCommand::CancelTimer(command) => format!("timer_id = {:?}", command.timer_id),
+			C2SCommand::SetObjectTtl(command) => format!("ttl = {:?}", command.ttl),
+			C2SCommand::TransferOwnership(command) => format!("new_owner = {:?}", command.new_owner),
+			C2SCommand::SetObjectAccessGroups(command) => format!("access_groups = {:?}", command.access_groups),
+			C2SCommand::Subscribe(command) | C2SCommand::Unsubscribe(command) => {
+				format!("templates = {:?}, fields = {:?}", command.templates, command.fields)
+			}
+			C2SCommand::RpcRequest(command) => format!(
+				"target = {:?}, call_id = {:?}, timeout = {:?}, payload = {:?}",
+				command.member_id, command.call_id, command.timeout, command.payload
+			),
+			C2SCommand::RpcResponse(command) => format!(
+				"caller = {:?}, call_id = {:?}, status = {:?}, payload = {:?}",
+				command.member_id, command.call_id, command.status, command.payload
+			),
+			C2SCommand::RoomEvent(command) => format!(
+				"event_id = {:?}, target = {:?}, event = {:?}",
+				command.event_id, command.target, command.event
+			),
+			C2SCommand::FieldHistory(command) => format!(
+				"object = {:?}, field = {:?}, field_type = {:?}, time = {:?}",
+				command.object_id, command.field_id, command.field_type, command.time
+			),
 		}
 	}
 
@@ -200,18 +422,57 @@ impl C2SCommand {
 			CommandTypeId::CreateGameObject => C2SCommand::CreateGameObject(CreateGameObjectCommand::decode(object_id?, input)?),
 			CommandTypeId::IncrementLong => C2SCommand::IncrementLongValue(IncrementLongC2SCommand::decode(object_id?, field_id?, input)?),
 			CommandTypeId::IncrementDouble => C2SCommand::IncrementDouble(IncrementDoubleC2SCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::IncrementLongWithBounds => {
+				C2SCommand::IncrementLongWithBounds(IncrementLongWithBoundsC2SCommand::decode(object_id?, field_id?, input)?)
+			}
+			CommandTypeId::IncrementDoubleWithBounds => {
+				C2SCommand::IncrementDoubleWithBounds(IncrementDoubleWithBoundsC2SCommand::decode(object_id?, field_id?, input)?)
+			}
 			CommandTypeId::CompareAndSetLong => C2SCommand::CompareAndSetLong(CompareAndSetLongCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::CompareAndSetDouble => C2SCommand::CompareAndSetDouble(CompareAndSetDoubleCommand::decode(object_id?, field_id?, input)?),
 			CommandTypeId::CompareAndSetStructure => {
 				C2SCommand::CompareAndSetStructure(CompareAndSetStructureCommand::decode(object_id?, field_id?, input)?)
 			}
 			CommandTypeId::SetDouble => C2SCommand::SetField(SetFieldCommand::decode::<f64>(object_id?, field_id?, input)?),
 			CommandTypeId::SetLong => C2SCommand::SetField(SetFieldCommand::decode::<i64>(object_id?, field_id?, input)?),
 			CommandTypeId::SetStructure => C2SCommand::SetField(SetFieldCommand::decode::<BinaryValue>(object_id?, field_id?, input)?),
+			CommandTypeId::SetList => C2SCommand::SetField(SetFieldCommand::decode::<Vec<BinaryValue>>(object_id?, field_id?, input)?),
+			CommandTypeId::SetMap => C2SCommand::SetField(SetFieldCommand::decode::<MapItems>(object_id?, field_id?, input)?),
+			CommandTypeId::SetString => C2SCommand::SetField(SetFieldCommand::decode::<String>(object_id?, field_id?, input)?),
 			CommandTypeId::Event => C2SCommand::Event(EventCommand::decode(object_id?, field_id?, input)?),
 			CommandTypeId::TargetEvent => C2SCommand::TargetEvent(TargetEventCommand::decode(object_id?, field_id?, input)?),
 			CommandTypeId::DeleteField => C2SCommand::DeleteField(DeleteFieldCommand::decode(object_id?, field_id?, input)?),
 			CommandTypeId::Forwarded => C2SCommand::Forwarded(Box::new(ForwardedCommand::decode(object_id, field_id, input)?)),
-			CommandTypeId::MemberConnected => return Err(CommandDecodeError::UnknownTypeId(command_type_id)),
+			CommandTypeId::LockstepInput => C2SCommand::LockstepInput(LockstepInputCommand::decode(input)?),
+			CommandTypeId::LockstepHistory => C2SCommand::LockstepHistory(LockstepHistoryCommand::decode(input)?),
+			CommandTypeId::ListPush => C2SCommand::ListPush(ListPushCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::ListRemove => C2SCommand::ListRemove(ListRemoveCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::ListRemoveValue => C2SCommand::ListRemoveValue(ListRemoveValueCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::ListSetItem => C2SCommand::ListSetItem(ListSetItemCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::ListClear => C2SCommand::ListClear(ListClearCommand {
+				object_id: object_id?,
+				field_id: field_id?,
+			}),
+			CommandTypeId::MapSet => C2SCommand::MapSet(MapSetCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::MapDelete => C2SCommand::MapDelete(MapDeleteCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::MapIncrement => C2SCommand::MapIncrement(MapIncrementCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::Transaction => C2SCommand::Transaction(TransactionCommand::decode(input)?),
+			CommandTypeId::ScheduleTimer => C2SCommand::ScheduleTimer(ScheduleTimerCommand::decode(object_id, field_id, input)?),
+			CommandTypeId::CancelTimer => C2SCommand::CancelTimer(CancelTimerCommand::decode(input)?),
+			CommandTypeId::SetObjectTtl => C2SCommand::SetObjectTtl(SetObjectTtlCommand::decode(object_id?, input)?),
+			CommandTypeId::TransferOwnership => C2SCommand::TransferOwnership(TransferOwnershipCommand::decode(object_id?, input)?),
+			CommandTypeId::SetObjectAccessGroups => C2SCommand::SetObjectAccessGroups(SetObjectAccessGroupsCommand::decode(object_id?, input)?),
+			CommandTypeId::Subscribe => C2SCommand::Subscribe(SubscriptionCommand::decode(input)?),
+			CommandTypeId::Unsubscribe => C2SCommand::Unsubscribe(SubscriptionCommand::decode(input)?),
+			CommandTypeId::RpcRequest => C2SCommand::RpcRequest(RpcRequestCommand::decode(input)?),
+			CommandTypeId::RpcResponse => C2SCommand::RpcResponse(RpcResponseCommand::decode(input)?),
+			CommandTypeId::RoomEvent => C2SCommand::RoomEvent(RoomEventCommand::decode(input)?),
+			CommandTypeId::FieldHistory => C2SCommand::FieldHistory(FieldHistoryQueryCommand::decode(input)?),
+			CommandTypeId::MemberConnected
+			| CommandTypeId::SetStructureDelta
+			| CommandTypeId::LockstepTick
+			| CommandTypeId::CommandRejected
+			| CommandTypeId::FieldViolation => return Err(CommandDecodeError::UnknownTypeId(command_type_id)),
 		})
 	}
 }
@@ -223,15 +484,28 @@ mod tests {
 	use crate::commands::binary_value::BinaryValue;
 	use crate::commands::c2s::C2SCommand;
 	use crate::commands::field::FieldId;
+	use crate::commands::types::access_groups::SetObjectAccessGroupsCommand;
+	use crate::commands::types::bounds::{Bounds, BoundsMode};
 	use crate::commands::types::create::{C2SCreatedGameObjectCommand, CreateGameObjectCommand};
 	use crate::commands::types::delete::DeleteGameObjectCommand;
 	use crate::commands::types::event::{EventCommand, TargetEventCommand};
-	use crate::commands::types::field::SetFieldCommand;
-	use crate::commands::types::float::IncrementDoubleC2SCommand;
+	use crate::commands::types::field::{DeleteFieldCommand, SetFieldCommand};
+	use crate::commands::types::field_history::FieldHistoryQueryCommand;
+	use crate::commands::types::float::{CompareAndSetDoubleCommand, IncrementDoubleC2SCommand, IncrementDoubleWithBoundsC2SCommand};
 	use crate::commands::types::forwarded::ForwardedCommand;
-	use crate::commands::types::long::{CompareAndSetLongCommand, IncrementLongC2SCommand};
+	use crate::commands::types::list::{ListClearCommand, ListPushCommand, ListRemoveCommand, ListRemoveValueCommand, ListSetItemCommand};
+	use crate::commands::types::lockstep::{LockstepHistoryCommand, LockstepInputCommand};
+	use crate::commands::types::long::{CompareAndSetLongCommand, IncrementLongC2SCommand, IncrementLongWithBoundsC2SCommand};
+	use crate::commands::types::map::{MapDeleteCommand, MapIncrementCommand, MapItems, MapSetCommand, MapValue};
+	use crate::commands::types::ownership::TransferOwnershipCommand;
+	use crate::commands::types::room_event::{RoomEventCommand, RoomEventTarget};
+	use crate::commands::types::rpc::{RpcRequestCommand, RpcResponseCommand, RpcStatus};
 	use crate::commands::types::structure::CompareAndSetStructureCommand;
-	use crate::commands::CommandTypeId;
+	use crate::commands::types::subscription::SubscriptionCommand;
+	use crate::commands::types::timer::{CancelTimerCommand, ScheduleTimerCommand, TimerAction, TimerOwner, TimerTime};
+	use crate::commands::types::transaction::{PreconditionKind, TransactionCommand, TransactionMutation, TransactionPrecondition};
+	use crate::commands::types::ttl::SetObjectTtlCommand;
+	use crate::commands::{CommandTypeId, FieldType};
 	use crate::protocol::codec::commands::context::CommandContextError;
 	use crate::room::access::AccessGroups;
 	use crate::room::object::GameObjectId;
@@ -254,6 +528,25 @@ mod tests {
 				object_id,
 				template: 3,
 				access_groups: AccessGroups(5),
+				parent: None,
+				inherit_access_groups: false,
+			}),
+			CommandTypeId::CreateGameObject,
+			Some(object_id),
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_create_child_object() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Member(5));
+		check(
+			&C2SCommand::CreateGameObject(CreateGameObjectCommand {
+				object_id,
+				template: 3,
+				access_groups: AccessGroups(5),
+				parent: Some(GameObjectId::new(7, GameObjectOwner::Room)),
+				inherit_access_groups: true,
 			}),
 			CommandTypeId::CreateGameObject,
 			Some(object_id),
@@ -304,6 +597,24 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_decode_encode_compare_and_set_double_value() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let field_id = 77;
+		check(
+			&C2SCommand::CompareAndSetDouble(CompareAndSetDoubleCommand::new(object_id, field_id, 1.5, 2.5, Some(0.5))),
+			CommandTypeId::CompareAndSetDouble,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&C2SCommand::CompareAndSetDouble(CompareAndSetDoubleCommand::new(object_id, field_id, 1.5, 2.5, None)),
+			CommandTypeId::CompareAndSetDouble,
+			Some(object_id),
+			Some(field_id),
+		);
+	}
+
 	#[test]
 	fn should_decode_encode_compare_and_set_long_value() {
 		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
@@ -366,6 +677,42 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_decode_encode_increment_with_bounds() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let field_id = 77;
+		check(
+			&C2SCommand::IncrementLongWithBounds(IncrementLongWithBoundsC2SCommand {
+				object_id,
+				field_id,
+				increment: -10,
+				bounds: Bounds {
+					min: 0,
+					max: 100,
+					mode: BoundsMode::Clamp,
+				},
+			}),
+			CommandTypeId::IncrementLongWithBounds,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&C2SCommand::IncrementDoubleWithBounds(IncrementDoubleWithBoundsC2SCommand {
+				object_id,
+				field_id,
+				increment: 1.5,
+				bounds: Bounds {
+					min: -1.0,
+					max: 1.0,
+					mode: BoundsMode::Reject,
+				},
+			}),
+			CommandTypeId::IncrementDoubleWithBounds,
+			Some(object_id),
+			Some(field_id),
+		);
+	}
+
 	#[test]
 	fn should_decode_encode_set_structure() {
 		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
@@ -382,6 +729,22 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_decode_encode_set_string() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let field_id = 77;
+		check(
+			&C2SCommand::SetField(SetFieldCommand {
+				object_id,
+				field_id,
+				value: "Игрок".into(),
+			}),
+			CommandTypeId::SetString,
+			Some(object_id),
+			Some(field_id),
+		);
+	}
+
 	#[test]
 	fn should_decode_encode_event() {
 		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
@@ -450,6 +813,332 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_decode_encode_lockstep_input() {
+		check(
+			&C2SCommand::LockstepInput(LockstepInputCommand {
+				tick: 1000,
+				input: BinaryValue::from(vec![1, 2, 3, 4].as_slice()),
+			}),
+			CommandTypeId::LockstepInput,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_lockstep_history() {
+		check(
+			&C2SCommand::LockstepHistory(LockstepHistoryCommand { from_tick: 1000 }),
+			CommandTypeId::LockstepHistory,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_set_list() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let field_id = 77;
+		check(
+			&C2SCommand::SetField(SetFieldCommand {
+				object_id,
+				field_id,
+				value: vec![BinaryValue::from([1, 2].as_ref()), BinaryValue::from([3].as_ref())].into(),
+			}),
+			CommandTypeId::SetList,
+			Some(object_id),
+			Some(field_id),
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_list_commands() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let field_id = 77;
+		let value = BinaryValue::from([1, 2, 3].as_ref());
+		check(
+			&C2SCommand::ListPush(ListPushCommand { object_id, field_id, value }),
+			CommandTypeId::ListPush,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&C2SCommand::ListRemove(ListRemoveCommand {
+				object_id,
+				field_id,
+				index: 300,
+			}),
+			CommandTypeId::ListRemove,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&C2SCommand::ListRemoveValue(ListRemoveValueCommand { object_id, field_id, value }),
+			CommandTypeId::ListRemoveValue,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&C2SCommand::ListSetItem(ListSetItemCommand {
+				object_id,
+				field_id,
+				index: 5,
+				value,
+			}),
+			CommandTypeId::ListSetItem,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&C2SCommand::ListClear(ListClearCommand { object_id, field_id }),
+			CommandTypeId::ListClear,
+			Some(object_id),
+			Some(field_id),
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_map_commands() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let field_id = 77;
+		check(
+			&C2SCommand::SetField(SetFieldCommand {
+				object_id,
+				field_id,
+				value: MapItems::from([(5, MapValue::Long(-10)), (500, MapValue::Binary(BinaryValue::from([1, 2].as_ref())))]).into(),
+			}),
+			CommandTypeId::SetMap,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&C2SCommand::MapSet(MapSetCommand {
+				object_id,
+				field_id,
+				key: 1000,
+				value: MapValue::Double(1.5),
+			}),
+			CommandTypeId::MapSet,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&C2SCommand::MapDelete(MapDeleteCommand {
+				object_id,
+				field_id,
+				key: 1000,
+			}),
+			CommandTypeId::MapDelete,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&C2SCommand::MapIncrement(MapIncrementCommand {
+				object_id,
+				field_id,
+				key: 1000,
+				increment: MapValue::Long(5),
+			}),
+			CommandTypeId::MapIncrement,
+			Some(object_id),
+			Some(field_id),
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_transaction() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		check(
+			&C2SCommand::Transaction(TransactionCommand {
+				preconditions: vec![TransactionPrecondition {
+					object_id,
+					field_id: 1,
+					kind: PreconditionKind::GreaterThan,
+					value: 49.into(),
+				}],
+				mutations: vec![
+					TransactionMutation::IncrementLong(IncrementLongC2SCommand {
+						object_id,
+						field_id: 1,
+						increment: -50,
+					}),
+					TransactionMutation::SetField(SetFieldCommand {
+						object_id,
+						field_id: 2,
+						value: 1.5.into(),
+					}),
+					TransactionMutation::DeleteField(DeleteFieldCommand {
+						field_id: 3,
+						object_id,
+						field_type: FieldType::Structure,
+					}),
+				],
+			}),
+			CommandTypeId::Transaction,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_timer() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let field_id = 77;
+		check(
+			&C2SCommand::ScheduleTimer(ScheduleTimerCommand {
+				timer_id: 5,
+				time: TimerTime::Delay(3000),
+				owner: TimerOwner::Member,
+				action: TimerAction::Mutation(TransactionMutation::SetField(SetFieldCommand {
+					object_id,
+					field_id,
+					value: 100.into(),
+				})),
+			}),
+			CommandTypeId::ScheduleTimer,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&C2SCommand::ScheduleTimer(ScheduleTimerCommand {
+				timer_id: 6,
+				time: TimerTime::ServerTime(10000),
+				owner: TimerOwner::Room,
+				action: TimerAction::Delete(DeleteGameObjectCommand { object_id }),
+			}),
+			CommandTypeId::ScheduleTimer,
+			Some(object_id),
+			None,
+		);
+		check(
+			&C2SCommand::CancelTimer(CancelTimerCommand { timer_id: 5 }),
+			CommandTypeId::CancelTimer,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_set_object_ttl() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		check(
+			&C2SCommand::SetObjectTtl(SetObjectTtlCommand { object_id, ttl: 10_000 }),
+			CommandTypeId::SetObjectTtl,
+			Some(object_id),
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_transfer_ownership() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Member(5));
+		check(
+			&C2SCommand::TransferOwnership(TransferOwnershipCommand { object_id, new_owner: 300 }),
+			CommandTypeId::TransferOwnership,
+			Some(object_id),
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_set_object_access_groups() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Member(5));
+		check(
+			&C2SCommand::SetObjectAccessGroups(SetObjectAccessGroupsCommand {
+				object_id,
+				access_groups: AccessGroups(0b1010),
+			}),
+			CommandTypeId::SetObjectAccessGroups,
+			Some(object_id),
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_subscribe() {
+		check(
+			&C2SCommand::Subscribe(SubscriptionCommand {
+				templates: vec![1, 300],
+				fields: vec![5],
+			}),
+			CommandTypeId::Subscribe,
+			None,
+			None,
+		);
+		check(
+			&C2SCommand::Unsubscribe(SubscriptionCommand::default()),
+			CommandTypeId::Unsubscribe,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_rpc() {
+		check(
+			&C2SCommand::RpcRequest(RpcRequestCommand {
+				member_id: 5,
+				call_id: 1000,
+				timeout: 3000,
+				payload: BinaryValue::from([1, 2, 3].as_ref()),
+			}),
+			CommandTypeId::RpcRequest,
+			None,
+			None,
+		);
+		check(
+			&C2SCommand::RpcResponse(RpcResponseCommand {
+				member_id: 7,
+				call_id: 1000,
+				status: RpcStatus::Error,
+				payload: BinaryValue::from([4, 5].as_ref()),
+			}),
+			CommandTypeId::RpcResponse,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_room_event() {
+		check(
+			&C2SCommand::RoomEvent(RoomEventCommand {
+				event_id: 300,
+				target: RoomEventTarget::Groups(AccessGroups(0b101)),
+				event: BinaryValue::from([1, 2, 3].as_ref()),
+			}),
+			CommandTypeId::RoomEvent,
+			None,
+			None,
+		);
+		check(
+			&C2SCommand::RoomEvent(RoomEventCommand {
+				event_id: 1,
+				target: RoomEventTarget::Members(vec![1, 500]),
+				event: BinaryValue::default(),
+			}),
+			CommandTypeId::RoomEvent,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_field_history() {
+		check(
+			&C2SCommand::FieldHistory(FieldHistoryQueryCommand {
+				object_id: GameObjectId::new(100, GameObjectOwner::Member(5)),
+				field_id: 10,
+				field_type: FieldType::Double,
+				time: 123_456,
+			}),
+			CommandTypeId::FieldHistory,
+			None,
+			None,
+		);
+	}
+
 	fn check(expected: &C2SCommand, command_type_id: CommandTypeId, object_id: Option<GameObjectId>, field_id: Option<FieldId>) {
 		let object_id = object_id.ok_or(CommandContextError::ContextNotContainsObjectId);
 		let field_id = field_id.ok_or(CommandContextError::ContextNotContainsFieldId);
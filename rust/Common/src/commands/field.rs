@@ -4,6 +4,7 @@ use std::io::ErrorKind;
 use byteorder::{ReadBytesExt, WriteBytesExt};
 
 use crate::commands::binary_value::BinaryValue;
+use crate::commands::types::map::MapItems;
 
 ///
 /// Тип данных поля
@@ -15,6 +16,9 @@ pub enum FieldType {
 	Double,
 	Structure,
 	Event,
+	List,
+	Map,
+	String,
 }
 
 impl hash32::Hash for FieldType {
@@ -33,6 +37,9 @@ impl ToString for FieldType {
 			FieldType::Double => "double",
 			FieldType::Structure => "structure",
 			FieldType::Event => "event",
+			FieldType::List => "list",
+			FieldType::Map => "map",
+			FieldType::String => "string",
 		}
 		.into()
 	}
@@ -45,6 +52,9 @@ impl FieldType {
 			FieldType::Double => 2,
 			FieldType::Structure => 3,
 			FieldType::Event => 4,
+			FieldType::List => 5,
+			FieldType::Map => 6,
+			FieldType::String => 7,
 		};
 		out.write_u8(code)
 	}
@@ -56,6 +66,9 @@ impl FieldType {
 			2 => FieldType::Double,
 			3 => FieldType::Structure,
 			4 => FieldType::Event,
+			5 => FieldType::List,
+			6 => FieldType::Map,
+			7 => FieldType::String,
 			_ => return Err(std::io::Error::new(ErrorKind::InvalidData, format!("Read FieldType with code {value}"))),
 		})
 	}
@@ -83,6 +96,24 @@ impl ToFieldType for BinaryValue {
 	}
 }
 
+impl ToFieldType for Vec<BinaryValue> {
+	fn to_field_type() -> FieldType {
+		FieldType::List
+	}
+}
+
+impl ToFieldType for MapItems {
+	fn to_field_type() -> FieldType {
+		FieldType::Map
+	}
+}
+
+impl ToFieldType for String {
+	fn to_field_type() -> FieldType {
+		FieldType::String
+	}
+}
+
 pub type FieldId = u16;
 
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
@@ -103,6 +134,9 @@ mod test {
 		check(FieldType::Structure);
 		check(FieldType::Double);
 		check(FieldType::Event);
+		check(FieldType::List);
+		check(FieldType::Map);
+		check(FieldType::String);
 	}
 
 	fn check(original: FieldType) {
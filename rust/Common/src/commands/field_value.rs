@@ -1,4 +1,4 @@
-use std::io::Cursor;
+use std::io::{Cursor, Error, ErrorKind};
 
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 
@@ -7,14 +7,23 @@ use crate::{
 	protocol::codec::variable_int::{VariableIntReader, VariableIntWriter},
 };
 
-use super::{binary_value::BinaryValue, field::ToFieldType};
+use super::{
+	binary_value::BinaryValue,
+	field::ToFieldType,
+	types::list::MAX_LIST_ITEMS,
+	types::map::{decode_items, encode_items, MapItems},
+	types::string::{decode_string, encode_string},
+};
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq)]
 #[repr(u8)]
 pub enum FieldValue {
 	Long(i64),
 	Double(f64),
 	Structure(BinaryValue),
+	List(Vec<BinaryValue>),
+	Map(MapItems),
+	String(String),
 }
 
 impl FieldValue {
@@ -24,6 +33,9 @@ impl FieldValue {
 			FieldValue::Long(_) => FieldType::Long,
 			FieldValue::Double(_) => FieldType::Double,
 			FieldValue::Structure(_) => FieldType::Structure,
+			FieldValue::List(_) => FieldType::List,
+			FieldValue::Map(_) => FieldType::Map,
+			FieldValue::String(_) => FieldType::String,
 		}
 	}
 }
@@ -34,16 +46,37 @@ impl FieldValue {
 			FieldValue::Long(v) => out.write_variable_i64(*v),
 			FieldValue::Double(v) => out.write_f64::<BigEndian>(*v),
 			FieldValue::Structure(v) => v.encode(out),
+			FieldValue::List(items) => {
+				out.write_variable_u64(items.len() as u64)?;
+				items.iter().try_for_each(|item| item.encode(out))
+			}
+			FieldValue::Map(items) => encode_items(items, out),
+			FieldValue::String(value) => encode_string(value, out),
 		}
 	}
 
 	pub fn decode<T: Into<Self> + ToFieldType>(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
-		let field_type = T::to_field_type();
+		Self::decode_by_type(T::to_field_type(), input)
+	}
+
+	///
+	/// Чтение значения, тип которого известен только во время выполнения
+	///
+	pub fn decode_by_type(field_type: FieldType, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
 		Ok(match field_type {
 			FieldType::Long => input.read_variable_i64()?.into(),
 			FieldType::Double => input.read_f64::<BigEndian>()?.into(),
 			FieldType::Structure => BinaryValue::decode(input)?.into(),
-			FieldType::Event => panic!("Event type is not supported"),
+			FieldType::List => {
+				let len: usize = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+				if len > MAX_LIST_ITEMS {
+					return Err(Error::new(ErrorKind::InvalidData, format!("List items count to big {len}")));
+				}
+				(0..len).map(|_| BinaryValue::decode(input)).collect::<std::io::Result<Vec<_>>>()?.into()
+			}
+			FieldType::Map => decode_items(input)?.into(),
+			FieldType::String => decode_string(input)?.into(),
+			FieldType::Event => return Err(Error::new(ErrorKind::InvalidData, "Event type is not supported")),
 		})
 	}
 }
@@ -76,6 +109,30 @@ impl From<BinaryValue> for FieldValue {
 	}
 }
 
+impl From<Vec<BinaryValue>> for FieldValue {
+	fn from(value: Vec<BinaryValue>) -> Self {
+		FieldValue::List(value)
+	}
+}
+
+impl From<MapItems> for FieldValue {
+	fn from(value: MapItems) -> Self {
+		FieldValue::Map(value)
+	}
+}
+
+impl From<String> for FieldValue {
+	fn from(value: String) -> Self {
+		FieldValue::String(value)
+	}
+}
+
+impl From<&str> for FieldValue {
+	fn from(value: &str) -> Self {
+		FieldValue::String(value.to_owned())
+	}
+}
+
 impl AsRef<f64> for FieldValue {
 	fn as_ref(&self) -> &f64 {
 		if let FieldValue::Double(v) = self {
@@ -106,12 +163,43 @@ impl AsRef<BinaryValue> for FieldValue {
 	}
 }
 
+impl AsRef<Vec<BinaryValue>> for FieldValue {
+	fn as_ref(&self) -> &Vec<BinaryValue> {
+		if let FieldValue::List(v) = self {
+			v
+		} else {
+			panic!("FieldValue had unexpected variant, expected FieldValue::List")
+		}
+	}
+}
+
+impl AsRef<MapItems> for FieldValue {
+	fn as_ref(&self) -> &MapItems {
+		if let FieldValue::Map(v) = self {
+			v
+		} else {
+			panic!("FieldValue had unexpected variant, expected FieldValue::Map")
+		}
+	}
+}
+
+impl AsRef<String> for FieldValue {
+	fn as_ref(&self) -> &String {
+		if let FieldValue::String(v) = self {
+			v
+		} else {
+			panic!("FieldValue had unexpected variant, expected FieldValue::String")
+		}
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use std::io::Cursor;
 
 	use crate::commands::binary_value::BinaryValue;
 	use crate::commands::field::ToFieldType;
+	use crate::commands::types::map::{MapItems, MapValue};
 	use crate::commands::FieldValue;
 
 	#[test]
@@ -119,6 +207,17 @@ mod test {
 		check::<i64>(FieldValue::Long(100));
 		check::<f64>(FieldValue::Double(100.100));
 		check::<BinaryValue>(FieldValue::Structure(BinaryValue::from([1, 2, 3].as_ref())));
+		check::<Vec<BinaryValue>>(FieldValue::List(vec![]));
+		check::<MapItems>(FieldValue::Map(MapItems::from([
+			(1, MapValue::Long(100)),
+			(1000, MapValue::Double(1.5)),
+			(u64::MAX, MapValue::Binary(BinaryValue::from([1, 2].as_ref()))),
+		])));
+		check::<Vec<BinaryValue>>(FieldValue::List(vec![
+			BinaryValue::from([1, 2].as_ref()),
+			BinaryValue::from([3].as_ref()),
+		]));
+		check::<String>(FieldValue::String("Привет, world".to_owned()));
 	}
 
 	fn check<T: Into<FieldValue> + ToFieldType>(original: FieldValue) {
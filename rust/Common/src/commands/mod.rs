@@ -36,6 +36,38 @@ pub enum CommandTypeId {
 	CompareAndSetStructure,
 	Forwarded,
 	MemberConnected,
+	SetStructureDelta,
+	LockstepInput,
+	LockstepTick,
+	LockstepHistory,
+	CommandRejected,
+	SetList,
+	ListPush,
+	ListRemove,
+	ListRemoveValue,
+	ListSetItem,
+	ListClear,
+	SetMap,
+	MapSet,
+	MapDelete,
+	MapIncrement,
+	SetString,
+	IncrementLongWithBounds,
+	IncrementDoubleWithBounds,
+	CompareAndSetDouble,
+	Transaction,
+	ScheduleTimer,
+	CancelTimer,
+	SetObjectTtl,
+	TransferOwnership,
+	SetObjectAccessGroups,
+	Subscribe,
+	Unsubscribe,
+	RpcRequest,
+	RpcResponse,
+	RoomEvent,
+	FieldViolation,
+	FieldHistory,
 }
 
 #[derive(Error, Debug)]
@@ -9,8 +9,18 @@ use crate::commands::types::create::{CreateGameObjectCommand, GameObjectCreatedS
 use crate::commands::types::delete::DeleteGameObjectCommand;
 use crate::commands::types::event::EventCommand;
 use crate::commands::types::field::{DeleteFieldCommand, SetFieldCommand};
+use crate::commands::types::field_history::FieldHistoryValueCommand;
+use crate::commands::types::field_violation::FieldViolationCommand;
 use crate::commands::types::forwarded::ForwardedCommand;
+use crate::commands::types::list::{ListClearCommand, ListPushCommand, ListRemoveCommand, ListSetItemCommand};
+use crate::commands::types::lockstep::{LockstepMemberInputCommand, LockstepTickCommand};
+use crate::commands::types::map::{MapDeleteCommand, MapItems, MapSetCommand};
 use crate::commands::types::member_connected::MemberConnectedCommand;
+use crate::commands::types::ownership::TransferOwnershipCommand;
+use crate::commands::types::rejected::CommandRejectedCommand;
+use crate::commands::types::room_event::RoomEventS2CCommand;
+use crate::commands::types::rpc::{RpcRequestCommand, RpcResponseCommand};
+use crate::commands::types::structure::SetStructureDeltaCommand;
 use crate::commands::{CommandDecodeError, CommandTypeId, FieldType};
 use crate::protocol::codec::commands::context::CommandContextError;
 use crate::room::object::GameObjectId;
@@ -27,12 +37,32 @@ pub enum S2CCommand {
 	DeleteField(DeleteFieldCommand),
 	Forwarded(Box<ForwardedCommand>),
 	MemberConnected(MemberConnectedCommand),
+	SetStructureDelta(SetStructureDeltaCommand),
+	LockstepInput(LockstepMemberInputCommand),
+	LockstepTick(LockstepTickCommand),
+	CommandRejected(CommandRejectedCommand),
+	ListPush(ListPushCommand),
+	ListRemove(ListRemoveCommand),
+	ListSetItem(ListSetItemCommand),
+	ListClear(ListClearCommand),
+	MapSet(MapSetCommand),
+	MapDelete(MapDeleteCommand),
+	TransferOwnership(TransferOwnershipCommand),
+	RpcRequest(RpcRequestCommand),
+	RpcResponse(RpcResponseCommand),
+	RoomEvent(RoomEventS2CCommand),
+	FieldViolation(FieldViolationCommand),
+	FieldHistory(FieldHistoryValueCommand),
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct S2CCommandWithCreator {
 	pub command: S2CCommand,
 	pub creator: RoomMemberId,
+	///
+	/// Время сервера в миллисекундах от создания комнаты, передается если включено в настройках комнаты
+	///
+	pub time: Option<u64>,
 }
 
 #[derive(Debug)]
@@ -59,6 +89,22 @@ impl S2CCommand {
 			S2CCommand::DeleteField(command) => Some(command.field_id),
 			S2CCommand::Forwarded(command) => command.c2s.get_field_id(),
 			S2CCommand::MemberConnected(_) => None,
+			S2CCommand::SetStructureDelta(command) => Some(command.field_id),
+			S2CCommand::LockstepInput(_) => None,
+			S2CCommand::LockstepTick(_) => None,
+			S2CCommand::CommandRejected(_) => None,
+			S2CCommand::ListPush(command) => Some(command.field_id),
+			S2CCommand::ListRemove(command) => Some(command.field_id),
+			S2CCommand::ListSetItem(command) => Some(command.field_id),
+			S2CCommand::ListClear(command) => Some(command.field_id),
+			S2CCommand::MapSet(command) => Some(command.field_id),
+			S2CCommand::MapDelete(command) => Some(command.field_id),
+			S2CCommand::TransferOwnership(_) => None,
+			S2CCommand::RpcRequest(_) => None,
+			S2CCommand::RpcResponse(_) => None,
+			S2CCommand::RoomEvent(_) => None,
+			S2CCommand::FieldViolation(_) => None,
+			S2CCommand::FieldHistory(_) => None,
 		}
 	}
 
@@ -73,6 +119,22 @@ impl S2CCommand {
 			S2CCommand::DeleteField(command) => Some(command.object_id),
 			S2CCommand::Forwarded(command) => command.c2s.get_object_id(),
 			S2CCommand::MemberConnected(_) => None,
+			S2CCommand::SetStructureDelta(command) => Some(command.object_id),
+			S2CCommand::LockstepInput(_) => None,
+			S2CCommand::LockstepTick(_) => None,
+			S2CCommand::CommandRejected(_) => None,
+			S2CCommand::ListPush(command) => Some(command.object_id),
+			S2CCommand::ListRemove(command) => Some(command.object_id),
+			S2CCommand::ListSetItem(command) => Some(command.object_id),
+			S2CCommand::ListClear(command) => Some(command.object_id),
+			S2CCommand::MapSet(command) => Some(command.object_id),
+			S2CCommand::MapDelete(command) => Some(command.object_id),
+			S2CCommand::TransferOwnership(command) => Some(command.object_id),
+			S2CCommand::RpcRequest(_) => None,
+			S2CCommand::RpcResponse(_) => None,
+			S2CCommand::RoomEvent(_) => None,
+			S2CCommand::FieldViolation(_) => None,
+			S2CCommand::FieldHistory(_) => None,
 		}
 	}
 
@@ -87,6 +149,22 @@ impl S2CCommand {
 			S2CCommand::DeleteField(command) => Some(command.field_type),
 			S2CCommand::Forwarded(command) => command.c2s.get_field_type(),
 			S2CCommand::MemberConnected(_) => None,
+			S2CCommand::SetStructureDelta(_) => Some(FieldType::Structure),
+			S2CCommand::LockstepInput(_) => None,
+			S2CCommand::LockstepTick(_) => None,
+			S2CCommand::CommandRejected(_) => None,
+			S2CCommand::ListPush(_) => Some(FieldType::List),
+			S2CCommand::ListRemove(_) => Some(FieldType::List),
+			S2CCommand::ListSetItem(_) => Some(FieldType::List),
+			S2CCommand::ListClear(_) => Some(FieldType::List),
+			S2CCommand::MapSet(_) => Some(FieldType::Map),
+			S2CCommand::MapDelete(_) => Some(FieldType::Map),
+			S2CCommand::TransferOwnership(_) => None,
+			S2CCommand::RpcRequest(_) => None,
+			S2CCommand::RpcResponse(_) => None,
+			S2CCommand::RoomEvent(_) => None,
+			S2CCommand::FieldViolation(_) => None,
+			S2CCommand::FieldHistory(_) => None,
 		}
 	}
 
@@ -99,12 +177,31 @@ impl S2CCommand {
 				FieldValue::Long(_) => CommandTypeId::SetLong,
 				FieldValue::Double(_) => CommandTypeId::SetDouble,
 				FieldValue::Structure(_) => CommandTypeId::SetStructure,
+				FieldValue::List(_) => CommandTypeId::SetList,
+				FieldValue::Map(_) => CommandTypeId::SetMap,
+				FieldValue::String(_) => CommandTypeId::SetString,
 			},
 			S2CCommand::Event(_) => CommandTypeId::Event,
 			S2CCommand::Delete(_) => CommandTypeId::Delete,
 			S2CCommand::DeleteField(_) => CommandTypeId::DeleteField,
 			S2CCommand::Forwarded(_) => CommandTypeId::Forwarded,
 			S2CCommand::MemberConnected(_) => CommandTypeId::MemberConnected,
+			S2CCommand::SetStructureDelta(_) => CommandTypeId::SetStructureDelta,
+			S2CCommand::LockstepInput(_) => CommandTypeId::LockstepInput,
+			S2CCommand::LockstepTick(_) => CommandTypeId::LockstepTick,
+			S2CCommand::CommandRejected(_) => CommandTypeId::CommandRejected,
+			S2CCommand::ListPush(_) => CommandTypeId::ListPush,
+			S2CCommand::ListRemove(_) => CommandTypeId::ListRemove,
+			S2CCommand::ListSetItem(_) => CommandTypeId::ListSetItem,
+			S2CCommand::ListClear(_) => CommandTypeId::ListClear,
+			S2CCommand::MapSet(_) => CommandTypeId::MapSet,
+			S2CCommand::MapDelete(_) => CommandTypeId::MapDelete,
+			S2CCommand::TransferOwnership(_) => CommandTypeId::TransferOwnership,
+			S2CCommand::RpcRequest(_) => CommandTypeId::RpcRequest,
+			S2CCommand::RpcResponse(_) => CommandTypeId::RpcResponse,
+			S2CCommand::RoomEvent(_) => CommandTypeId::RoomEvent,
+			S2CCommand::FieldViolation(_) => CommandTypeId::FieldViolation,
+			S2CCommand::FieldHistory(_) => CommandTypeId::FieldHistory,
 		}
 	}
 
@@ -119,6 +216,40 @@ impl S2CCommand {
 			S2CCommand::DeleteField(_) => String::new(),
 			S2CCommand::Forwarded(command) => format!("forward: member({:?}) command({:?})", command.creator, command.c2s.get_trace_string()),
 			S2CCommand::MemberConnected(command) => format!("member connected({:?})", command.member_id),
+			S2CCommand::SetStructureDelta(command) => format!("delta({:?}) len({:?})", command.changes, command.len),
+			S2CCommand::LockstepInput(command) => format!(
+				"tick = {:?}, member = {:?}, filled = {:?}, input = {:?}",
+				command.tick, command.member_id, command.filled, command.input
+			),
+			S2CCommand::LockstepTick(command) => format!("tick = {:?}, inputs = {:?}", command.tick, command.inputs),
+			S2CCommand::CommandRejected(command) => format!(
+				"command = {:?}, reason = {:?}, object = {:?}, field = {:?}",
+				command.command_type_id, command.reason, command.object_id, command.field_id
+			),
+			S2CCommand::ListPush(command) => format!("{:?}", command.value),
+			S2CCommand::ListRemove(command) => format!("index = {:?}", command.index),
+			S2CCommand::ListSetItem(command) => format!("index = {:?}, value = {:?}", command.index, command.value),
+			S2CCommand::ListClear(_) => String::new(),
+			S2CCommand::MapSet(command) => format!("key = {:?}, value = {:?}", command.key, command.value),
+			S2CCommand::MapDelete(command) => format!("key = {:?}", command.key),
+			S2CCommand::TransferOwnership(command) => format!("new_owner = {:?}", command.new_owner),
+			S2CCommand::RpcRequest(command) => format!(
+				"caller = {:?}, call_id = {:?}, timeout = {:?}, payload = {:?}",
+				command.member_id, command.call_id, command.timeout, command.payload
+			),
+			S2CCommand::RpcResponse(command) => format!(
+				"target = {:?}, call_id = {:?}, status = {:?}, payload = {:?}",
+				command.member_id, command.call_id, command.status, command.payload
+			),
+			S2CCommand::RoomEvent(command) => format!("event_id = {:?}, event = {:?}", command.event_id, command.event),
+			S2CCommand::FieldViolation(command) => format!(
+				"member = {:?}, object = {:?}, field = {:?}, field_type = {:?}",
+				command.member_id, command.object_id, command.field_id, command.field_type
+			),
+			S2CCommand::FieldHistory(command) => format!(
+				"object = {:?}, field = {:?}, time = {:?}, value = {:?}",
+				command.object_id, command.field_id, command.time, command.value
+			),
 		}
 	}
 
@@ -132,6 +263,22 @@ impl S2CCommand {
 			S2CCommand::DeleteField(command) => command.encode(out),
 			S2CCommand::Forwarded(command) => command.encode(out),
 			S2CCommand::MemberConnected(command) => command.encode(out),
+			S2CCommand::SetStructureDelta(command) => command.encode(out),
+			S2CCommand::LockstepInput(command) => command.encode(out),
+			S2CCommand::LockstepTick(command) => command.encode(out),
+			S2CCommand::CommandRejected(command) => command.encode(out),
+			S2CCommand::ListPush(command) => command.encode(out),
+			S2CCommand::ListRemove(command) => command.encode(out),
+			S2CCommand::ListSetItem(command) => command.encode(out),
+			S2CCommand::ListClear(_) => Ok(()),
+			S2CCommand::MapSet(command) => command.encode(out),
+			S2CCommand::MapDelete(command) => command.encode(out),
+			S2CCommand::TransferOwnership(command) => command.encode(out),
+			S2CCommand::RpcRequest(command) => command.encode(out),
+			S2CCommand::RpcResponse(command) => command.encode(out),
+			S2CCommand::RoomEvent(command) => command.encode(out),
+			S2CCommand::FieldViolation(command) => command.encode(out),
+			S2CCommand::FieldHistory(command) => command.encode(out),
 		}
 	}
 
@@ -148,10 +295,32 @@ impl S2CCommand {
 			CommandTypeId::SetLong => S2CCommand::SetField(SetFieldCommand::decode::<i64>(object_id?, field_id?, input)?),
 			CommandTypeId::SetDouble => S2CCommand::SetField(SetFieldCommand::decode::<f64>(object_id?, field_id?, input)?),
 			CommandTypeId::SetStructure => S2CCommand::SetField(SetFieldCommand::decode::<BinaryValue>(object_id?, field_id?, input)?),
+			CommandTypeId::SetList => S2CCommand::SetField(SetFieldCommand::decode::<Vec<BinaryValue>>(object_id?, field_id?, input)?),
+			CommandTypeId::SetMap => S2CCommand::SetField(SetFieldCommand::decode::<MapItems>(object_id?, field_id?, input)?),
+			CommandTypeId::SetString => S2CCommand::SetField(SetFieldCommand::decode::<String>(object_id?, field_id?, input)?),
 			CommandTypeId::Event => S2CCommand::Event(EventCommand::decode(object_id?, field_id?, input)?),
 			CommandTypeId::DeleteField => S2CCommand::DeleteField(DeleteFieldCommand::decode(object_id?, field_id?, input)?),
 			CommandTypeId::Forwarded => S2CCommand::Forwarded(Box::new(ForwardedCommand::decode(object_id, field_id, input)?)),
 			CommandTypeId::MemberConnected => S2CCommand::MemberConnected(MemberConnectedCommand::decode(input)?),
+			CommandTypeId::SetStructureDelta => S2CCommand::SetStructureDelta(SetStructureDeltaCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::LockstepInput => S2CCommand::LockstepInput(LockstepMemberInputCommand::decode(input)?),
+			CommandTypeId::LockstepTick => S2CCommand::LockstepTick(LockstepTickCommand::decode(input)?),
+			CommandTypeId::CommandRejected => S2CCommand::CommandRejected(CommandRejectedCommand::decode(input)?),
+			CommandTypeId::ListPush => S2CCommand::ListPush(ListPushCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::ListRemove => S2CCommand::ListRemove(ListRemoveCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::ListSetItem => S2CCommand::ListSetItem(ListSetItemCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::ListClear => S2CCommand::ListClear(ListClearCommand {
+				object_id: object_id?,
+				field_id: field_id?,
+			}),
+			CommandTypeId::MapSet => S2CCommand::MapSet(MapSetCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::MapDelete => S2CCommand::MapDelete(MapDeleteCommand::decode(object_id?, field_id?, input)?),
+			CommandTypeId::TransferOwnership => S2CCommand::TransferOwnership(TransferOwnershipCommand::decode(object_id?, input)?),
+			CommandTypeId::RpcRequest => S2CCommand::RpcRequest(RpcRequestCommand::decode(input)?),
+			CommandTypeId::RpcResponse => S2CCommand::RpcResponse(RpcResponseCommand::decode(input)?),
+			CommandTypeId::RoomEvent => S2CCommand::RoomEvent(RoomEventS2CCommand::decode(input)?),
+			CommandTypeId::FieldViolation => S2CCommand::FieldViolation(FieldViolationCommand::decode(input)?),
+			CommandTypeId::FieldHistory => S2CCommand::FieldHistory(FieldHistoryValueCommand::decode(input)?),
 			_ => return Err(CommandDecodeError::UnknownTypeId(*command_type_id)),
 		})
 	}
@@ -163,13 +332,23 @@ mod tests {
 
 	use crate::commands::binary_value::BinaryValue;
 	use crate::commands::c2s::C2SCommand;
-	use crate::commands::field::FieldId;
+	use crate::commands::field::{FieldId, FieldType};
 	use crate::commands::types::create::{CreateGameObjectCommand, GameObjectCreatedS2CCommand};
 	use crate::commands::types::delete::DeleteGameObjectCommand;
 	use crate::commands::types::event::TargetEventCommand;
 	use crate::commands::types::field::SetFieldCommand;
+	use crate::commands::types::field_history::FieldHistoryValueCommand;
+	use crate::commands::types::field_violation::FieldViolationCommand;
 	use crate::commands::types::forwarded::ForwardedCommand;
+	use crate::commands::types::list::{ListClearCommand, ListPushCommand, ListRemoveCommand, ListSetItemCommand};
+	use crate::commands::types::lockstep::{LockstepMemberInputCommand, LockstepTickCommand};
+	use crate::commands::types::map::{MapDeleteCommand, MapItems, MapSetCommand, MapValue};
 	use crate::commands::types::member_connected::MemberConnectedCommand;
+	use crate::commands::types::ownership::TransferOwnershipCommand;
+	use crate::commands::types::rejected::{CommandRejectReason, CommandRejectedCommand};
+	use crate::commands::types::room_event::RoomEventS2CCommand;
+	use crate::commands::types::rpc::{RpcRequestCommand, RpcResponseCommand, RpcStatus};
+	use crate::commands::types::structure::SetStructureDeltaCommand;
 	use crate::commands::CommandTypeId;
 	use crate::{
 		commands::s2c::S2CCommand, commands::types::event::EventCommand, protocol::codec::commands::context::CommandContextError,
@@ -184,6 +363,8 @@ mod tests {
 				object_id,
 				template: 3,
 				access_groups: AccessGroups(5),
+				parent: None,
+				inherit_access_groups: false,
 			}),
 			CommandTypeId::CreateGameObject,
 			Some(object_id),
@@ -250,6 +431,22 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_decode_encode_set_string() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let field_id = 77;
+		check(
+			&S2CCommand::SetField(SetFieldCommand {
+				object_id,
+				field_id,
+				value: "Игрок".into(),
+			}),
+			CommandTypeId::SetString,
+			Some(object_id),
+			Some(field_id),
+		);
+	}
+
 	#[test]
 	fn should_decode_encode_event() {
 		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
@@ -309,6 +506,255 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_decode_encode_set_structure_delta() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let field_id = 77;
+		check(
+			&S2CCommand::SetStructureDelta(SetStructureDeltaCommand {
+				object_id,
+				field_id,
+				len: 10,
+				changes: BinaryValue::from([5, 2, 1, 2].as_ref()),
+			}),
+			CommandTypeId::SetStructureDelta,
+			Some(object_id),
+			Some(field_id),
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_lockstep_input() {
+		check(
+			&S2CCommand::LockstepInput(LockstepMemberInputCommand {
+				tick: 1000,
+				member_id: 5,
+				filled: true,
+				input: BinaryValue::from(vec![1, 2, 3, 4].as_slice()),
+			}),
+			CommandTypeId::LockstepInput,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_lockstep_tick() {
+		check(
+			&S2CCommand::LockstepTick(LockstepTickCommand { tick: 1000, inputs: 4 }),
+			CommandTypeId::LockstepTick,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_command_rejected() {
+		check(
+			&S2CCommand::CommandRejected(CommandRejectedCommand {
+				command_type_id: CommandTypeId::SetLong,
+				reason: CommandRejectReason::MemberCannotAccessToObjectField,
+				object_id: Some(GameObjectId::new(100, GameObjectOwner::Member(5))),
+				field_id: Some(10),
+			}),
+			CommandTypeId::CommandRejected,
+			None,
+			None,
+		);
+		check(
+			&S2CCommand::CommandRejected(CommandRejectedCommand {
+				command_type_id: CommandTypeId::LockstepInput,
+				reason: CommandRejectReason::LockstepTickOutOfRange,
+				object_id: None,
+				field_id: None,
+			}),
+			CommandTypeId::CommandRejected,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_list_commands() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let field_id = 77;
+		let value = BinaryValue::from([1, 2, 3].as_ref());
+		check(
+			&S2CCommand::SetField(SetFieldCommand {
+				object_id,
+				field_id,
+				value: vec![value].into(),
+			}),
+			CommandTypeId::SetList,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&S2CCommand::ListPush(ListPushCommand { object_id, field_id, value }),
+			CommandTypeId::ListPush,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&S2CCommand::ListRemove(ListRemoveCommand {
+				object_id,
+				field_id,
+				index: 300,
+			}),
+			CommandTypeId::ListRemove,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&S2CCommand::ListSetItem(ListSetItemCommand {
+				object_id,
+				field_id,
+				index: 5,
+				value,
+			}),
+			CommandTypeId::ListSetItem,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&S2CCommand::ListClear(ListClearCommand { object_id, field_id }),
+			CommandTypeId::ListClear,
+			Some(object_id),
+			Some(field_id),
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_map_commands() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let field_id = 77;
+		check(
+			&S2CCommand::SetField(SetFieldCommand {
+				object_id,
+				field_id,
+				value: MapItems::from([(5, MapValue::Long(-10)), (500, MapValue::Double(2.5))]).into(),
+			}),
+			CommandTypeId::SetMap,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&S2CCommand::MapSet(MapSetCommand {
+				object_id,
+				field_id,
+				key: 1000,
+				value: MapValue::Binary(BinaryValue::from([1, 2].as_ref())),
+			}),
+			CommandTypeId::MapSet,
+			Some(object_id),
+			Some(field_id),
+		);
+		check(
+			&S2CCommand::MapDelete(MapDeleteCommand {
+				object_id,
+				field_id,
+				key: 1000,
+			}),
+			CommandTypeId::MapDelete,
+			Some(object_id),
+			Some(field_id),
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_transfer_ownership() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		check(
+			&S2CCommand::TransferOwnership(TransferOwnershipCommand { object_id, new_owner: 7 }),
+			CommandTypeId::TransferOwnership,
+			Some(object_id),
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_rpc() {
+		check(
+			&S2CCommand::RpcRequest(RpcRequestCommand {
+				member_id: 5,
+				call_id: 1000,
+				timeout: 3000,
+				payload: BinaryValue::from([1, 2, 3].as_ref()),
+			}),
+			CommandTypeId::RpcRequest,
+			None,
+			None,
+		);
+		check(
+			&S2CCommand::RpcResponse(RpcResponseCommand {
+				member_id: 7,
+				call_id: 1000,
+				status: RpcStatus::Timeout,
+				payload: BinaryValue::default(),
+			}),
+			CommandTypeId::RpcResponse,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_room_event() {
+		check(
+			&S2CCommand::RoomEvent(RoomEventS2CCommand {
+				event_id: 300,
+				event: BinaryValue::from([1, 2, 3].as_ref()),
+			}),
+			CommandTypeId::RoomEvent,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_field_violation() {
+		check(
+			&S2CCommand::FieldViolation(FieldViolationCommand {
+				member_id: 7,
+				object_id: GameObjectId::new(100, GameObjectOwner::Room),
+				field_id: 10,
+				field_type: FieldType::Double,
+			}),
+			CommandTypeId::FieldViolation,
+			None,
+			None,
+		);
+	}
+
+	#[test]
+	fn should_decode_encode_field_history() {
+		let object_id = GameObjectId::new(100, GameObjectOwner::Member(5));
+		check(
+			&S2CCommand::FieldHistory(FieldHistoryValueCommand {
+				object_id,
+				field_id: 10,
+				field_type: FieldType::Double,
+				time: 123_456,
+				value: Some(1.5.into()),
+			}),
+			CommandTypeId::FieldHistory,
+			None,
+			None,
+		);
+		check(
+			&S2CCommand::FieldHistory(FieldHistoryValueCommand {
+				object_id,
+				field_id: 10,
+				field_type: FieldType::Long,
+				time: 0,
+				value: None,
+			}),
+			CommandTypeId::FieldHistory,
+			None,
+			None,
+		);
+	}
+
 	fn check(expected: &S2CCommand, command_type_id: CommandTypeId, object_id: Option<GameObjectId>, field_id: Option<FieldId>) {
 		let object_id = object_id.ok_or(CommandContextError::ContextNotContainsObjectId);
 		let field_id = field_id.ok_or(CommandContextError::ContextNotContainsFieldId);
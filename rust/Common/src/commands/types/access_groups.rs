@@ -0,0 +1,28 @@
+use std::io::Cursor;
+
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::access::AccessGroups;
+use crate::room::object::GameObjectId;
+
+///
+/// Изменить группы доступа объекта
+///
+/// - пользователям, получившим доступ к объекту, отправляется создание объекта, потерявшим - удаление
+/// - C->S
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetObjectAccessGroupsCommand {
+	pub object_id: GameObjectId,
+	pub access_groups: AccessGroups,
+}
+
+impl SetObjectAccessGroupsCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.access_groups.0)
+	}
+
+	pub fn decode(object_id: GameObjectId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let access_groups = AccessGroups(input.read_variable_u64()?);
+		Ok(Self { object_id, access_groups })
+	}
+}
@@ -0,0 +1,147 @@
+use std::cmp::Ordering;
+use std::io::{Cursor, Error, ErrorKind};
+
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::FromPrimitive;
+
+///
+/// Поведение при выходе значения за границы
+///
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum BoundsMode {
+	///
+	/// Значение ограничивается ближайшей границей
+	///
+	Clamp = 0,
+	///
+	/// Команда отклоняется, значение поля не меняется
+	///
+	Reject,
+}
+
+///
+/// Допустимый диапазон значения поля (включая границы)
+///
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Bounds<T> {
+	pub min: T,
+	pub max: T,
+	pub mode: BoundsMode,
+}
+
+impl<T: PartialOrd + Copy> Bounds<T> {
+	///
+	/// Значение с учетом границ, None - значение вне границ и команду необходимо отклонить
+	///
+	/// Несравнимое с границами значение (NaN) не может быть ограничено и отклоняется в любом режиме.
+	///
+	pub fn apply(&self, value: T) -> Option<T> {
+		let bounded = match (value.partial_cmp(&self.min)?, value.partial_cmp(&self.max)?) {
+			(Ordering::Less, _) => self.min,
+			(_, Ordering::Greater) => self.max,
+			_ => return Some(value),
+		};
+		match self.mode {
+			BoundsMode::Clamp => Some(bounded),
+			BoundsMode::Reject => None,
+		}
+	}
+
+	///
+	/// Пересечение диапазонов, значение вне пересечения отклоняется, если отклонение задано хотя бы для одного из них,
+	/// None - диапазоны не пересекаются
+	///
+	pub fn intersect(&self, other: &Self) -> Option<Self> {
+		let min = if self.min < other.min { other.min } else { self.min };
+		let max = if self.max > other.max { other.max } else { self.max };
+		if min > max {
+			return None;
+		}
+		let mode = match (self.mode, other.mode) {
+			(BoundsMode::Clamp, BoundsMode::Clamp) => BoundsMode::Clamp,
+			_ => BoundsMode::Reject,
+		};
+		Some(Self { min, max, mode })
+	}
+}
+
+impl BoundsMode {
+	pub(crate) fn encode(self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_u8(self as u8)
+	}
+
+	pub(crate) fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let mode = input.read_u8()?;
+		BoundsMode::from_u8(mode).ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("Invalid bounds mode {mode}")))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::commands::types::bounds::{Bounds, BoundsMode};
+
+	#[test]
+	fn should_clamp() {
+		let bounds = Bounds {
+			min: 0,
+			max: 100,
+			mode: BoundsMode::Clamp,
+		};
+		assert_eq!(bounds.apply(50), Some(50));
+		assert_eq!(bounds.apply(-10), Some(0));
+		assert_eq!(bounds.apply(110), Some(100));
+	}
+
+	#[test]
+	fn should_reject() {
+		let bounds = Bounds {
+			min: 0.0,
+			max: 1.0,
+			mode: BoundsMode::Reject,
+		};
+		assert_eq!(bounds.apply(1.0), Some(1.0));
+		assert_eq!(bounds.apply(1.5), None);
+		assert_eq!(bounds.apply(-0.5), None);
+	}
+
+	#[test]
+	fn should_reject_nan() {
+		for mode in [BoundsMode::Clamp, BoundsMode::Reject] {
+			let bounds = Bounds { min: 0.0, max: 1.0, mode };
+			assert_eq!(bounds.apply(f64::NAN), None);
+		}
+	}
+
+	#[test]
+	fn should_intersect() {
+		let template = Bounds {
+			min: 0,
+			max: 100,
+			mode: BoundsMode::Reject,
+		};
+		let client = Bounds {
+			min: -50,
+			max: 50,
+			mode: BoundsMode::Clamp,
+		};
+		assert_eq!(
+			template.intersect(&client),
+			Some(Bounds {
+				min: 0,
+				max: 50,
+				mode: BoundsMode::Reject,
+			})
+		);
+		assert_eq!(
+			client.intersect(&Bounds {
+				min: 60,
+				max: 70,
+				mode: BoundsMode::Clamp,
+			}),
+			None
+		);
+	}
+}
@@ -18,6 +18,14 @@ pub struct CreateGameObjectCommand {
 	pub object_id: GameObjectId,
 	pub template: GameObjectTemplateId,
 	pub access_groups: AccessGroups,
+	///
+	/// Родительский объект, при удалении родителя объект удаляется вместе с ним
+	///
+	pub parent: Option<GameObjectId>,
+	///
+	/// Использовать группы доступа родительского объекта вместо `access_groups`, только для C->S
+	///
+	pub inherit_access_groups: bool,
 }
 
 ///
@@ -47,19 +55,42 @@ pub struct GameObjectCreatedS2CCommand {
 	pub object_id: GameObjectId,
 }
 
+const PARENT_BIT: u8 = 1;
+const INHERIT_ACCESS_GROUPS_BIT: u8 = 2;
+
 impl CreateGameObjectCommand {
 	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
 		out.write_variable_u64(u64::from(self.template))?;
-		out.write_variable_u64(self.access_groups.0)
+		out.write_variable_u64(self.access_groups.0)?;
+		let mut flags = 0;
+		if self.parent.is_some() {
+			flags |= PARENT_BIT;
+		}
+		if self.inherit_access_groups {
+			flags |= INHERIT_ACCESS_GROUPS_BIT;
+		}
+		out.write_u8(flags)?;
+		if let Some(parent) = &self.parent {
+			parent.encode(out)?;
+		}
+		Ok(())
 	}
 
 	pub fn decode(object_id: GameObjectId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
 		let template = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
 		let access_groups = AccessGroups(input.read_variable_u64()?);
+		let flags = input.read_u8()?;
+		let parent = if flags & PARENT_BIT == 0 {
+			None
+		} else {
+			Some(GameObjectId::decode(input)?)
+		};
 		Ok(Self {
 			object_id,
 			template,
 			access_groups,
+			parent,
+			inherit_access_groups: flags & INHERIT_ACCESS_GROUPS_BIT != 0,
 		})
 	}
 }
@@ -1,7 +1,7 @@
 use std::io::Cursor;
 
 use crate::commands::field::FieldId;
-use crate::commands::{field::ToFieldType, FieldType, FieldValue};
+use crate::commands::{field::ToFieldType, CommandTypeId, FieldType, FieldValue};
 use crate::room::object::GameObjectId;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -35,6 +35,21 @@ pub struct SetFieldCommand {
 }
 
 impl SetFieldCommand {
+	///
+	/// Тип команды определяется типом значения
+	///
+	#[must_use]
+	pub fn get_type_id(&self) -> CommandTypeId {
+		match self.value {
+			FieldValue::Long(_) => CommandTypeId::SetLong,
+			FieldValue::Double(_) => CommandTypeId::SetDouble,
+			FieldValue::Structure(_) => CommandTypeId::SetStructure,
+			FieldValue::List(_) => CommandTypeId::SetList,
+			FieldValue::Map(_) => CommandTypeId::SetMap,
+			FieldValue::String(_) => CommandTypeId::SetString,
+		}
+	}
+
 	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
 		self.value.encode(out)
 	}
@@ -0,0 +1,86 @@
+use std::io::{Cursor, Error, ErrorKind};
+
+use byteorder::{ReadBytesExt, WriteBytesExt};
+
+use crate::commands::field::{FieldId, FieldType};
+use crate::commands::FieldValue;
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::object::GameObjectId;
+
+///
+/// Запрос значения поля объекта в момент времени сервера (в миллисекундах от создания комнаты)
+/// - C->S, выполняется только для супер-пользователей
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldHistoryQueryCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub field_type: FieldType,
+	pub time: u64,
+}
+
+///
+/// Значение поля объекта в момент времени сервера, значение отсутствует если история поля не хранится
+/// или не охватывает запрошенный момент
+/// - S->C
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldHistoryValueCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub field_type: FieldType,
+	pub time: u64,
+	pub value: Option<FieldValue>,
+}
+
+impl FieldHistoryQueryCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		self.object_id.encode(out)?;
+		out.write_variable_u64(u64::from(self.field_id))?;
+		self.field_type.encode(out)?;
+		out.write_variable_u64(self.time)
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			object_id: GameObjectId::decode(input)?,
+			field_id: input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?,
+			field_type: FieldType::decode(input)?,
+			time: input.read_variable_u64()?,
+		})
+	}
+}
+
+impl FieldHistoryValueCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		self.object_id.encode(out)?;
+		out.write_variable_u64(u64::from(self.field_id))?;
+		self.field_type.encode(out)?;
+		out.write_variable_u64(self.time)?;
+		match &self.value {
+			None => out.write_u8(0),
+			Some(value) => {
+				out.write_u8(1)?;
+				value.encode(out)
+			}
+		}
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let object_id = GameObjectId::decode(input)?;
+		let field_id = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+		let field_type = FieldType::decode(input)?;
+		let time = input.read_variable_u64()?;
+		let value = match input.read_u8()? {
+			0 => None,
+			_ => Some(FieldValue::decode_by_type(field_type, input)?),
+		};
+		Ok(Self {
+			object_id,
+			field_id,
+			field_type,
+			time,
+			value,
+		})
+	}
+}
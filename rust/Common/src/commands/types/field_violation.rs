@@ -0,0 +1,36 @@
+use std::io::{Cursor, Error, ErrorKind};
+
+use crate::commands::field::{FieldId, FieldType};
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::object::GameObjectId;
+use crate::room::RoomMemberId;
+
+///
+/// Изменение поля пользователем нарушило правило проверки, изменение при этом применено
+/// - S->C, отправляется только супер-пользователям
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldViolationCommand {
+	pub member_id: RoomMemberId,
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub field_type: FieldType,
+}
+
+impl FieldViolationCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(u64::from(self.member_id))?;
+		self.object_id.encode(out)?;
+		out.write_variable_u64(u64::from(self.field_id))?;
+		self.field_type.encode(out)
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			member_id: input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?,
+			object_id: GameObjectId::decode(input)?,
+			field_id: input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?,
+			field_type: FieldType::decode(input)?,
+		})
+	}
+}
@@ -3,6 +3,7 @@ use std::io::Cursor;
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::commands::field::FieldId;
+use crate::commands::types::bounds::{Bounds, BoundsMode};
 use crate::room::object::GameObjectId;
 
 ///
@@ -17,6 +18,34 @@ pub struct IncrementDoubleC2SCommand {
 	pub increment: f64,
 }
 
+///
+/// Обновление счетчика с ограничением значения
+/// - C->S
+///
+#[derive(Debug, PartialEq, Clone)]
+#[repr(C)]
+pub struct IncrementDoubleWithBoundsC2SCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub increment: f64,
+	pub bounds: Bounds<f64>,
+}
+
+///
+/// Установка значения new если текущее равно current (точное сравнение)
+/// reset - значение после выхода пользователя
+///
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct CompareAndSetDoubleCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub current: f64,
+	pub new: f64,
+	has_reset: bool,
+	reset: f64,
+}
+
 impl IncrementDoubleC2SCommand {
 	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
 		out.write_f64::<BigEndian>(self.increment)
@@ -31,3 +60,69 @@ impl IncrementDoubleC2SCommand {
 		})
 	}
 }
+
+impl IncrementDoubleWithBoundsC2SCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_f64::<BigEndian>(self.increment)?;
+		out.write_f64::<BigEndian>(self.bounds.min)?;
+		out.write_f64::<BigEndian>(self.bounds.max)?;
+		self.bounds.mode.encode(out)
+	}
+
+	pub fn decode(object_id: GameObjectId, field_id: FieldId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let increment = input.read_f64::<BigEndian>()?;
+		let min = input.read_f64::<BigEndian>()?;
+		let max = input.read_f64::<BigEndian>()?;
+		let mode = BoundsMode::decode(input)?;
+		Ok(Self {
+			object_id,
+			field_id,
+			increment,
+			bounds: Bounds { min, max, mode },
+		})
+	}
+}
+
+impl CompareAndSetDoubleCommand {
+	#[must_use]
+	pub fn new(object_id: GameObjectId, field_id: FieldId, current: f64, new: f64, reset: Option<f64>) -> Self {
+		Self {
+			object_id,
+			field_id,
+			current,
+			new,
+			has_reset: reset.is_some(),
+			reset: reset.unwrap_or_default(),
+		}
+	}
+
+	#[must_use]
+	pub fn get_reset(&self) -> Option<f64> {
+		self.has_reset.then_some(self.reset)
+	}
+
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_f64::<BigEndian>(self.current)?;
+		out.write_f64::<BigEndian>(self.new)?;
+		out.write_u8(u8::from(self.has_reset))?;
+		if self.has_reset {
+			out.write_f64::<BigEndian>(self.reset)?;
+		}
+		Ok(())
+	}
+
+	pub fn decode(object_id: GameObjectId, field_id: FieldId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let current = input.read_f64::<BigEndian>()?;
+		let new = input.read_f64::<BigEndian>()?;
+		let has_reset = input.read_u8()? == 1;
+		let reset = if has_reset { input.read_f64::<BigEndian>()? } else { 0.0 };
+		Ok(Self {
+			object_id,
+			field_id,
+			current,
+			new,
+			has_reset,
+			reset,
+		})
+	}
+}
@@ -0,0 +1,138 @@
+use std::io::{Cursor, Error, ErrorKind};
+
+use crate::commands::binary_value::BinaryValue;
+use crate::commands::field::FieldId;
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::object::GameObjectId;
+
+///
+/// Позиция элемента в списке
+///
+pub type ListIndex = u16;
+
+///
+/// Максимальное количество элементов в списке
+///
+pub const MAX_LIST_ITEMS: usize = 64;
+
+///
+/// Добавить элемент в конец списка
+/// - C->S, S->C
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct ListPushCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub value: BinaryValue,
+}
+
+///
+/// Удалить элемент списка по индексу
+/// - C->S, S->C
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct ListRemoveCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub index: ListIndex,
+}
+
+///
+/// Удалить первый элемент списка с заданным значением, клиентам отправляется удаление по индексу
+/// - C->S
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct ListRemoveValueCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub value: BinaryValue,
+}
+
+///
+/// Заменить элемент списка по индексу
+/// - C->S, S->C
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct ListSetItemCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub index: ListIndex,
+	pub value: BinaryValue,
+}
+
+///
+/// Удалить все элементы списка
+/// - C->S, S->C
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct ListClearCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+}
+
+impl ListPushCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		self.value.encode(out)
+	}
+
+	pub fn decode(object_id: GameObjectId, field_id: FieldId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			object_id,
+			field_id,
+			value: BinaryValue::decode(input)?,
+		})
+	}
+}
+
+impl ListRemoveCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(u64::from(self.index))
+	}
+
+	pub fn decode(object_id: GameObjectId, field_id: FieldId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			object_id,
+			field_id,
+			index: read_index(input)?,
+		})
+	}
+}
+
+impl ListRemoveValueCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		self.value.encode(out)
+	}
+
+	pub fn decode(object_id: GameObjectId, field_id: FieldId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			object_id,
+			field_id,
+			value: BinaryValue::decode(input)?,
+		})
+	}
+}
+
+impl ListSetItemCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(u64::from(self.index))?;
+		self.value.encode(out)
+	}
+
+	pub fn decode(object_id: GameObjectId, field_id: FieldId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			object_id,
+			field_id,
+			index: read_index(input)?,
+			value: BinaryValue::decode(input)?,
+		})
+	}
+}
+
+fn read_index(input: &mut Cursor<&[u8]>) -> std::io::Result<ListIndex> {
+	input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))
+}
@@ -0,0 +1,120 @@
+use std::io::{Cursor, Error, ErrorKind};
+
+use byteorder::{ReadBytesExt, WriteBytesExt};
+
+use crate::commands::binary_value::BinaryValue;
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::RoomMemberId;
+
+///
+/// Номер тика в режиме lockstep
+///
+pub type LockstepTickId = u64;
+
+///
+/// Ввод пользователя для тика
+/// - C->S
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockstepInputCommand {
+	pub tick: LockstepTickId,
+	pub input: BinaryValue,
+}
+
+///
+/// Ввод пользователя из набора вводов тика
+/// - S->C
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockstepMemberInputCommand {
+	pub tick: LockstepTickId,
+	pub member_id: RoomMemberId,
+	///
+	/// Ввод не пришел до окончания тика - сервер подставил последний известный ввод пользователя
+	///
+	pub filled: bool,
+	pub input: BinaryValue,
+}
+
+///
+/// Набор вводов для тика полностью отправлен
+/// - S->C
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockstepTickCommand {
+	pub tick: LockstepTickId,
+	///
+	/// Количество вводов в наборе
+	///
+	pub inputs: u16,
+}
+
+///
+/// Запрос истории вводов начиная с указанного тика (для подключившихся позже), ответ ограничен по количеству тиков -
+/// остальные тики запрашиваются повторно
+/// - C->S
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockstepHistoryCommand {
+	pub from_tick: LockstepTickId,
+}
+
+impl LockstepInputCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.tick)?;
+		self.input.encode(out)
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			tick: input.read_variable_u64()?,
+			input: BinaryValue::decode(input)?,
+		})
+	}
+}
+
+impl LockstepMemberInputCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.tick)?;
+		out.write_variable_u64(u64::from(self.member_id))?;
+		out.write_u8(u8::from(self.filled))?;
+		self.input.encode(out)
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let tick = input.read_variable_u64()?;
+		let member_id = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+		let filled = input.read_u8()? == 1;
+		Ok(Self {
+			tick,
+			member_id,
+			filled,
+			input: BinaryValue::decode(input)?,
+		})
+	}
+}
+
+impl LockstepTickCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.tick)?;
+		out.write_variable_u64(u64::from(self.inputs))
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let tick = input.read_variable_u64()?;
+		let inputs = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+		Ok(Self { tick, inputs })
+	}
+}
+
+impl LockstepHistoryCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.from_tick)
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			from_tick: input.read_variable_u64()?,
+		})
+	}
+}
@@ -3,6 +3,7 @@ use std::io::Cursor;
 use byteorder::{ReadBytesExt, WriteBytesExt};
 
 use crate::commands::field::FieldId;
+use crate::commands::types::bounds::{Bounds, BoundsMode};
 use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
 use crate::room::object::GameObjectId;
 
@@ -18,6 +19,19 @@ pub struct IncrementLongC2SCommand {
 	pub increment: i64,
 }
 
+///
+/// Обновление счетчика с ограничением значения
+/// - C->S
+///
+#[derive(Debug, PartialEq, Clone)]
+#[repr(C)]
+pub struct IncrementLongWithBoundsC2SCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub increment: i64,
+	pub bounds: Bounds<i64>,
+}
+
 ///
 /// Установка значения new если текущее равно current
 /// reset - значение после выхода пользователя
@@ -48,6 +62,28 @@ impl IncrementLongC2SCommand {
 	}
 }
 
+impl IncrementLongWithBoundsC2SCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_i64(self.increment)?;
+		out.write_variable_i64(self.bounds.min)?;
+		out.write_variable_i64(self.bounds.max)?;
+		self.bounds.mode.encode(out)
+	}
+
+	pub fn decode(object_id: GameObjectId, field_id: FieldId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let increment = input.read_variable_i64()?;
+		let min = input.read_variable_i64()?;
+		let max = input.read_variable_i64()?;
+		let mode = BoundsMode::decode(input)?;
+		Ok(Self {
+			object_id,
+			field_id,
+			increment,
+			bounds: Bounds { min, max, mode },
+		})
+	}
+}
+
 impl CompareAndSetLongCommand {
 	pub fn new(object_id: GameObjectId, field_id: FieldId, current: i64, new: i64, reset: Option<i64>) -> Self {
 		Self {
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+use std::io::{Cursor, Error, ErrorKind};
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+
+use crate::commands::binary_value::BinaryValue;
+use crate::commands::field::FieldId;
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::object::GameObjectId;
+
+///
+/// Ключ элемента словаря
+///
+pub type MapKey = u64;
+
+///
+/// Элементы словаря, упорядочены по ключу
+///
+pub type MapItems = BTreeMap<MapKey, MapValue>;
+
+///
+/// Максимальное количество элементов в словаре
+///
+pub const MAX_MAP_ITEMS: usize = 64;
+
+///
+/// Значение элемента словаря
+///
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum MapValue {
+	Long(i64),
+	Double(f64),
+	Binary(BinaryValue),
+}
+
+///
+/// Установить значение по ключу
+/// - C->S, S->C
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapSetCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub key: MapKey,
+	pub value: MapValue,
+}
+
+///
+/// Удалить значение по ключу
+/// - C->S, S->C
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct MapDeleteCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub key: MapKey,
+}
+
+///
+/// Увеличить значение по ключу (только Long и Double), клиентам отправляется установка итогового значения
+/// - C->S
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapIncrementCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub key: MapKey,
+	pub increment: MapValue,
+}
+
+impl MapValue {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		match self {
+			MapValue::Long(value) => {
+				out.write_u8(1)?;
+				out.write_variable_i64(*value)
+			}
+			MapValue::Double(value) => {
+				out.write_u8(2)?;
+				out.write_f64::<BigEndian>(*value)
+			}
+			MapValue::Binary(value) => {
+				out.write_u8(3)?;
+				value.encode(out)
+			}
+		}
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let code = input.read_u8()?;
+		Ok(match code {
+			1 => MapValue::Long(input.read_variable_i64()?),
+			2 => MapValue::Double(input.read_f64::<BigEndian>()?),
+			3 => MapValue::Binary(BinaryValue::decode(input)?),
+			_ => return Err(Error::new(ErrorKind::InvalidData, format!("Read MapValue with code {code}"))),
+		})
+	}
+}
+
+///
+/// Все элементы словаря: количество, затем пары ключ-значение
+///
+pub(crate) fn encode_items(items: &MapItems, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+	out.write_variable_u64(items.len() as u64)?;
+	for (key, value) in items {
+		out.write_variable_u64(*key)?;
+		value.encode(out)?;
+	}
+	Ok(())
+}
+
+pub(crate) fn decode_items(input: &mut Cursor<&[u8]>) -> std::io::Result<MapItems> {
+	let len: usize = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+	if len > MAX_MAP_ITEMS {
+		return Err(Error::new(ErrorKind::InvalidData, format!("Map items count to big {len}")));
+	}
+	(0..len).map(|_| Ok((input.read_variable_u64()?, MapValue::decode(input)?))).collect()
+}
+
+impl MapSetCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.key)?;
+		self.value.encode(out)
+	}
+
+	pub fn decode(object_id: GameObjectId, field_id: FieldId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			object_id,
+			field_id,
+			key: input.read_variable_u64()?,
+			value: MapValue::decode(input)?,
+		})
+	}
+}
+
+impl MapDeleteCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.key)
+	}
+
+	pub fn decode(object_id: GameObjectId, field_id: FieldId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			object_id,
+			field_id,
+			key: input.read_variable_u64()?,
+		})
+	}
+}
+
+impl MapIncrementCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.key)?;
+		self.increment.encode(out)
+	}
+
+	pub fn decode(object_id: GameObjectId, field_id: FieldId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			object_id,
+			field_id,
+			key: input.read_variable_u64()?,
+			increment: MapValue::decode(input)?,
+		})
+	}
+}
@@ -1,9 +1,25 @@
+pub mod access_groups;
+pub mod bounds;
 pub mod create;
 pub mod delete;
 pub mod event;
 pub mod field;
+pub mod field_history;
+pub mod field_violation;
 pub mod float;
 pub mod forwarded;
+pub mod list;
+pub mod lockstep;
 pub mod long;
+pub mod map;
 pub mod member_connected;
+pub mod ownership;
+pub mod rejected;
+pub mod room_event;
+pub mod rpc;
+pub mod string;
 pub mod structure;
+pub mod subscription;
+pub mod timer;
+pub mod transaction;
+pub mod ttl;
@@ -0,0 +1,31 @@
+use std::io::{Cursor, Error, ErrorKind};
+
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::object::GameObjectId;
+use crate::room::RoomMemberId;
+
+///
+/// Передача владения объектом пользователю
+///
+/// - C->S - запрос на передачу владения
+/// - S->C - оповещение о смене владельца объекта
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferOwnershipCommand {
+	pub object_id: GameObjectId,
+	pub new_owner: RoomMemberId,
+}
+
+impl TransferOwnershipCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.new_owner.into())
+	}
+
+	pub fn decode(object_id: GameObjectId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let new_owner = input
+			.read_variable_u64()?
+			.try_into()
+			.map_err(|e| Error::new(ErrorKind::InvalidData, format!("could not convert new_owner into RoomMemberId: {e:?}")))?;
+		Ok(Self { object_id, new_owner })
+	}
+}
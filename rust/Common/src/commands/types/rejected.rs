@@ -0,0 +1,107 @@
+use std::io::{Cursor, Error, ErrorKind};
+
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::FromPrimitive;
+
+use crate::commands::field::FieldId;
+use crate::commands::CommandTypeId;
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::object::GameObjectId;
+
+///
+/// Причина отказа в выполнении команды
+///
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum CommandRejectReason {
+	Other = 0,
+	GameObjectNotFound,
+	MemberNotOwnerGameObject,
+	MemberCannotAccessToObject,
+	MemberCannotAccessToObjectField,
+	FieldCountOverflow,
+	CompareAndSetOverflow,
+	ForwardedCommandPermissionDenied,
+	LockstepNotEnabled,
+	LockstepTickOutOfRange,
+	ListIndexOutOfRange,
+	ListOverflow,
+	MapOverflow,
+	MapValueTypeMismatch,
+	StringTooLong,
+	IncrementOutOfBounds,
+	TransactionPreconditionFailed,
+	TimersOverflow,
+	TimerPermissionDenied,
+	RpcCallsOverflow,
+	RpcCallAlreadyExists,
+	RoomEventPermissionDenied,
+	FieldValidationFailed,
+	FieldHistoryPermissionDenied,
+}
+
+///
+/// Команда пользователя не выполнена на сервере
+/// - S->C
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandRejectedCommand {
+	///
+	/// Тип отклоненной команды
+	///
+	pub command_type_id: CommandTypeId,
+	pub reason: CommandRejectReason,
+	pub object_id: Option<GameObjectId>,
+	pub field_id: Option<FieldId>,
+}
+
+const OBJECT_ID_BIT: u8 = 1;
+const FIELD_ID_BIT: u8 = 2;
+
+impl CommandRejectedCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_u8(self.command_type_id as u8)?;
+		out.write_u8(self.reason as u8)?;
+		let mut flags = 0;
+		if self.object_id.is_some() {
+			flags |= OBJECT_ID_BIT;
+		}
+		if self.field_id.is_some() {
+			flags |= FIELD_ID_BIT;
+		}
+		out.write_u8(flags)?;
+		if let Some(object_id) = &self.object_id {
+			object_id.encode(out)?;
+		}
+		if let Some(field_id) = self.field_id {
+			out.write_variable_u64(u64::from(field_id))?;
+		}
+		Ok(())
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let command_type_id = input.read_u8()?;
+		let command_type_id = CommandTypeId::from_u8(command_type_id)
+			.ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("Invalid command type id {command_type_id}")))?;
+		let reason = input.read_u8()?;
+		let reason = CommandRejectReason::from_u8(reason).unwrap_or(CommandRejectReason::Other);
+		let flags = input.read_u8()?;
+		let object_id = if flags & OBJECT_ID_BIT > 0 {
+			Some(GameObjectId::decode(input)?)
+		} else {
+			None
+		};
+		let field_id = if flags & FIELD_ID_BIT > 0 {
+			Some(input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?)
+		} else {
+			None
+		};
+		Ok(Self {
+			command_type_id,
+			reason,
+			object_id,
+			field_id,
+		})
+	}
+}
@@ -0,0 +1,121 @@
+use std::io::{Cursor, Error, ErrorKind};
+
+use byteorder::{ReadBytesExt, WriteBytesExt};
+
+use crate::commands::binary_value::BinaryValue;
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::access::AccessGroups;
+use crate::room::RoomMemberId;
+
+///
+/// Идентификатор события комнаты, права доступа задаются для идентификатора
+///
+pub type RoomEventId = u16;
+
+///
+/// Максимальное количество пользователей в списке получателей события
+///
+pub const MAX_ROOM_EVENT_MEMBERS: usize = 64;
+
+///
+/// Получатели события комнаты, отправитель события не входит в получателей
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoomEventTarget {
+	///
+	/// Пользователи с любой из групп
+	///
+	Groups(AccessGroups),
+	///
+	/// Пользователи из списка
+	///
+	Members(Vec<RoomMemberId>),
+}
+
+///
+/// Событие комнаты, не привязанное к объекту
+/// - C->S
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoomEventCommand {
+	pub event_id: RoomEventId,
+	pub target: RoomEventTarget,
+	pub event: BinaryValue,
+}
+
+///
+/// Событие комнаты, отправитель передается в создателе команды
+/// - S->C
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoomEventS2CCommand {
+	pub event_id: RoomEventId,
+	pub event: BinaryValue,
+}
+
+const GROUPS_TAG: u8 = 0;
+const MEMBERS_TAG: u8 = 1;
+
+impl RoomEventTarget {
+	fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		match self {
+			RoomEventTarget::Groups(groups) => {
+				out.write_u8(GROUPS_TAG)?;
+				out.write_variable_u64(groups.0)
+			}
+			RoomEventTarget::Members(members) => {
+				out.write_u8(MEMBERS_TAG)?;
+				out.write_variable_u64(members.len() as u64)?;
+				members.iter().try_for_each(|member_id| out.write_variable_u64(u64::from(*member_id)))
+			}
+		}
+	}
+
+	fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let tag = input.read_u8()?;
+		match tag {
+			GROUPS_TAG => Ok(RoomEventTarget::Groups(AccessGroups(input.read_variable_u64()?))),
+			MEMBERS_TAG => {
+				let len: usize = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+				if len > MAX_ROOM_EVENT_MEMBERS {
+					return Err(Error::new(ErrorKind::InvalidData, format!("Room event members count to big {len}")));
+				}
+				let members = (0..len)
+					.map(|_| input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e)))
+					.collect::<std::io::Result<_>>()?;
+				Ok(RoomEventTarget::Members(members))
+			}
+			_ => Err(Error::new(ErrorKind::InvalidData, format!("Invalid room event target tag {tag}"))),
+		}
+	}
+}
+
+impl RoomEventCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(u64::from(self.event_id))?;
+		self.target.encode(out)?;
+		self.event.encode(out)
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			event_id: input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?,
+			target: RoomEventTarget::decode(input)?,
+			event: BinaryValue::decode(input)?,
+		})
+	}
+}
+
+impl RoomEventS2CCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(u64::from(self.event_id))?;
+		self.event.encode(out)
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			event_id: input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?,
+			event: BinaryValue::decode(input)?,
+		})
+	}
+}
@@ -0,0 +1,104 @@
+use std::io::{Cursor, Error, ErrorKind};
+
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::FromPrimitive;
+
+use crate::commands::binary_value::BinaryValue;
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::RoomMemberId;
+
+///
+/// Идентификатор вызова, уникален в рамках вызывающего пользователя
+///
+pub type RpcCallId = u32;
+
+///
+/// Результат вызова
+///
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum RpcStatus {
+	Ok = 0,
+	///
+	/// Вызываемый пользователь вернул ошибку
+	///
+	Error,
+	///
+	/// Вызываемый пользователь не ответил за время ожидания, формируется сервером
+	///
+	Timeout,
+	///
+	/// Вызываемый пользователь не найден или вышел из комнаты, формируется сервером
+	///
+	TargetNotFound,
+}
+
+///
+/// Запрос к пользователю
+/// - C->S - `member_id` - вызываемый пользователь
+/// - S->C - `member_id` - вызывающий пользователь
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcRequestCommand {
+	pub member_id: RoomMemberId,
+	pub call_id: RpcCallId,
+	///
+	/// Время ожидания ответа в миллисекундах
+	///
+	pub timeout: u32,
+	pub payload: BinaryValue,
+}
+
+///
+/// Ответ на запрос
+/// - C->S - `member_id` - вызывающий пользователь
+/// - S->C - `member_id` - вызываемый пользователь
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcResponseCommand {
+	pub member_id: RoomMemberId,
+	pub call_id: RpcCallId,
+	pub status: RpcStatus,
+	pub payload: BinaryValue,
+}
+
+impl RpcRequestCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(u64::from(self.member_id))?;
+		out.write_variable_u64(u64::from(self.call_id))?;
+		out.write_variable_u64(u64::from(self.timeout))?;
+		self.payload.encode(out)
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		Ok(Self {
+			member_id: input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?,
+			call_id: input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?,
+			timeout: input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?,
+			payload: BinaryValue::decode(input)?,
+		})
+	}
+}
+
+impl RpcResponseCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(u64::from(self.member_id))?;
+		out.write_variable_u64(u64::from(self.call_id))?;
+		out.write_u8(self.status as u8)?;
+		self.payload.encode(out)
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let member_id = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+		let call_id = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+		let status = input.read_u8()?;
+		let status = RpcStatus::from_u8(status).ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("Invalid rpc status {status}")))?;
+		Ok(Self {
+			member_id,
+			call_id,
+			status,
+			payload: BinaryValue::decode(input)?,
+		})
+	}
+}
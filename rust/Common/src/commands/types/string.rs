@@ -0,0 +1,62 @@
+use std::io::{Cursor, Error, ErrorKind, Read, Write};
+
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+
+///
+/// Максимальный размер строки в байтах (UTF-8)
+///
+pub const MAX_STRING_SIZE: usize = 255;
+
+pub(crate) fn encode_string(value: &str, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+	if value.len() > MAX_STRING_SIZE {
+		return Err(Error::new(ErrorKind::InvalidInput, format!("String size to big {}", value.len())));
+	}
+	out.write_variable_u64(value.len() as u64)?;
+	out.write_all(value.as_bytes())
+}
+
+pub(crate) fn decode_string(input: &mut Cursor<&[u8]>) -> std::io::Result<String> {
+	let size: usize = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+	if size > MAX_STRING_SIZE {
+		return Err(Error::new(ErrorKind::InvalidData, format!("String size to big {size}")));
+	}
+	let mut buffer = vec![0; size];
+	input.read_exact(&mut buffer)?;
+	String::from_utf8(buffer).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::{Cursor, ErrorKind};
+
+	use crate::commands::types::string::{decode_string, encode_string, MAX_STRING_SIZE};
+	use crate::protocol::codec::variable_int::VariableIntWriter;
+
+	#[test]
+	fn should_encode_decode_utf8() {
+		let mut buffer = [0_u8; 100];
+		let mut cursor = Cursor::new(buffer.as_mut());
+		encode_string("Игрок 🚀", &mut cursor).unwrap();
+		let mut read_cursor = Cursor::<&[u8]>::new(&buffer);
+		assert_eq!(decode_string(&mut read_cursor).unwrap(), "Игрок 🚀");
+	}
+
+	#[test]
+	fn should_not_encode_big_string() {
+		let mut buffer = [0_u8; 1024];
+		let mut cursor = Cursor::new(buffer.as_mut());
+		let value = "a".repeat(MAX_STRING_SIZE + 1);
+		assert_eq!(encode_string(&value, &mut cursor).unwrap_err().kind(), ErrorKind::InvalidInput);
+	}
+
+	#[test]
+	fn should_not_decode_invalid_utf8() {
+		let mut buffer = [0_u8; 10];
+		let mut cursor = Cursor::new(buffer.as_mut());
+		cursor.write_variable_u64(2).unwrap();
+		buffer[1] = 0xC3;
+		buffer[2] = 0x28;
+		let mut read_cursor = Cursor::<&[u8]>::new(&buffer);
+		assert_eq!(decode_string(&mut read_cursor).unwrap_err().kind(), ErrorKind::InvalidData);
+	}
+}
@@ -1,8 +1,8 @@
-use std::io::Cursor;
+use std::io::{Cursor, Error, ErrorKind, Read};
 
 use byteorder::{ReadBytesExt, WriteBytesExt};
 
-use crate::commands::binary_value::BinaryValue;
+use crate::commands::binary_value::{BinaryValue, BUFFER_MAX_SIZE};
 use crate::commands::field::FieldId;
 use crate::room::object::GameObjectId;
 
@@ -70,3 +70,136 @@ impl CompareAndSetStructureCommand {
 		})
 	}
 }
+
+///
+/// Обновить структуру в объекте, передается только разница с предыдущим значением
+/// - S->C
+///
+/// Формат changes - последовательность блоков [смещение, размер, данные]
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetStructureDeltaCommand {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub len: u8,
+	pub changes: BinaryValue,
+}
+
+///
+/// Минимальный разрыв между изменениями, при котором выгодней начать новый блок,
+/// чем передавать совпадающие байты (заголовок блока занимает 2 байта)
+///
+const MIN_GAP_FOR_NEW_BLOCK: usize = 3;
+
+impl SetStructureDeltaCommand {
+	///
+	/// Создать команду с разницей между base и value, None - если разница не меньше самого значения
+	///
+	#[must_use]
+	pub fn new(object_id: GameObjectId, field_id: FieldId, base: &BinaryValue, value: &BinaryValue) -> Option<Self> {
+		let base = base.as_slice();
+		let value = value.as_slice();
+		let is_changed = |i: usize| i >= base.len() || base[i] != value[i];
+
+		let mut changes = Vec::with_capacity(value.len());
+		let mut position = 0;
+		while position < value.len() {
+			if !is_changed(position) {
+				position += 1;
+				continue;
+			}
+			let start = position;
+			let mut end = position + 1;
+			let mut cursor = end;
+			while cursor < value.len() && cursor - end < MIN_GAP_FOR_NEW_BLOCK {
+				if is_changed(cursor) {
+					end = cursor + 1;
+				}
+				cursor += 1;
+			}
+			changes.push(start as u8);
+			changes.push((end - start) as u8);
+			changes.extend_from_slice(&value[start..end]);
+			position = end;
+		}
+
+		// + байт длины значения
+		(changes.len() + 1 < value.len()).then(|| Self {
+			object_id,
+			field_id,
+			len: value.len() as u8,
+			changes: changes.as_slice().into(),
+		})
+	}
+
+	///
+	/// Применить разницу к предыдущему значению
+	///
+	pub fn apply(&self, base: &BinaryValue) -> std::io::Result<BinaryValue> {
+		let len = self.len as usize;
+		let mut buffer = [0; BUFFER_MAX_SIZE];
+		let copy_len = len.min(base.len as usize);
+		buffer[0..copy_len].copy_from_slice(&base.as_slice()[0..copy_len]);
+
+		let mut changes = Cursor::new(self.changes.as_slice());
+		while (changes.position() as usize) < self.changes.len as usize {
+			let offset = changes.read_u8()? as usize;
+			let size = changes.read_u8()? as usize;
+			if offset + size > len {
+				return Err(Error::new(
+					ErrorKind::InvalidData,
+					format!("Structure delta out of bounds {offset} {size} {len}"),
+				));
+			}
+			changes.read_exact(&mut buffer[offset..offset + size])?;
+		}
+		Ok(BinaryValue::from(&buffer[0..len]))
+	}
+
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_u8(self.len)?;
+		self.changes.encode(out)
+	}
+
+	pub fn decode(object_id: GameObjectId, field_id: FieldId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let len = input.read_u8()?;
+		let changes = BinaryValue::decode(input)?;
+		Ok(Self {
+			object_id,
+			field_id,
+			len,
+			changes,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::commands::binary_value::BinaryValue;
+	use crate::commands::types::structure::SetStructureDeltaCommand;
+	use crate::room::object::GameObjectId;
+
+	#[test]
+	fn should_apply_delta() {
+		check(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12], &[1, 2, 3, 100, 5, 6, 7, 8, 9, 10, 11, 12]);
+		check(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12], &[100, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 100]);
+		check(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12], &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13]);
+		check(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12], &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+		check(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12], &[1, 100, 3, 100, 5, 6, 7, 8, 9, 10, 11, 12]);
+	}
+
+	#[test]
+	fn should_not_create_delta_if_it_not_smaller() {
+		let base = BinaryValue::from([1, 2, 3].as_slice());
+		let value = BinaryValue::from([4, 5, 6].as_slice());
+		assert!(SetStructureDeltaCommand::new(GameObjectId::default(), 1, &base, &value).is_none());
+	}
+
+	fn check(base: &[u8], value: &[u8]) {
+		let base = BinaryValue::from(base);
+		let value = BinaryValue::from(value);
+		let delta = SetStructureDeltaCommand::new(GameObjectId::default(), 1, &base, &value).unwrap();
+		assert!(delta.changes.len < value.len);
+		assert_eq!(delta.apply(&base).unwrap(), value);
+	}
+}
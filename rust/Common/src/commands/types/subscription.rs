@@ -0,0 +1,50 @@
+use std::io::{Cursor, Error, ErrorKind};
+
+use crate::commands::field::FieldId;
+use crate::constants::GameObjectTemplateId;
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+
+///
+/// Максимальное количество шаблонов или полей в команде подписки
+///
+pub const MAX_SUBSCRIPTION_ITEMS: usize = 64;
+
+///
+/// Подписка на объекты шаблонов и поля, пользователю отправляются только объекты и поля из подписки
+///
+/// - пустой список шаблонов (полей) - подписка на все шаблоны (поля);
+/// - отписка с пустыми списками шаблонов и полей - сброс подписки;
+/// - C->S
+///
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SubscriptionCommand {
+	pub templates: Vec<GameObjectTemplateId>,
+	pub fields: Vec<FieldId>,
+}
+
+impl SubscriptionCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.templates.len() as u64)?;
+		self.templates
+			.iter()
+			.try_for_each(|template| out.write_variable_u64(u64::from(*template)))?;
+		out.write_variable_u64(self.fields.len() as u64)?;
+		self.fields.iter().try_for_each(|field_id| out.write_variable_u64(u64::from(*field_id)))
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let templates = Self::read_items(input)?;
+		let fields = Self::read_items(input)?;
+		Ok(Self { templates, fields })
+	}
+
+	fn read_items(input: &mut Cursor<&[u8]>) -> std::io::Result<Vec<u16>> {
+		let len: usize = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+		if len > MAX_SUBSCRIPTION_ITEMS {
+			return Err(Error::new(ErrorKind::InvalidData, format!("Subscription items count to big {len}")));
+		}
+		(0..len)
+			.map(|_| input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e)))
+			.collect()
+	}
+}
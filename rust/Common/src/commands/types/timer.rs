@@ -0,0 +1,205 @@
+use std::io::{Cursor, Error, ErrorKind};
+
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::FromPrimitive;
+
+use crate::commands::c2s::C2SCommand;
+use crate::commands::field::{Field, FieldId};
+use crate::commands::types::delete::DeleteGameObjectCommand;
+use crate::commands::types::event::EventCommand;
+use crate::commands::types::transaction::TransactionMutation;
+use crate::commands::{CommandDecodeError, CommandTypeId, FieldType};
+use crate::protocol::codec::commands::context::CommandContextError;
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::object::GameObjectId;
+
+///
+/// Идентификатор таймера, уникален в рамках создавшего его пользователя
+///
+pub type TimerId = u32;
+
+///
+/// Владелец таймера
+///
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum TimerOwner {
+	///
+	/// Таймер отменяется при выходе пользователя из комнаты
+	///
+	Member = 0,
+	///
+	/// Таймер сохраняется после выхода пользователя, создается только суперпользователем
+	///
+	Room,
+}
+
+///
+/// Время срабатывания таймера в миллисекундах
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TimerTime {
+	///
+	/// Задержка относительно времени выполнения команды на сервере
+	///
+	Delay(u64),
+	///
+	/// Время сервера от создания комнаты
+	///
+	ServerTime(u64),
+}
+
+///
+/// Действие, выполняемое при срабатывании таймера
+///
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum TimerAction {
+	Mutation(TransactionMutation),
+	Event(EventCommand),
+	Delete(DeleteGameObjectCommand),
+}
+
+///
+/// Запланировать выполнение действия, таймер с тем же идентификатором заменяется
+/// - C->S
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleTimerCommand {
+	pub timer_id: TimerId,
+	pub time: TimerTime,
+	pub owner: TimerOwner,
+	pub action: TimerAction,
+}
+
+///
+/// Отменить таймер
+/// - C->S
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelTimerCommand {
+	pub timer_id: TimerId,
+}
+
+const DELAY_TAG: u8 = 0;
+const SERVER_TIME_TAG: u8 = 1;
+
+impl TimerAction {
+	#[must_use]
+	pub fn get_object_id(&self) -> GameObjectId {
+		match self {
+			TimerAction::Mutation(mutation) => mutation.get_object_id(),
+			TimerAction::Event(command) => command.object_id,
+			TimerAction::Delete(command) => command.object_id,
+		}
+	}
+
+	#[must_use]
+	pub fn get_field(&self) -> Option<Field> {
+		match self {
+			TimerAction::Mutation(mutation) => Some(mutation.get_field()),
+			TimerAction::Event(command) => Some(Field {
+				id: command.field_id,
+				field_type: FieldType::Event,
+			}),
+			TimerAction::Delete(_) => None,
+		}
+	}
+
+	pub(crate) fn get_type_id(&self) -> CommandTypeId {
+		match self {
+			TimerAction::Mutation(mutation) => mutation.get_type_id(),
+			TimerAction::Event(_) => CommandTypeId::Event,
+			TimerAction::Delete(_) => CommandTypeId::Delete,
+		}
+	}
+
+	fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_u8(self.get_type_id() as u8)?;
+		match self {
+			TimerAction::Mutation(mutation) => mutation.encode_payload(out),
+			TimerAction::Event(command) => command.encode(out),
+			TimerAction::Delete(_) => Ok(()),
+		}
+	}
+
+	fn decode(
+		object_id: Result<GameObjectId, CommandContextError>,
+		field_id: Result<FieldId, CommandContextError>,
+		input: &mut Cursor<&[u8]>,
+	) -> Result<Self, CommandDecodeError> {
+		let command_type_id = input.read_u8()?;
+		let command_type_id = CommandTypeId::from_u8(command_type_id).ok_or(CommandContextError::UnknownCommandTypeId(command_type_id))?;
+		// вложенные составные команды не декодируем
+		if matches!(
+			command_type_id,
+			CommandTypeId::Transaction | CommandTypeId::Forwarded | CommandTypeId::ScheduleTimer
+		) {
+			return Err(CommandDecodeError::UnknownTypeId(command_type_id));
+		}
+		Ok(match C2SCommand::decode(command_type_id, object_id, field_id, input)? {
+			C2SCommand::Event(command) => TimerAction::Event(command),
+			C2SCommand::Delete(command) => TimerAction::Delete(command),
+			command => TimerAction::Mutation(TransactionMutation::from_c2s(command).ok_or(CommandDecodeError::UnknownTypeId(command_type_id))?),
+		})
+	}
+}
+
+impl TimerTime {
+	fn encode(self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		let (tag, value) = match self {
+			TimerTime::Delay(value) => (DELAY_TAG, value),
+			TimerTime::ServerTime(value) => (SERVER_TIME_TAG, value),
+		};
+		out.write_u8(tag)?;
+		out.write_variable_u64(value)
+	}
+
+	fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let tag = input.read_u8()?;
+		let value = input.read_variable_u64()?;
+		match tag {
+			DELAY_TAG => Ok(TimerTime::Delay(value)),
+			SERVER_TIME_TAG => Ok(TimerTime::ServerTime(value)),
+			_ => Err(Error::new(ErrorKind::InvalidData, format!("Invalid timer time tag {tag}"))),
+		}
+	}
+}
+
+impl ScheduleTimerCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(u64::from(self.timer_id))?;
+		self.time.encode(out)?;
+		out.write_u8(self.owner as u8)?;
+		self.action.encode(out)
+	}
+
+	pub fn decode(
+		object_id: Result<GameObjectId, CommandContextError>,
+		field_id: Result<FieldId, CommandContextError>,
+		input: &mut Cursor<&[u8]>,
+	) -> Result<Self, CommandDecodeError> {
+		let timer_id = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+		let time = TimerTime::decode(input)?;
+		let owner = input.read_u8()?;
+		let owner = TimerOwner::from_u8(owner).ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("Invalid timer owner {owner}")))?;
+		Ok(Self {
+			timer_id,
+			time,
+			owner,
+			action: TimerAction::decode(object_id, field_id, input)?,
+		})
+	}
+}
+
+impl CancelTimerCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(u64::from(self.timer_id))
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let timer_id = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+		Ok(Self { timer_id })
+	}
+}
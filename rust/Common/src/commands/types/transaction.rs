@@ -0,0 +1,355 @@
+use std::io::{Cursor, Error, ErrorKind};
+
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::FromPrimitive;
+
+use crate::commands::c2s::C2SCommand;
+use crate::commands::field::{Field, FieldId};
+use crate::commands::types::field::{DeleteFieldCommand, SetFieldCommand};
+use crate::commands::types::float::{IncrementDoubleC2SCommand, IncrementDoubleWithBoundsC2SCommand};
+use crate::commands::types::long::{IncrementLongC2SCommand, IncrementLongWithBoundsC2SCommand};
+use crate::commands::{CommandDecodeError, CommandTypeId, FieldType, FieldValue};
+use crate::protocol::codec::commands::context::CommandContextError;
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::protocol::frame::output::MAX_ENCODED_COMMANDS_SIZE;
+use crate::room::object::GameObjectId;
+
+///
+/// Максимальное количество предусловий в транзакции
+///
+pub const MAX_TRANSACTION_PRECONDITIONS: usize = 8;
+
+///
+/// Максимальное количество изменений в транзакции
+///
+pub const MAX_TRANSACTION_MUTATIONS: usize = 16;
+
+///
+/// Максимальный размер данных транзакции в байтах, часть фрейма оставлена под заголовок команды
+///
+pub const MAX_TRANSACTION_ENCODED_SIZE: usize = MAX_ENCODED_COMMANDS_SIZE - 64;
+
+///
+/// Атомарное изменение нескольких полей
+///
+/// Сервер проверяет права доступа и предусловия, затем применяет все изменения или ни одного.
+///
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TransactionCommand {
+	pub preconditions: Vec<TransactionPrecondition>,
+	pub mutations: Vec<TransactionMutation>,
+}
+
+///
+/// Тип сравнения текущего значения поля в предусловии
+///
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum PreconditionKind {
+	///
+	/// Значение поля равно заданному
+	///
+	Equal = 0,
+	///
+	/// Значение поля больше заданного, только для long и double
+	///
+	GreaterThan,
+}
+
+///
+/// Условие выполнения транзакции
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionPrecondition {
+	pub object_id: GameObjectId,
+	pub field_id: FieldId,
+	pub kind: PreconditionKind,
+	pub value: FieldValue,
+}
+
+///
+/// Изменение поля в составе транзакции
+///
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum TransactionMutation {
+	SetField(SetFieldCommand),
+	IncrementLong(IncrementLongC2SCommand),
+	IncrementDouble(IncrementDoubleC2SCommand),
+	IncrementLongWithBounds(IncrementLongWithBoundsC2SCommand),
+	IncrementDoubleWithBounds(IncrementDoubleWithBoundsC2SCommand),
+	DeleteField(DeleteFieldCommand),
+}
+
+impl TransactionPrecondition {
+	#[must_use]
+	pub fn get_field(&self) -> Field {
+		Field {
+			id: self.field_id,
+			field_type: self.value.field_type(),
+		}
+	}
+
+	///
+	/// Проверить условие для текущего значения поля, отсутствующее поле условию не удовлетворяет
+	///
+	#[must_use]
+	pub fn check(&self, current: Option<&FieldValue>) -> bool {
+		let Some(current) = current else {
+			return false;
+		};
+		match self.kind {
+			PreconditionKind::Equal => *current == self.value,
+			PreconditionKind::GreaterThan => match (current, &self.value) {
+				(FieldValue::Long(current), FieldValue::Long(value)) => current > value,
+				(FieldValue::Double(current), FieldValue::Double(value)) => current > value,
+				_ => false,
+			},
+		}
+	}
+
+	fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		self.object_id.encode(out)?;
+		out.write_variable_u64(u64::from(self.field_id))?;
+		out.write_u8(self.kind as u8)?;
+		self.value.field_type().encode(out)?;
+		self.value.encode(out)
+	}
+
+	fn decode(input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let object_id = GameObjectId::decode(input)?;
+		let field_id = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+		let kind = input.read_u8()?;
+		let kind = PreconditionKind::from_u8(kind).ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("Invalid precondition kind {kind}")))?;
+		let field_type = FieldType::decode(input)?;
+		let value = FieldValue::decode_by_type(field_type, input)?;
+		Ok(Self {
+			object_id,
+			field_id,
+			kind,
+			value,
+		})
+	}
+}
+
+impl TransactionMutation {
+	#[must_use]
+	pub fn get_object_id(&self) -> GameObjectId {
+		match self {
+			TransactionMutation::SetField(command) => command.object_id,
+			TransactionMutation::IncrementLong(command) => command.object_id,
+			TransactionMutation::IncrementDouble(command) => command.object_id,
+			TransactionMutation::IncrementLongWithBounds(command) => command.object_id,
+			TransactionMutation::IncrementDoubleWithBounds(command) => command.object_id,
+			TransactionMutation::DeleteField(command) => command.object_id,
+		}
+	}
+
+	#[must_use]
+	pub fn get_field(&self) -> Field {
+		let (id, field_type) = match self {
+			TransactionMutation::SetField(command) => (command.field_id, command.value.field_type()),
+			TransactionMutation::IncrementLong(command) => (command.field_id, FieldType::Long),
+			TransactionMutation::IncrementDouble(command) => (command.field_id, FieldType::Double),
+			TransactionMutation::IncrementLongWithBounds(command) => (command.field_id, FieldType::Long),
+			TransactionMutation::IncrementDoubleWithBounds(command) => (command.field_id, FieldType::Double),
+			TransactionMutation::DeleteField(command) => (command.field_id, command.field_type),
+		};
+		Field { id, field_type }
+	}
+
+	pub(crate) fn get_type_id(&self) -> CommandTypeId {
+		match self {
+			TransactionMutation::SetField(command) => command.get_type_id(),
+			TransactionMutation::IncrementLong(_) => CommandTypeId::IncrementLong,
+			TransactionMutation::IncrementDouble(_) => CommandTypeId::IncrementDouble,
+			TransactionMutation::IncrementLongWithBounds(_) => CommandTypeId::IncrementLongWithBounds,
+			TransactionMutation::IncrementDoubleWithBounds(_) => CommandTypeId::IncrementDoubleWithBounds,
+			TransactionMutation::DeleteField(_) => CommandTypeId::DeleteField,
+		}
+	}
+
+	fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_u8(self.get_type_id() as u8)?;
+		self.get_object_id().encode(out)?;
+		out.write_variable_u64(u64::from(self.get_field().id))?;
+		self.encode_payload(out)
+	}
+
+	///
+	/// Данные команды без типа, идентификаторов объекта и поля
+	///
+	pub(crate) fn encode_payload(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		match self {
+			TransactionMutation::SetField(command) => command.encode(out),
+			TransactionMutation::IncrementLong(command) => command.encode(out),
+			TransactionMutation::IncrementDouble(command) => command.encode(out),
+			TransactionMutation::IncrementLongWithBounds(command) => command.encode(out),
+			TransactionMutation::IncrementDoubleWithBounds(command) => command.encode(out),
+			TransactionMutation::DeleteField(command) => command.encode(out),
+		}
+	}
+
+	fn decode(input: &mut Cursor<&[u8]>) -> Result<Self, CommandDecodeError> {
+		let command_type_id = input.read_u8()?;
+		let command_type_id = CommandTypeId::from_u8(command_type_id).ok_or(CommandContextError::UnknownCommandTypeId(command_type_id))?;
+		// вложенные составные команды не декодируем
+		if matches!(
+			command_type_id,
+			CommandTypeId::Transaction | CommandTypeId::Forwarded | CommandTypeId::ScheduleTimer
+		) {
+			return Err(CommandDecodeError::UnknownTypeId(command_type_id));
+		}
+		let object_id = GameObjectId::decode(input)?;
+		let field_id = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+		Self::from_c2s(C2SCommand::decode(command_type_id, Ok(object_id), Ok(field_id), input)?)
+			.ok_or(CommandDecodeError::UnknownTypeId(command_type_id))
+	}
+
+	///
+	/// Изменение поля из команды, None - команда не изменяет значение поля
+	///
+	pub(crate) fn from_c2s(command: C2SCommand) -> Option<Self> {
+		Some(match command {
+			C2SCommand::SetField(command) => TransactionMutation::SetField(command),
+			C2SCommand::IncrementLongValue(command) => TransactionMutation::IncrementLong(command),
+			C2SCommand::IncrementDouble(command) => TransactionMutation::IncrementDouble(command),
+			C2SCommand::IncrementLongWithBounds(command) => TransactionMutation::IncrementLongWithBounds(command),
+			C2SCommand::IncrementDoubleWithBounds(command) => TransactionMutation::IncrementDoubleWithBounds(command),
+			C2SCommand::DeleteField(command) => TransactionMutation::DeleteField(command),
+			_ => return None,
+		})
+	}
+}
+
+impl TransactionCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.preconditions.len() as u64)?;
+		self.preconditions.iter().try_for_each(|precondition| precondition.encode(out))?;
+		out.write_variable_u64(self.mutations.len() as u64)?;
+		self.mutations.iter().try_for_each(|mutation| mutation.encode(out))
+	}
+
+	///
+	/// Размер данных транзакции в байтах, None - превышен [`MAX_TRANSACTION_ENCODED_SIZE`]
+	///
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)]
+	pub fn encoded_size(&self) -> Option<usize> {
+		let mut buffer = [0; MAX_TRANSACTION_ENCODED_SIZE];
+		let mut out = Cursor::new(buffer.as_mut_slice());
+		self.encode(&mut out).ok()?;
+		Some(out.position() as usize)
+	}
+
+	pub fn decode(input: &mut Cursor<&[u8]>) -> Result<Self, CommandDecodeError> {
+		let start = input.position();
+		let preconditions = Self::read_len(input, MAX_TRANSACTION_PRECONDITIONS)?;
+		let preconditions = (0..preconditions)
+			.map(|_| TransactionPrecondition::decode(input))
+			.collect::<std::io::Result<Vec<_>>>()?;
+		let mutations = Self::read_len(input, MAX_TRANSACTION_MUTATIONS)?;
+		let mutations = (0..mutations)
+			.map(|_| TransactionMutation::decode(input))
+			.collect::<Result<Vec<_>, _>>()?;
+		let size = input.position() - start;
+		if size > MAX_TRANSACTION_ENCODED_SIZE as u64 {
+			return Err(Error::new(ErrorKind::InvalidData, format!("Transaction size to big {size}")).into());
+		}
+		Ok(Self { preconditions, mutations })
+	}
+
+	fn read_len(input: &mut Cursor<&[u8]>, max: usize) -> std::io::Result<usize> {
+		let len: usize = input.read_variable_u64()?.try_into().map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+		if len > max {
+			return Err(Error::new(ErrorKind::InvalidData, format!("Transaction items count to big {len}")));
+		}
+		Ok(len)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::Cursor;
+
+	use crate::commands::binary_value::BinaryValue;
+	use crate::commands::c2s::C2SCommand;
+	use crate::commands::types::field::SetFieldCommand;
+	use crate::commands::types::transaction::{
+		PreconditionKind, TransactionCommand, TransactionMutation, TransactionPrecondition, MAX_TRANSACTION_MUTATIONS,
+	};
+	use crate::commands::FieldValue;
+	use crate::protocol::frame::applications::{BothDirectionCommand, ChannelGroup, ChannelSequence, CommandWithChannel};
+	use crate::protocol::frame::channel::Channel;
+	use crate::protocol::frame::output::OutFrame;
+	use crate::room::object::GameObjectId;
+	use crate::room::owner::GameObjectOwner;
+
+	#[test]
+	fn should_check_equal() {
+		let precondition = precondition(PreconditionKind::Equal, 10.into());
+		assert!(precondition.check(Some(&10.into())));
+		assert!(!precondition.check(Some(&11.into())));
+		assert!(!precondition.check(None));
+	}
+
+	#[test]
+	fn should_check_greater_than() {
+		let precondition = precondition(PreconditionKind::GreaterThan, 1.5.into());
+		assert!(precondition.check(Some(&2.0.into())));
+		assert!(!precondition.check(Some(&1.5.into())));
+		assert!(!precondition.check(Some(&2.into())));
+	}
+
+	///
+	/// Транзакция максимального размера помещается в один фрейм вместе с заголовком команды
+	///
+	#[test]
+	fn should_fit_max_size_transaction_into_frame() {
+		let mut transaction = TransactionCommand::default();
+		while transaction.encoded_size().is_some() {
+			transaction.mutations.push(structure_mutation());
+		}
+		transaction.mutations.pop();
+		assert!(transaction.encoded_size().is_some());
+
+		let mut frame = OutFrame::new(0);
+		assert!(frame.add_command(CommandWithChannel {
+			channel: Channel::ReliableSequence(ChannelGroup(u8::MAX), ChannelSequence(u32::MAX)),
+			both_direction_command: BothDirectionCommand::C2S(C2SCommand::Transaction(transaction)),
+		}));
+	}
+
+	#[test]
+	fn should_not_decode_oversized_transaction() {
+		let transaction = TransactionCommand {
+			preconditions: Default::default(),
+			mutations: (0..MAX_TRANSACTION_MUTATIONS).map(|_| structure_mutation()).collect(),
+		};
+		assert!(transaction.encoded_size().is_none());
+
+		let mut buffer = [0; 8192];
+		let mut out = Cursor::new(buffer.as_mut_slice());
+		transaction.encode(&mut out).unwrap();
+		let size = out.position() as usize;
+		assert!(TransactionCommand::decode(&mut Cursor::new(&buffer[0..size])).is_err());
+	}
+
+	fn structure_mutation() -> TransactionMutation {
+		TransactionMutation::SetField(SetFieldCommand {
+			object_id: GameObjectId::new(u32::MAX, GameObjectOwner::Member(u16::MAX)),
+			field_id: u16::MAX,
+			value: BinaryValue::from([1; 200].as_slice()).into(),
+		})
+	}
+
+	fn precondition(kind: PreconditionKind, value: FieldValue) -> TransactionPrecondition {
+		TransactionPrecondition {
+			object_id: GameObjectId::default(),
+			field_id: 1,
+			kind,
+			value,
+		}
+	}
+}
@@ -0,0 +1,27 @@
+use std::io::Cursor;
+
+use crate::protocol::codec::variable_int::{VariableIntReader, VariableIntWriter};
+use crate::room::object::GameObjectId;
+
+///
+/// Установить время жизни объекта в миллисекундах, отсчет начинается заново от времени выполнения команды на сервере
+///
+/// - 0 - время жизни не ограничено
+/// - C->S
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetObjectTtlCommand {
+	pub object_id: GameObjectId,
+	pub ttl: u64,
+}
+
+impl SetObjectTtlCommand {
+	pub fn encode(&self, out: &mut Cursor<&mut [u8]>) -> std::io::Result<()> {
+		out.write_variable_u64(self.ttl)
+	}
+
+	pub fn decode(object_id: GameObjectId, input: &mut Cursor<&[u8]>) -> std::io::Result<Self> {
+		let ttl = input.read_variable_u64()?;
+		Ok(Self { object_id, ttl })
+	}
+}
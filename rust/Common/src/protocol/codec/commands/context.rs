@@ -25,6 +25,7 @@ pub struct CommandContext {
 	field_id: Option<FieldId>,
 	channel_group: Option<ChannelGroup>,
 	creator: Option<RoomMemberId>,
+	time: Option<u64>,
 }
 
 ///
@@ -81,6 +82,10 @@ impl CommandContext {
 		self.field_id.ok_or(CommandContextError::ContextNotContainsCreator)
 	}
 
+	pub(crate) fn get_time(&self) -> Option<u64> {
+		self.time
+	}
+
 	pub(crate) fn get_object_id(&self) -> Result<GameObjectId, CommandContextError> {
 		self.object_id.ok_or(CommandContextError::ContextNotContainsObjectId)
 	}
@@ -100,6 +105,7 @@ impl CommandContext {
 		channel_type_id: ChannelType,
 		command_type_id: CommandTypeId,
 		creator: Option<RoomMemberId>,
+		time: Option<u64>,
 		out: &mut Cursor<&mut [u8]>,
 	) -> std::io::Result<()> {
 		let mut header = CommandHeader::new();
@@ -123,6 +129,11 @@ impl CommandContext {
 			header.new_channel_group_id = true;
 		}
 
+		if compare_and_set(&mut self.time, time) {
+			out.write_variable_u64(*self.time.as_ref().unwrap())?;
+			header.new_time = true;
+		}
+
 		let creator_source = self.determinate_creator_source(creator);
 		if let CreatorSource::New = creator_source {
 			let creator = creator.unwrap();
@@ -183,6 +194,9 @@ impl CommandContext {
 		if header.new_channel_group_id {
 			self.channel_group.replace(ChannelGroup(input.read_u8()?));
 		}
+		if header.new_time {
+			self.time.replace(input.read_variable_u64()?);
+		}
 		self.read_and_set_creator(input, header.creator_source)?;
 		Ok(header)
 	}
@@ -283,6 +297,7 @@ pub mod tests {
 		channel_type_id: ChannelType,
 		command_type_id: CommandTypeId,
 		creator: Option<RoomMemberId>,
+		time: Option<u64>,
 		size: u64,
 	}
 	///
@@ -298,6 +313,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: None,
+				time: None,
 				size: 2, //flags
 			},
 			Params {
@@ -307,6 +323,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: None,
+				time: None,
 				size: 2 + 2, //flags + object_id
 			},
 			Params {
@@ -316,6 +333,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: None,
+				time: None,
 				size: 2, // flags
 			},
 			Params {
@@ -325,6 +343,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: None,
+				time: None,
 				size: 3, // flags + field_id
 			},
 			Params {
@@ -334,6 +353,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: None,
+				time: None,
 				size: 2, // flags
 			},
 			Params {
@@ -343,6 +363,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: None,
+				time: None,
 				size: 3, // flags + channel_group
 			},
 			Params {
@@ -352,6 +373,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: None,
+				time: None,
 				size: 2, // flags
 			},
 			Params {
@@ -361,6 +383,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: Some(7),
+				time: None,
 				size: 3, // flags+creator
 			},
 			Params {
@@ -370,6 +393,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: Some(7),
+				time: None,
 				size: 4, // flags + object_id
 			},
 			Params {
@@ -379,6 +403,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: Some(7),
+				time: None,
 				size: 3, // flags + field_id
 			},
 			Params {
@@ -388,6 +413,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: Some(5),
+				time: None,
 				size: 2, // flags
 			},
 		];
@@ -405,6 +431,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: Some(5),
+				time: None,
 				size: 2 + 1, //flags + сохранение идентификатора пользователя
 			},
 			Params {
@@ -414,6 +441,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: Some(5),
+				time: None,
 				size: 2, //flags + пользователь не поменялся
 			},
 			Params {
@@ -423,6 +451,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: Some(5),
+				time: None,
 				size: 2 + 2, //flags + игровой объект
 			},
 			Params {
@@ -432,6 +461,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: Some(7),
+				time: None,
 				size: 2, //flags + пользователь равен владельцу игрового объекта, отдельного
 				         // сохранения не требуется
 			},
@@ -442,6 +472,7 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: Some(7),
+				time: None,
 				size: 4, //flags + object_id
 			},
 			Params {
@@ -451,12 +482,50 @@ pub mod tests {
 				channel_type_id: ChannelType(5),
 				command_type_id: CommandTypeId::CreateGameObject,
 				creator: Some(9),
+				time: None,
 				size: 3, //flags + member_id
 			},
 		];
 		check(&params);
 	}
 
+	#[test]
+	fn test_time() {
+		let params = vec![
+			Params {
+				object_id: None,
+				field_id: None,
+				channel_group: None,
+				channel_type_id: ChannelType(5),
+				command_type_id: CommandTypeId::CreateGameObject,
+				creator: None,
+				time: Some(1_000),
+				size: 2 + 3, //flags + время
+			},
+			Params {
+				object_id: None,
+				field_id: None,
+				channel_group: None,
+				channel_type_id: ChannelType(5),
+				command_type_id: CommandTypeId::CreateGameObject,
+				creator: None,
+				time: Some(1_000),
+				size: 2, //flags + время не поменялось
+			},
+			Params {
+				object_id: None,
+				field_id: None,
+				channel_group: None,
+				channel_type_id: ChannelType(5),
+				command_type_id: CommandTypeId::CreateGameObject,
+				creator: None,
+				time: Some(100_000),
+				size: 2 + 4, //flags + новое время
+			},
+		];
+		check(&params);
+	}
+
 	fn check(params: &[Params]) {
 		let mut buffer = [0_u8; 100];
 		let mut cursor = Cursor::new(buffer.as_mut());
@@ -472,6 +541,7 @@ pub mod tests {
 					param.channel_type_id,
 					param.command_type_id,
 					param.creator,
+					param.time,
 					&mut cursor,
 				)
 				.unwrap();
@@ -489,6 +559,7 @@ pub mod tests {
 			assert_eq!(header.channel_type_id, param.channel_type_id, "channel_type_id");
 			assert_eq!(header.command_type_id, param.command_type_id, "command_type_id");
 			assert_eq!(read_context.creator, param.creator, "creator");
+			assert_eq!(read_context.time, param.time, "time");
 		}
 
 		assert_eq!(write_position, read_cursor.position());
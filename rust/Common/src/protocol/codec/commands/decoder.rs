@@ -44,6 +44,7 @@ fn decode_command(
 		} else {
 			BothDirectionCommand::S2CWithCreator(S2CCommandWithCreator {
 				creator: context.get_creator()?,
+				time: context.get_time(),
 				command: S2CCommand::decode(&header.command_type_id, context.get_object_id(), context.get_field_id(), input)?,
 			})
 		},
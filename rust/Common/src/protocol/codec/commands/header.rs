@@ -17,6 +17,7 @@ pub(crate) struct CommandHeader {
 	pub(crate) new_object_id: bool,
 	pub(crate) new_field_id: bool,
 	pub(crate) new_channel_group_id: bool,
+	pub(crate) new_time: bool,
 	pub(crate) creator_source: CreatorSource,
 	pub(crate) channel_type_id: ChannelType,
 	pub(crate) command_type_id: CommandTypeId,
@@ -25,6 +26,7 @@ pub(crate) struct CommandHeader {
 const NEW_OBJECT_ID_BIT: u16 = 15;
 const NEW_FIELD_ID_BIT: u16 = 14;
 const NEW_CHANNEL_GROUP_ID_BIT: u16 = 13;
+const NEW_TIME_BIT: u16 = 12;
 
 impl CommandHeader {
 	pub(crate) fn new() -> Self {
@@ -32,6 +34,7 @@ impl CommandHeader {
 			new_object_id: false,
 			new_field_id: false,
 			new_channel_group_id: false,
+			new_time: false,
 			creator_source: CreatorSource::NotSupported,
 			channel_type_id: ChannelType(0),
 			command_type_id: CommandTypeId::CreatedGameObject,
@@ -44,6 +47,7 @@ impl CommandHeader {
 			new_object_id: (header & 1 << NEW_OBJECT_ID_BIT) > 0,
 			new_field_id: (header & 1 << NEW_FIELD_ID_BIT) > 0,
 			new_channel_group_id: (header & 1 << NEW_CHANNEL_GROUP_ID_BIT) > 0,
+			new_time: (header & 1 << NEW_TIME_BIT) > 0,
 			creator_source: CreatorSource::try_from(((header & 0b110_0000_0000) >> 9) as u8)?,
 			channel_type_id: ChannelType(((header & 0b1_1100_0000) >> 6) as u8),
 			command_type_id: FromPrimitive::from_u8(command_type_id).ok_or(CommandContextError::UnknownCommandTypeId(command_type_id))?,
@@ -56,6 +60,7 @@ impl CommandHeader {
 		header += self.command_type_id as u16;
 		header += u16::from(self.channel_type_id.0) << 6;
 		header += u16::from(u8::from(&self.creator_source)) << 9;
+		header += if self.new_time { 1 << NEW_TIME_BIT } else { 0 };
 		header += if self.new_channel_group_id { 1 << NEW_CHANNEL_GROUP_ID_BIT } else { 0 };
 		header += if self.new_field_id { 1 << NEW_FIELD_ID_BIT } else { 0 };
 		header += if self.new_object_id { 1 << NEW_OBJECT_ID_BIT } else { 0 };
@@ -81,6 +86,7 @@ mod tests {
 			new_object_id: false,
 			new_field_id: false,
 			new_channel_group_id: false,
+			new_time: false,
 			creator_source: CreatorSource::NotSupported,
 			channel_type_id: ChannelType(0),
 			command_type_id: CommandTypeId::CreateGameObject,
@@ -89,6 +95,7 @@ mod tests {
 			new_object_id: true,
 			new_field_id: false,
 			new_channel_group_id: false,
+			new_time: false,
 			creator_source: CreatorSource::New,
 			channel_type_id: ChannelType(7),
 			command_type_id: CommandTypeId::CreateGameObject,
@@ -97,6 +104,7 @@ mod tests {
 			new_object_id: false,
 			new_field_id: true,
 			new_channel_group_id: false,
+			new_time: false,
 			creator_source: CreatorSource::Current,
 			channel_type_id: ChannelType(5),
 			command_type_id: CommandTypeId::SetStructure,
@@ -106,6 +114,7 @@ mod tests {
 			new_object_id: false,
 			new_field_id: false,
 			new_channel_group_id: true,
+			new_time: false,
 			creator_source: CreatorSource::AsObjectOwner,
 			channel_type_id: ChannelType(3),
 			command_type_id: CommandTypeId::CreateGameObject,
@@ -115,6 +124,7 @@ mod tests {
 			new_object_id: true,
 			new_field_id: true,
 			new_channel_group_id: true,
+			new_time: true,
 			creator_source: CreatorSource::NotSupported,
 			channel_type_id: ChannelType(7),
 			command_type_id: CommandTypeId::CreateGameObject,
@@ -137,5 +147,6 @@ mod tests {
 		assert_eq!(actual.new_channel_group_id, header.new_channel_group_id, "new_channel_group_id");
 		assert_eq!(actual.new_field_id, header.new_field_id, "new_field_id");
 		assert_eq!(actual.new_object_id, header.new_object_id, "new_object_id");
+		assert_eq!(actual.new_time, header.new_time, "new_time");
 	}
 }
@@ -60,6 +60,7 @@ mod tests {
 						value: 1.5.into(),
 					}),
 					creator: 55,
+					time: Some(1_000),
 				}),
 			},
 			CommandWithChannel {
@@ -71,6 +72,7 @@ mod tests {
 						value: 1.into(),
 					}),
 					creator: 57,
+					time: Some(1_000),
 				}),
 			},
 		];
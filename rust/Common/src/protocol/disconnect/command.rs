@@ -72,6 +72,10 @@ pub enum DisconnectByCommandReason {
 	ClientStopped = 0,
 	RoomDeleted,
 	MemberDeleted,
+	///
+	/// Пользователь превысил лимит частоты команд
+	///
+	CommandRateLimitExceeded,
 }
 
 impl DisconnectHeader {
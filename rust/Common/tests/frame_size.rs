@@ -31,6 +31,8 @@ pub fn create_object_frame() {
 			object_id: Default::default(),
 			template: 0,
 			access_groups: Default::default(),
+			parent: None,
+			inherit_access_groups: false,
 		})),
 	});
 	frame.add_command(CommandWithChannel {
@@ -14,7 +14,8 @@ use crate::proto::matches::realtime::internal::{
 	CreateMemberRequest, CreateMemberResponse, CreateSuperMemberRequest, DeleteMemberRequest, DeleteMemberResponse, DeleteRoomRequest,
 	DeleteRoomResponse, EmptyRequest, GetRoomInfoRequest, GetRoomInfoResponse, MarkRoomAsReadyRequest, MarkRoomAsReadyResponse, ProbeRequest,
 	ProbeResponse, PutForwardedCommandConfigRequest, PutForwardedCommandConfigResponse, RoomIdResponse, RoomLifecycleResponse, RoomTemplate,
-	UpdateRoomPermissionsRequest, UpdateRoomPermissionsResponse,
+	UpdateMemberGroupsRequest, UpdateMemberGroupsResponse, UpdateObjectGroupsRequest, UpdateObjectGroupsResponse, UpdateRoomPermissionsRequest,
+	UpdateRoomPermissionsResponse,
 };
 
 pub struct RealtimeStub<CreatedEventStubFunc, Fut>
@@ -84,6 +85,14 @@ where
 	) -> Result<Response<UpdateRoomPermissionsResponse>, Status> {
 		unreachable!()
 	}
+
+	async fn update_member_groups(&self, _request: Request<UpdateMemberGroupsRequest>) -> Result<Response<UpdateMemberGroupsResponse>, Status> {
+		unreachable!()
+	}
+
+	async fn update_object_groups(&self, _request: Request<UpdateObjectGroupsRequest>) -> Result<Response<UpdateObjectGroupsResponse>, Status> {
+		unreachable!()
+	}
 }
 
 pub fn create_stub_server<F, Fut>(f: F) -> (Runtime, JoinHandle<Result<(), Error>>, Channel)
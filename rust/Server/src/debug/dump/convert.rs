@@ -1,28 +1,41 @@
 use std::collections::HashMap;
 
 use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::timer::TimerOwner;
+use cheetah_common::room::object::GameObjectId;
 use cheetah_common::room::owner::GameObjectOwner;
 
 use crate::debug::proto::admin;
 use crate::debug::proto::shared::GameObjectField;
 use crate::room::object::GameObject;
+use crate::room::timer::Timer;
 use crate::room::{Member, Room};
 
 impl From<&Room> for admin::DumpResponse {
 	fn from(room: &Room) -> Self {
 		let users = room.members.values().map(admin::DumpUser::from).collect();
 		let objects = room.objects.iter().map(|(_k, o)| admin::DumpObject::from(o)).collect();
-		Self { users, objects }
+		let timers = room.timers.iter().map(admin::DumpTimer::from).collect();
+		Self { users, objects, timers }
+	}
+}
+
+impl From<&Timer> for admin::DumpTimer {
+	fn from(timer: &Timer) -> Self {
+		Self {
+			creator: u32::from(timer.creator),
+			timer_id: timer.timer_id,
+			room_owner: timer.owner == TimerOwner::Room,
+			fire_at: timer.fire_at,
+			action: format!("{:?}", timer.action),
+		}
 	}
 }
 
 impl From<&GameObject> for admin::DumpObject {
 	fn from(source: &GameObject) -> Self {
 		Self {
-			owner_user_id: match &source.id.get_owner() {
-				GameObjectOwner::Room => None,
-				GameObjectOwner::Member(id) => Some(u32::from(*id)),
-			},
+			owner_user_id: get_owner_user_id(&source.id),
 			id: source.id.id,
 			template: u32::from(source.template_id),
 			groups: source.access_groups.0,
@@ -36,10 +49,26 @@ impl From<&GameObject> for admin::DumpObject {
 				})
 				.collect(),
 			compare_and_set_owners: from(source.get_compare_and_set_owners()),
+			expire_at: source.expire_at,
+			parent: source.parent.map(|parent| admin::DumpObjectId {
+				id: parent.id,
+				owner_user_id: get_owner_user_id(&parent),
+			}),
+			transferred_owner_user_id: match source.owner {
+				GameObjectOwner::Member(id) if source.owner != source.id.get_owner() => Some(u32::from(id)),
+				_ => None,
+			},
 		}
 	}
 }
 
+fn get_owner_user_id(object_id: &GameObjectId) -> Option<u32> {
+	match object_id.get_owner() {
+		GameObjectOwner::Room => None,
+		GameObjectOwner::Member(id) => Some(u32::from(id)),
+	}
+}
+
 fn from<IN: Clone, OUT: From<IN>, const N: usize>(source: &heapless::FnvIndexMap<FieldId, IN, N>) -> HashMap<u32, OUT> {
 	source.iter().map(|(k, v)| (u32::from(*k), OUT::from(v.clone()))).collect()
 }
@@ -1,4 +1,7 @@
+use cheetah_common::commands::c2s::C2SCommand;
 use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::s2c::S2CCommand;
+use cheetah_common::commands::types::room_event::RoomEventId;
 use cheetah_common::commands::FieldType;
 use cheetah_common::constants::GameObjectTemplateId;
 use cheetah_common::room::object::GameObjectId;
@@ -31,6 +34,7 @@ pub enum Rule {
 	RoomOwner,
 	MemberOwner(RoomMemberId),
 	ObjectId(u32),
+	RoomEvent(RoomEventId),
 	True,
 }
 
@@ -78,6 +82,14 @@ impl TracedBothDirectionCommand {
 			TracedBothDirectionCommand::S2C(command) => command.get_object_id(),
 		}
 	}
+
+	pub(crate) fn get_room_event_id(&self) -> Option<RoomEventId> {
+		match self {
+			TracedBothDirectionCommand::C2S(C2SCommand::RoomEvent(command)) => Some(command.event_id),
+			TracedBothDirectionCommand::S2C(S2CCommand::RoomEvent(command)) => Some(command.event_id),
+			_ => None,
+		}
+	}
 }
 
 impl Rule {
@@ -115,6 +127,10 @@ impl Rule {
 				None => false,
 				Some(game_object_id) => game_object_id.id == *object_id,
 			},
+			Rule::RoomEvent(event_id) => match command.network_command.get_room_event_id() {
+				None => false,
+				Some(room_event_id) => room_event_id == *event_id,
+			},
 			Rule::True => true,
 		}
 	}
@@ -126,7 +142,9 @@ mod tests {
 	use cheetah_common::commands::field::FieldId;
 	use cheetah_common::commands::s2c::S2CCommand;
 	use cheetah_common::commands::types::event::EventCommand;
+	use cheetah_common::commands::types::room_event::{RoomEventCommand, RoomEventS2CCommand, RoomEventTarget};
 	use cheetah_common::constants::GameObjectTemplateId;
+	use cheetah_common::room::access::AccessGroups;
 	use cheetah_common::room::object::GameObjectId;
 	use cheetah_common::room::owner::GameObjectOwner;
 	use cheetah_common::room::RoomMemberId;
@@ -277,6 +295,29 @@ mod tests {
 		assert!(!filter.filter(&TracedCommand::c2s().with_object_id(GameObjectId::new(50, GameObjectOwner::Room))));
 	}
 
+	#[test]
+	fn should_filter_by_room_event() {
+		let filter = Filter::new(Rule::RoomEvent(5));
+		let c2s = TracedCommand {
+			network_command: TracedBothDirectionCommand::C2S(C2SCommand::RoomEvent(RoomEventCommand {
+				event_id: 5,
+				target: RoomEventTarget::Groups(AccessGroups(1)),
+				event: Default::default(),
+			})),
+			..TracedCommand::c2s()
+		};
+		let s2c = TracedCommand {
+			network_command: TracedBothDirectionCommand::S2C(S2CCommand::RoomEvent(RoomEventS2CCommand {
+				event_id: 7,
+				event: Default::default(),
+			})),
+			..TracedCommand::s2c()
+		};
+		assert!(filter.filter(&c2s));
+		assert!(!filter.filter(&s2c));
+		assert!(!filter.filter(&TracedCommand::c2s()));
+	}
+
 	#[test]
 	fn should_filter_or() {
 		let filter = Filter::new(Rule::OrRule(vec![Rule::Template(100), Rule::Template(55), Rule::Member(55)]));
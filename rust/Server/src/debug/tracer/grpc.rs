@@ -151,6 +151,9 @@ impl From<TracedCommand> for admin::Command {
 				FieldType::Double => shared::FieldType::Double,
 				FieldType::Structure => shared::FieldType::Structure,
 				FieldType::Event => shared::FieldType::Event,
+				FieldType::List => shared::FieldType::List,
+				FieldType::Map => shared::FieldType::Map,
+				FieldType::String => shared::FieldType::String,
 			})
 			.map(|field_type| field_type as i32);
 		let value = get_string_value(&command);
@@ -505,6 +505,8 @@ pub mod tests {
 				object_id: Default::default(),
 				template: 100,
 				access_groups: Default::default(),
+				parent: None,
+				inherit_access_groups: false,
 			}),
 		);
 
@@ -519,6 +521,8 @@ pub mod tests {
 					object_id: Default::default(),
 					template: 100,
 					access_groups: Default::default(),
+					parent: None,
+					inherit_access_groups: false,
 				})),
 			}]
 		);
@@ -161,6 +161,10 @@ fn parse_field(query: String) -> Result<(Token, String), ParseError> {
 				let id = value.parse().map_err(|_| ParseError::ValueFormatError(value))?;
 				Ok(Rule::ObjectId(id))
 			}
+			"room_event" => {
+				let id = value.parse().map_err(|_| ParseError::ValueFormatError(value))?;
+				Ok(Rule::RoomEvent(id))
+			}
 			"owner" => {
 				if value == "room" {
 					Ok(Rule::RoomOwner)
@@ -295,6 +299,12 @@ mod test {
 		assert_eq!(result, Rule::ObjectId(155));
 	}
 	#[test]
+	fn should_parse_room_event() {
+		let query = "room_event=7";
+		let result = parse(query).unwrap();
+		assert_eq!(result, Rule::RoomEvent(7));
+	}
+	#[test]
 	fn should_parse_room_owner() {
 		let query = "owner=room";
 		let result = parse(query).unwrap();
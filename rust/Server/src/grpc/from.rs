@@ -1,9 +1,17 @@
+use std::time::Duration;
+
 use cheetah_common::commands::field::Field;
+use cheetah_common::commands::types::bounds::{Bounds, BoundsMode};
+use cheetah_common::commands::types::map::MapValue;
+use cheetah_common::commands::types::string::MAX_STRING_SIZE;
 use cheetah_common::{commands::FieldValue, room::access::AccessGroups};
 
-use crate::debug::proto::shared::{field_value::Variant as VariantDebug, FieldValue as GRPCFieldValueDebug};
+use crate::debug::proto::shared::{
+	field_value::Variant as VariantDebug, map_item_value::Variant as MapItemVariantDebug, FieldValue as GRPCFieldValueDebug,
+	ListValue as ListValueDebug, MapItemValue as MapItemValueDebug, MapValue as MapValueDebug,
+};
 use crate::grpc::proto::internal;
-use crate::grpc::proto::shared::{self, field_value::Variant, FieldValue as GRPCFieldValue};
+use crate::grpc::proto::shared::{self, field_value::Variant, map_item_value::Variant as MapItemVariant, FieldValue as GRPCFieldValue};
 use crate::room::template::config;
 
 impl From<internal::RoomTemplate> for config::RoomTemplate {
@@ -12,6 +20,154 @@ impl From<internal::RoomTemplate> for config::RoomTemplate {
 			name: source.template_name,
 			objects: source.objects.into_iter().map(config::GameObjectTemplate::from).collect(),
 			permissions: config::Permissions::from(source.permissions.unwrap_or_default()),
+			structure_delta: source.structure_delta,
+			tick_rate: (source.tick_rate > 0).then_some(source.tick_rate),
+			lockstep_deadline: (source.lockstep_deadline_ms > 0).then(|| Duration::from_millis(u64::from(source.lockstep_deadline_ms))),
+			server_time: source.server_time,
+			notify_rejected_commands: source.notify_rejected_commands,
+			max_string_length: (source.max_string_length > 0).then_some(source.max_string_length as usize),
+			field_bounds: source.field_bounds.into_iter().map(config::FieldBoundsTemplate::from).collect(),
+			object_ttl: source.object_ttl.into_iter().map(config::ObjectTtlTemplate::from).collect(),
+			interest: source.interest.and_then(interest),
+			rate_limits: source.rate_limits.map(config::RateLimitsTemplate::from),
+			field_validations: source.field_validations.into_iter().filter_map(field_validation).collect(),
+			field_history: source.field_history.into_iter().filter_map(field_history).collect(),
+		}
+	}
+}
+
+impl From<internal::RateLimits> for config::RateLimitsTemplate {
+	#[allow(clippy::cast_possible_truncation)]
+	fn from(source: internal::RateLimits) -> Self {
+		config::RateLimitsTemplate {
+			limits: source
+				.limits
+				.into_iter()
+				.filter_map(|limit| match num::FromPrimitive::from_u32(limit.command_type_id) {
+					None => {
+						tracing::error!("unknown command_type_id {} in rate limit", limit.command_type_id);
+						None
+					}
+					Some(command_type_id) => Some(config::RateLimitTemplate {
+						command_type_id,
+						field_id: limit.field_id.map(|field_id| field_id as u16),
+						burst: limit.burst,
+						per_second: limit.per_second,
+					}),
+				})
+				.collect(),
+			warn_after: (source.warn_after > 0).then_some(source.warn_after),
+			disconnect_after: (source.disconnect_after > 0).then_some(source.disconnect_after),
+		}
+	}
+}
+
+///
+/// Тип числового поля (long или double), для остальных типов - None
+///
+pub(super) fn numeric_field_type(field_type: i32) -> Option<cheetah_common::commands::FieldType> {
+	match field_type {
+		x if x == shared::FieldType::Long as i32 => Some(cheetah_common::commands::FieldType::Long),
+		x if x == shared::FieldType::Double as i32 => Some(cheetah_common::commands::FieldType::Double),
+		_ => None,
+	}
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn field_history(source: internal::FieldHistory) -> Option<config::FieldHistoryTemplate> {
+	let Some(field_type) = numeric_field_type(source.field_type) else {
+		tracing::error!("unsupported field_type {} in field history", source.field_type);
+		return None;
+	};
+	Some(config::FieldHistoryTemplate {
+		template: source.template as u16,
+		field: Field {
+			id: source.field_id as u16,
+			field_type,
+		},
+		depth: Duration::from_millis(u64::from(source.depth_ms)),
+	})
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn field_validation(source: internal::FieldValidation) -> Option<config::FieldValidationTemplate> {
+	let Some(field_type) = numeric_field_type(source.field_type) else {
+		tracing::error!("unsupported field_type {} in field validation", source.field_type);
+		return None;
+	};
+	let rule = match source.rule.expect("FieldValidation rule was empty") {
+		internal::field_validation::Rule::Range(rule) => config::FieldValidationRule::Range {
+			min: rule.min,
+			max: rule.max,
+		},
+		internal::field_validation::Rule::Rate(rule) => config::FieldValidationRule::Rate {
+			min_delta: rule.min_delta,
+			max_delta: rule.max_delta,
+			period: Duration::from_millis(u64::from(rule.period_ms)),
+		},
+	};
+	Some(config::FieldValidationTemplate {
+		template: source.template as u16,
+		field: Field {
+			id: source.field_id as u16,
+			field_type,
+		},
+		rule,
+		reaction: num::FromPrimitive::from_i32(source.reaction).expect("Enum field validation reaction unrecognized"),
+	})
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn interest(source: internal::Interest) -> Option<config::InterestTemplate> {
+	if !(source.cell_size > 0.0 && source.cell_size.is_finite()) {
+		tracing::error!("invalid cell_size {} in interest", source.cell_size);
+		return None;
+	}
+	Some(config::InterestTemplate {
+		cell_size: source.cell_size,
+		view_radius: source.view_radius,
+		positions: source
+			.positions
+			.into_iter()
+			.map(|position| config::PositionFieldsTemplate {
+				template: position.template as u16,
+				x_field_id: position.x_field_id as u16,
+				y_field_id: position.y_field_id as u16,
+			})
+			.collect(),
+	})
+}
+
+impl From<internal::ObjectTtl> for config::ObjectTtlTemplate {
+	#[allow(clippy::cast_possible_truncation)]
+	fn from(source: internal::ObjectTtl) -> Self {
+		config::ObjectTtlTemplate {
+			template: source.template as u16,
+			ttl: Duration::from_millis(source.ttl_ms),
+		}
+	}
+}
+
+impl From<internal::FieldBounds> for config::FieldBoundsTemplate {
+	#[allow(clippy::cast_possible_truncation)]
+	fn from(source: internal::FieldBounds) -> Self {
+		let mode = if source.reject { BoundsMode::Reject } else { BoundsMode::Clamp };
+		let bounds = match source.bounds.expect("FieldBounds was empty") {
+			internal::field_bounds::Bounds::Long(b) => config::FieldBounds::Long(Bounds {
+				min: b.min,
+				max: b.max,
+				mode,
+			}),
+			internal::field_bounds::Bounds::Double(b) => config::FieldBounds::Double(Bounds {
+				min: b.min,
+				max: b.max,
+				mode,
+			}),
+		};
+		config::FieldBoundsTemplate {
+			template: source.template as u16,
+			field_id: source.field_id as u16,
+			bounds,
 		}
 	}
 }
@@ -35,9 +191,21 @@ impl From<internal::GameObjectTemplate> for config::GameObjectTemplate {
 			fields: source
 				.fields
 				.into_iter()
-				.map(|f| {
+				.filter_map(|f| {
 					let field_value: FieldValue = f.value.expect("Field with no value").into();
-					((f.id as u16, field_value.field_type()), field_value)
+					// строка больше MAX_STRING_SIZE не может быть отправлена клиенту
+					if let FieldValue::String(value) = &field_value {
+						if value.len() > MAX_STRING_SIZE {
+							tracing::error!(
+								"string field {} size {} more than {MAX_STRING_SIZE} in object {}",
+								f.id,
+								value.len(),
+								source.id
+							);
+							return None;
+						}
+					}
+					Some(((f.id as u16, field_value.field_type()), field_value))
 				})
 				.collect(),
 		}
@@ -51,16 +219,77 @@ impl From<GRPCFieldValue> for FieldValue {
 			Variant::Double(v) => FieldValue::Double(v),
 			Variant::Long(v) => FieldValue::Long(v),
 			Variant::Structure(s) => FieldValue::Structure(s.as_slice().into()),
+			Variant::List(l) => FieldValue::List(l.items.iter().map(|item| item.as_slice().into()).collect()),
+			Variant::Map(m) => FieldValue::Map(
+				m.items
+					.into_iter()
+					.map(|(key, value)| {
+						let value = match value.variant.expect("MapItemValue was empty") {
+							MapItemVariant::Long(v) => MapValue::Long(v),
+							MapItemVariant::Double(v) => MapValue::Double(v),
+							MapItemVariant::Binary(v) => MapValue::Binary(v.as_slice().into()),
+						};
+						(key, value)
+					})
+					.collect(),
+			),
+			Variant::String(s) => FieldValue::String(s),
 		}
 	}
 }
 
+impl From<FieldValue> for GRPCFieldValue {
+	fn from(value: FieldValue) -> Self {
+		let variant = match value {
+			FieldValue::Double(v) => Variant::Double(v),
+			FieldValue::Long(v) => Variant::Long(v),
+			FieldValue::Structure(s) => Variant::Structure(s.as_slice().into()),
+			FieldValue::List(items) => Variant::List(shared::ListValue {
+				items: items.iter().map(|item| item.as_slice().into()).collect(),
+			}),
+			FieldValue::Map(items) => Variant::Map(shared::MapValue {
+				items: items
+					.into_iter()
+					.map(|(key, value)| {
+						let value = match value {
+							MapValue::Long(v) => MapItemVariant::Long(v),
+							MapValue::Double(v) => MapItemVariant::Double(v),
+							MapValue::Binary(v) => MapItemVariant::Binary(v.as_slice().into()),
+						};
+						(key, shared::MapItemValue { variant: Some(value) })
+					})
+					.collect(),
+			}),
+			FieldValue::String(s) => Variant::String(s),
+		};
+
+		GRPCFieldValue { variant: Some(variant) }
+	}
+}
+
 impl From<FieldValue> for GRPCFieldValueDebug {
 	fn from(value: FieldValue) -> Self {
 		let value_d = match value {
 			FieldValue::Double(v) => VariantDebug::Double(v),
 			FieldValue::Long(v) => VariantDebug::Long(v),
 			FieldValue::Structure(s) => VariantDebug::Structure(s.as_slice().into()),
+			FieldValue::List(items) => VariantDebug::List(ListValueDebug {
+				items: items.iter().map(|item| item.as_slice().into()).collect(),
+			}),
+			FieldValue::Map(items) => VariantDebug::Map(MapValueDebug {
+				items: items
+					.into_iter()
+					.map(|(key, value)| {
+						let value = match value {
+							MapValue::Long(v) => MapItemVariantDebug::Long(v),
+							MapValue::Double(v) => MapItemVariantDebug::Double(v),
+							MapValue::Binary(v) => MapItemVariantDebug::Binary(v.as_slice().into()),
+						};
+						(key, MapItemValueDebug { variant: Some(value) })
+					})
+					.collect(),
+			}),
+			FieldValue::String(s) => VariantDebug::String(s),
 		};
 
 		GRPCFieldValueDebug { variant: Some(value_d) }
@@ -71,6 +300,17 @@ impl From<internal::Permissions> for config::Permissions {
 	fn from(source: internal::Permissions) -> Self {
 		config::Permissions {
 			templates: source.objects.into_iter().map(config::GameObjectTemplatePermission::from).collect(),
+			room_events: source.room_events.into_iter().map(config::RoomEventPermission::from).collect(),
+		}
+	}
+}
+
+impl From<internal::RoomEventPermission> for config::RoomEventPermission {
+	#[allow(clippy::cast_possible_truncation)]
+	fn from(source: internal::RoomEventPermission) -> Self {
+		config::RoomEventPermission {
+			event_id: source.event_id as u16,
+			rules: source.rules.into_iter().map(config::GroupsPermissionRule::from).collect(),
 		}
 	}
 }
@@ -102,12 +342,18 @@ impl From<internal::PermissionField> for config::PermissionField {
 		let double = shared::FieldType::Double as i32;
 		let long = shared::FieldType::Long as i32;
 		let structure = shared::FieldType::Structure as i32;
+		let list = shared::FieldType::List as i32;
+		let map = shared::FieldType::Map as i32;
+		let string = shared::FieldType::String as i32;
 
 		let field_type = match source.r#type {
 			x if x == event => cheetah_common::commands::FieldType::Event,
 			x if x == double => cheetah_common::commands::FieldType::Double,
 			x if x == long => cheetah_common::commands::FieldType::Long,
 			x if x == structure => cheetah_common::commands::FieldType::Structure,
+			x if x == list => cheetah_common::commands::FieldType::List,
+			x if x == map => cheetah_common::commands::FieldType::Map,
+			x if x == string => cheetah_common::commands::FieldType::String,
 			_ => {
 				panic!("Enum field_type unrecognized {}", source.r#type)
 			}
@@ -6,10 +6,13 @@ use tokio::sync::{mpsc, MutexGuard};
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
 
-use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::field::{Field, FieldId};
 use cheetah_common::commands::CommandTypeId;
 use cheetah_common::constants::GameObjectTemplateId;
 use cheetah_common::protocol::others::member_id::MemberAndRoomId;
+use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::owner::GameObjectOwner;
 use cheetah_common::room::RoomId;
 
 use crate::grpc::proto::internal::internal_server::Internal;
@@ -234,6 +237,72 @@ impl Internal for RealtimeInternalService {
 			.map(|_| Response::new(UpdateRoomPermissionsResponse {}))
 			.map_err(Status::from)
 	}
+
+	async fn update_member_groups(&self, request: Request<UpdateMemberGroupsRequest>) -> Result<Response<UpdateMemberGroupsResponse>, Status> {
+		let request = request.get_ref();
+		let id = MemberAndRoomId {
+			member_id: request
+				.user_id
+				.try_into()
+				.map_err(|e| Status::invalid_argument(format!("member_id is too big: {e}")))?,
+			room_id: request.room_id,
+		};
+		self.server_manager
+			.lock()
+			.await
+			.update_member_groups(id, AccessGroups(request.groups))
+			.map(|_| Response::new(UpdateMemberGroupsResponse {}))
+			.map_err(Status::from)
+	}
+
+	async fn update_object_groups(&self, request: Request<UpdateObjectGroupsRequest>) -> Result<Response<UpdateObjectGroupsResponse>, Status> {
+		let request = request.get_ref();
+		let owner = match request.owner_user_id {
+			None => GameObjectOwner::Room,
+			Some(owner_user_id) => GameObjectOwner::Member(
+				owner_user_id
+					.try_into()
+					.map_err(|e| Status::invalid_argument(format!("owner_user_id is too big: {e}")))?,
+			),
+		};
+		self.server_manager
+			.lock()
+			.await
+			.update_object_groups(request.room_id, GameObjectId::new(request.object_id, owner), AccessGroups(request.groups))
+			.map(|_| Response::new(UpdateObjectGroupsResponse {}))
+			.map_err(Status::from)
+	}
+
+	async fn get_field_history(&self, request: Request<GetFieldHistoryRequest>) -> Result<Response<GetFieldHistoryResponse>, Status> {
+		let request = request.get_ref();
+		let owner = match request.owner_user_id {
+			None => GameObjectOwner::Room,
+			Some(owner_user_id) => GameObjectOwner::Member(
+				owner_user_id
+					.try_into()
+					.map_err(|e| Status::invalid_argument(format!("owner_user_id is too big: {e}")))?,
+			),
+		};
+		let object_id = GameObjectId::new(request.object_id, owner);
+		let field = Field {
+			id: request
+				.field_id
+				.try_into()
+				.map_err(|e| Status::invalid_argument(format!("field_id is too big: {e}")))?,
+			field_type: from::numeric_field_type(request.field_type)
+				.ok_or_else(|| Status::invalid_argument(format!("unsupported field_type {}", request.field_type)))?,
+		};
+		self.server_manager
+			.lock()
+			.await
+			.get_field_history_value(request.room_id, object_id, field, request.time_ms)
+			.map(|value| {
+				Response::new(GetFieldHistoryResponse {
+					value: value.map(Into::into),
+				})
+			})
+			.map_err(Status::from)
+	}
 }
 
 impl From<TaskError> for Status {
@@ -247,6 +316,7 @@ impl From<TaskError> for Status {
 			TaskError::TaskExecutionError(TaskExecutionError::ServerCommandError(server_err)) => match server_err {
 				ServerCommandError::MemberNotFound(e) => Status::not_found(e.to_string()),
 				ServerCommandError::RoomNotFound(e) => Status::not_found(e.to_string()),
+				e @ ServerCommandError::GameObjectNotFound { .. } => Status::not_found(e.to_string()),
 				e => Status::internal(e.to_string()),
 			},
 		}
@@ -272,17 +342,20 @@ mod test {
 	use tokio_stream::StreamExt;
 	use tonic::{Code, Request};
 
+	use cheetah_common::commands::types::string::MAX_STRING_SIZE;
 	use cheetah_common::commands::CommandTypeId;
 	use cheetah_common::network::bind_to_free_socket;
 
 	use crate::grpc::proto::internal::internal_server::Internal;
 	use crate::grpc::proto::internal::room_lifecycle_response::RoomLifecycleType;
 	use crate::grpc::proto::internal::{
-		DeleteMemberRequest, DeleteRoomRequest, EmptyRequest, GameObjectTemplatePermission, GetRoomInfoRequest, GroupsPermissionRule,
-		MarkRoomAsReadyRequest, Permissions, PutForwardedCommandConfigRequest, UpdateRoomPermissionsRequest,
+		DeleteMemberRequest, DeleteRoomRequest, EmptyRequest, FieldHistory, GameObjectTemplatePermission, GetFieldHistoryRequest, GetRoomInfoRequest,
+		GroupsPermissionRule, MarkRoomAsReadyRequest, Permissions, PutForwardedCommandConfigRequest, UpdateMemberGroupsRequest,
+		UpdateObjectGroupsRequest, UpdateRoomPermissionsRequest,
 	};
+	use crate::grpc::proto::shared::{field_value::Variant, FieldType, FieldValue, GameObjectField};
 	use crate::grpc::{RealtimeInternalService, SUPER_MEMBER_KEY_ENV};
-	use crate::room::template::config::{MemberTemplate, Permission, RoomTemplate};
+	use crate::room::template::config::{GameObjectTemplate, MemberTemplate, Permission, RoomTemplate};
 	use crate::server::manager::RoomsServerManager;
 
 	#[tokio::test]
@@ -381,6 +454,147 @@ mod test {
 		);
 	}
 
+	#[tokio::test]
+	async fn test_update_member_groups() {
+		let server_manager = Arc::new(Mutex::new(new_server_manager()));
+		let service = RealtimeInternalService::new(Arc::clone(&server_manager));
+
+		let room_id = service.create_room(Request::new(Default::default())).await.unwrap().into_inner().room_id;
+		let member_id = service
+			.register_member(room_id, MemberTemplate::default())
+			.await
+			.unwrap()
+			.into_inner()
+			.user_id;
+
+		service
+			.update_member_groups(Request::new(UpdateMemberGroupsRequest {
+				room_id,
+				user_id: member_id,
+				groups: 0b101,
+			}))
+			.await
+			.unwrap();
+
+		let dump = server_manager.lock().await.dump(room_id).unwrap();
+		assert_eq!(dump.users.iter().find(|u| u.id == member_id).unwrap().groups, 0b101);
+	}
+
+	#[tokio::test]
+	async fn test_update_member_groups_member_not_exist() {
+		let server_manager = Arc::new(Mutex::new(new_server_manager()));
+		let service = RealtimeInternalService::new(Arc::clone(&server_manager));
+		let room_id = service.create_room(Request::new(Default::default())).await.unwrap().into_inner().room_id;
+
+		let res = service
+			.update_member_groups(Request::new(UpdateMemberGroupsRequest {
+				room_id,
+				user_id: 100,
+				groups: 0b1,
+			}))
+			.await;
+
+		assert!(matches!(res.unwrap_err().code(), Code::NotFound));
+	}
+
+	#[tokio::test]
+	async fn test_update_object_groups() {
+		let server_manager = Arc::new(Mutex::new(new_server_manager()));
+		let service = RealtimeInternalService::new(Arc::clone(&server_manager));
+		let room_id = service
+			.create_room(Request::new(crate::grpc::proto::internal::RoomTemplate {
+				objects: vec![crate::grpc::proto::internal::GameObjectTemplate {
+					id: 1,
+					template: 5,
+					groups: 0b1,
+					fields: Default::default(),
+				}],
+				..Default::default()
+			}))
+			.await
+			.unwrap()
+			.into_inner()
+			.room_id;
+
+		service
+			.update_object_groups(Request::new(UpdateObjectGroupsRequest {
+				room_id,
+				object_id: 1,
+				owner_user_id: None,
+				groups: 0b110,
+			}))
+			.await
+			.unwrap();
+
+		let dump = server_manager.lock().await.dump(room_id).unwrap();
+		assert_eq!(dump.objects.iter().find(|o| o.id == 1).unwrap().groups, 0b110);
+
+		let res = service
+			.update_object_groups(Request::new(UpdateObjectGroupsRequest {
+				room_id,
+				object_id: 2,
+				owner_user_id: None,
+				groups: 0b110,
+			}))
+			.await;
+		assert!(matches!(res.unwrap_err().code(), Code::NotFound));
+	}
+
+	#[tokio::test]
+	async fn test_get_field_history() {
+		let server_manager = Arc::new(Mutex::new(new_server_manager()));
+		let service = RealtimeInternalService::new(Arc::clone(&server_manager));
+		let room_id = service
+			.create_room(Request::new(crate::grpc::proto::internal::RoomTemplate {
+				objects: vec![crate::grpc::proto::internal::GameObjectTemplate {
+					id: 1,
+					template: 5,
+					groups: 0b1,
+					fields: vec![GameObjectField {
+						id: 10,
+						value: Some(FieldValue {
+							variant: Some(Variant::Double(1.5)),
+						}),
+					}],
+				}],
+				field_history: vec![FieldHistory {
+					template: 5,
+					field_id: 10,
+					field_type: FieldType::Double as i32,
+					depth_ms: 1000,
+				}],
+				..Default::default()
+			}))
+			.await
+			.unwrap()
+			.into_inner()
+			.room_id;
+
+		let request = GetFieldHistoryRequest {
+			room_id,
+			object_id: 1,
+			owner_user_id: None,
+			field_id: 10,
+			field_type: FieldType::Double as i32,
+			time_ms: u64::MAX,
+		};
+		let value = service.get_field_history(Request::new(request.clone())).await.unwrap().into_inner().value;
+		assert_eq!(value.unwrap().variant, Some(Variant::Double(1.5)));
+
+		let res = service
+			.get_field_history(Request::new(GetFieldHistoryRequest {
+				field_type: FieldType::String as i32,
+				..request.clone()
+			}))
+			.await;
+		assert!(matches!(res.unwrap_err().code(), Code::InvalidArgument));
+
+		let res = service
+			.get_field_history(Request::new(GetFieldHistoryRequest { object_id: 2, ..request }))
+			.await;
+		assert!(matches!(res.unwrap_err().code(), Code::NotFound));
+	}
+
 	#[tokio::test]
 	async fn test_delete_member_room_not_exist() {
 		let server_manager = Arc::new(Mutex::new(new_server_manager()));
@@ -451,7 +665,7 @@ mod test {
 
 		let room_id = service.create_room(Request::new(Default::default())).await.unwrap().into_inner().room_id;
 
-		let tests = [(30, 0, 0), (0, u32::from(u16::MAX) + 1, 0), (0, 0, u32::from(u16::MAX) + 1)];
+		let tests = [(255, 0, 0), (0, u32::from(u16::MAX) + 1, 0), (0, 0, u32::from(u16::MAX) + 1)];
 		for (command_type_id, field_id, template_id) in tests {
 			let res = service
 				.put_forwarded_command_config(Request::new(PutForwardedCommandConfigRequest {
@@ -586,6 +800,39 @@ mod test {
 		status.unwrap();
 	}
 
+	#[test]
+	fn should_skip_too_long_string_field_in_template() {
+		let field = |id, value: &str| GameObjectField {
+			id,
+			value: Some(FieldValue {
+				variant: Some(Variant::String(value.to_owned())),
+			}),
+		};
+		let template = GameObjectTemplate::from(crate::grpc::proto::internal::GameObjectTemplate {
+			id: 1,
+			template: 5,
+			groups: 0b1,
+			fields: vec![field(1, &"a".repeat(MAX_STRING_SIZE)), field(2, &"я".repeat(MAX_STRING_SIZE))],
+		});
+		assert_eq!(template.fields.len(), 1);
+		assert!(template.fields.contains_key(&(1, cheetah_common::commands::FieldType::String)));
+	}
+
+	#[test]
+	fn should_skip_interest_with_invalid_cell_size() {
+		for cell_size in [0.0, -1.0, f64::NAN] {
+			let template = RoomTemplate::from(crate::grpc::proto::internal::RoomTemplate {
+				interest: Some(crate::grpc::proto::internal::Interest {
+					cell_size,
+					view_radius: 1,
+					positions: vec![],
+				}),
+				..Default::default()
+			});
+			assert!(template.interest.is_none());
+		}
+	}
+
 	fn new_server_manager() -> RoomsServerManager {
 		RoomsServerManager::new(bind_to_free_socket().unwrap(), FnvHashSet::default()).unwrap()
 	}
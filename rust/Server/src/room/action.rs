@@ -33,8 +33,34 @@ impl Room {
 	where
 		T: FnOnce(&mut GameObject) -> Result<Option<S2CCommand>, ServerCommandError>,
 	{
+		self.check_field_access(game_object_id, field, creator_id, permission)?;
+		let object = self.get_object(game_object_id)?;
+		let previous = self
+			.is_field_validated(object.template_id, field, creator_id)
+			.then(|| object.get_field_wrapped(field.id, field.field_type).cloned());
+		let object = self.get_object_mut(game_object_id)?;
+		if let Some(mut command) = action(object)? {
+			if let Some(previous) = previous {
+				command = self.validate_field_change(game_object_id, field, creator_id, previous, command)?;
+			}
+			self.send_field_command(game_object_id, field, creator_id, target, command)?;
+		}
+		Ok(())
+	}
+
+	///
+	/// Проверить права пользователя на доступ к полю объекта
+	///
+	/// - владелец объекта имеет полный доступ к полям объекта, информация о правах игнорируется
+	///
+	pub(crate) fn check_field_access(
+		&self,
+		game_object_id: GameObjectId,
+		field: Field,
+		creator_id: RoomMemberId,
+		permission: Permission,
+	) -> Result<(), ServerCommandError> {
 		let room_id = self.id;
-		let permission_manager = Rc::clone(&self.permission_manager);
 		let creator_access_group = match self.members.get(&creator_id) {
 			None => {
 				return Err(ServerCommandError::MemberNotFound(creator_id));
@@ -42,7 +68,7 @@ impl Room {
 			Some(member) => member.template.groups,
 		};
 
-		let object = self.get_object_mut(game_object_id)?;
+		let object = self.get_object(game_object_id)?;
 		// проверяем группу доступа
 		if !object.access_groups.contains_any(&creator_access_group) {
 			return Err(ServerCommandError::MemberCannotAccessToObject {
@@ -54,18 +80,13 @@ impl Room {
 			});
 		}
 
-		let object_owner = if let GameObjectOwner::Member(owner) = object.id.get_owner() {
-			Some(owner)
-		} else {
-			None
-		};
-
-		let is_creator_object_owner = object_owner == Some(creator_id);
+		let is_creator_object_owner = get_object_owner(object) == Some(creator_id);
 
 		let allow = is_creator_object_owner
-			|| permission_manager
+			|| self
+				.permission_manager
 				.borrow_mut()
-				.get_permission(object.template_id, field, creator_access_group)
+				.get_permission(object.template_id, field, creator_access_group, is_creator_object_owner)
 				>= permission;
 
 		if !allow {
@@ -77,41 +98,63 @@ impl Room {
 				field,
 			});
 		}
+		Ok(())
+	}
 
-		let command = action(object)?;
-		if let Some(command) = command {
-			// отправляем команду только для созданного объекта
-			if object.created {
-				let groups = object.access_groups;
-				let template = object.template_id;
+	///
+	/// Отправить результат изменения поля клиентам (клиенту), команда отправляется только для созданного объекта
+	///
+	pub(crate) fn send_field_command(
+		&mut self,
+		game_object_id: GameObjectId,
+		field: Field,
+		creator_id: RoomMemberId,
+		target: Option<RoomMemberId>,
+		command: S2CCommand,
+	) -> Result<(), ServerCommandError> {
+		self.record_field_history(game_object_id, field);
+		let object = self.get_object(game_object_id)?;
+		if !object.created {
+			return Ok(());
+		}
+		let groups = object.access_groups;
+		let template = object.template_id;
+		let object_owner = get_object_owner(object);
+		let permission_manager = Rc::clone(&self.permission_manager);
 
-				let commands_with_field = S2CCommandWithMeta {
-					field: Some(field),
-					creator: creator_id,
-					command,
-				};
-				let commands = [commands_with_field];
+		let commands_with_field = S2CCommandWithMeta {
+			field: Some(field),
+			creator: creator_id,
+			command,
+		};
+		let commands = [commands_with_field];
 
-				match target {
-					Some(target_member_id) => {
-						self.send_to_member(&target_member_id, template, &commands)?;
-					}
-					None => {
-						self.send_to_members(groups, Some(template), &commands, |member| {
-							let permission_manager = permission_manager.borrow_mut();
-							// отправляем себе только если есть права на запись
-							// иначе никто другой не может вносит изменения в данное поле и
-							// отправлять себе как единственному источнику изменений избыточно
-							if object_owner == Some(member.id) {
-								permission_manager.has_write_access(template, field)
-							} else {
-								true
-							}
-						})?;
+		match target {
+			Some(target_member_id) => {
+				self.send_to_member(&target_member_id, template, &commands)?;
+			}
+			None => {
+				self.send_to_members(groups, Some(template), &commands, |member| {
+					let permission_manager = permission_manager.borrow_mut();
+					// отправляем себе только если есть права на запись
+					// иначе никто другой не может вносит изменения в данное поле и
+					// отправлять себе как единственному источнику изменений избыточно
+					if object_owner == Some(member.id) {
+						permission_manager.has_write_access(template, field)
+					} else {
+						true
 					}
-				}
+				})?;
 			}
 		}
 		Ok(())
 	}
 }
+
+fn get_object_owner(object: &GameObject) -> Option<RoomMemberId> {
+	if let GameObjectOwner::Member(owner) = object.owner {
+		Some(owner)
+	} else {
+		None
+	}
+}
@@ -0,0 +1,259 @@
+use std::mem;
+
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+use cheetah_common::commands::types::access_groups::SetObjectAccessGroupsCommand;
+use cheetah_common::commands::types::delete::DeleteGameObjectCommand;
+use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::owner::GameObjectOwner;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+use crate::room::object::CreateCommandsCollector;
+use crate::room::Room;
+
+impl Room {
+	///
+	/// Изменить группы доступа объекта, пользователям, получившим доступ, отправляется создание объекта с текущими полями,
+	/// потерявшим доступ - удаление объекта
+	///
+	/// - группы доступа дочерних объектов, созданных с наследованием групп, изменяются вместе с родительским;
+	/// - родительские объекты создаются раньше дочерних и удаляются позже.
+	///
+	pub(crate) fn set_object_access_groups(
+		&mut self,
+		object_id: GameObjectId,
+		access_groups: AccessGroups,
+		creator: RoomMemberId,
+	) -> Result<(), ServerCommandError> {
+		// родительские объекты в списке раньше дочерних
+		let mut changed = Vec::new();
+		let mut pending = vec![object_id];
+		while let Some(id) = pending.pop() {
+			let object = self.get_object_mut(id)?;
+			let old_groups = mem::replace(&mut object.access_groups, access_groups);
+			if old_groups == access_groups {
+				continue;
+			}
+			changed.push((id, old_groups));
+			pending.extend(
+				self.objects
+					.values()
+					.filter(|child| child.parent == Some(id) && child.inherit_access_groups)
+					.map(|child| child.id),
+			);
+		}
+
+		let mut creates = Vec::new();
+		for (object_id, old_groups) in changed.into_iter().rev() {
+			let object = self.get_object(object_id)?;
+			if !object.created {
+				continue;
+			}
+			let template = object.template_id;
+			// объекты с позицией загружаются при пересчете области интереса
+			if let Some(interest) = self.interest.as_mut() {
+				if interest.is_positional(template) {
+					interest.mark_object_dirty(object_id);
+					continue;
+				}
+			}
+
+			let (entered, left): (Vec<_>, Vec<_>) = self
+				.members
+				.values()
+				.filter(|member| member.attached && member.connected)
+				.filter(|member| old_groups.contains_any(&member.template.groups) != access_groups.contains_any(&member.template.groups))
+				.map(|member| member.id)
+				.partition(|member_id| self.members[member_id].template.groups.contains_any(&access_groups));
+
+			let delete = [S2CCommandWithMeta {
+				field: None,
+				creator,
+				command: S2CCommand::Delete(DeleteGameObjectCommand { object_id }),
+			}];
+			for member_id in left {
+				self.send_to_member(&member_id, template, &delete)?;
+			}
+			creates.push((object_id, template, entered));
+		}
+
+		for (object_id, template, entered) in creates.into_iter().rev() {
+			let mut commands = CreateCommandsCollector::new();
+			self.get_object(object_id)?.collect_create_commands(&mut commands, creator);
+			for member_id in entered {
+				self.send_to_member(&member_id, template, commands.as_slice())?;
+			}
+		}
+		Ok(())
+	}
+}
+
+///
+/// Изменить группы доступа объекта может его владелец, объекта комнаты - только super member
+///
+impl ServerCommandExecutor for SetObjectAccessGroupsCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let member_template = &room.get_member(&member_id)?.template;
+		let super_member = member_template.super_member;
+		let member_groups = member_template.groups;
+		let object = room.get_object(self.object_id)?;
+		if !super_member && object.owner != GameObjectOwner::Member(member_id) {
+			return Err(ServerCommandError::MemberNotOwnerGameObject {
+				object_id: self.object_id,
+				member_id,
+			});
+		}
+		// как и при создании объекта, владелец не может назначить группы вне своих
+		if !super_member && !self.access_groups.is_sub_groups(&member_groups) {
+			return Err(ServerCommandError::Error(format!(
+				"Incorrect access group {:?} with client groups {:?}",
+				self.access_groups, member_groups
+			)));
+		}
+		room.set_object_access_groups(self.object_id, self.access_groups, member_id)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::access_groups::SetObjectAccessGroupsCommand;
+	use cheetah_common::commands::types::field::SetFieldCommand;
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::owner::GameObjectOwner;
+
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::template::config::{MemberTemplate, RoomTemplate};
+	use crate::room::Room;
+
+	#[test]
+	fn should_send_create_and_delete_when_change_access_groups() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let owner = room.register_member(MemberTemplate::stub(AccessGroups(0b11)));
+		let member_a = room.register_member(MemberTemplate::stub(AccessGroups(0b01)));
+		let member_b = room.register_member(MemberTemplate::stub(AccessGroups(0b10)));
+		room.test_mark_as_connected(owner).unwrap();
+		room.test_mark_as_connected(member_a).unwrap();
+		room.test_mark_as_connected(member_b).unwrap();
+		let object = room.test_create_object_with_created_state(GameObjectOwner::Member(owner), AccessGroups(0b01));
+		object.set_field(1, 100).unwrap();
+		let object_id = object.id;
+
+		SetObjectAccessGroupsCommand {
+			object_id,
+			access_groups: AccessGroups(0b10),
+		}
+		.execute(&mut room, owner)
+		.unwrap();
+
+		assert_eq!(room.get_object(object_id).unwrap().access_groups, AccessGroups(0b10));
+		assert!(room.test_get_member_out_commands(owner).is_empty());
+
+		let commands = room.test_get_member_out_commands(member_a);
+		assert!(matches!(commands.get(0), Some(S2CCommand::Delete(c)) if c.object_id == object_id));
+		assert_eq!(commands.len(), 1);
+
+		let commands = room.test_get_member_out_commands(member_b);
+		assert!(matches!(commands.get(0), Some(S2CCommand::Create(c)) if c.object_id == object_id && c.access_groups == AccessGroups(0b10)));
+		assert!(matches!(commands.get(1), Some(S2CCommand::SetField(SetFieldCommand { field_id: 1, .. }))));
+		assert!(matches!(commands.get(2), Some(S2CCommand::Created(c)) if c.object_id == object_id));
+	}
+
+	#[test]
+	fn should_not_change_access_groups_if_not_owner() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let owner = room.register_member(MemberTemplate::stub(AccessGroups(0b11)));
+		let member = room.register_member(MemberTemplate::stub(AccessGroups(0b11)));
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(owner), AccessGroups(0b01))
+			.id;
+
+		assert_eq!(
+			SetObjectAccessGroupsCommand {
+				object_id,
+				access_groups: AccessGroups(0b10),
+			}
+			.execute(&mut room, member),
+			Err(ServerCommandError::MemberNotOwnerGameObject {
+				object_id,
+				member_id: member
+			})
+		);
+		assert_eq!(room.get_object(object_id).unwrap().access_groups, AccessGroups(0b01));
+	}
+
+	#[test]
+	fn should_not_change_access_groups_outside_owner_groups() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let owner = room.register_member(MemberTemplate::stub(AccessGroups(0b11)));
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(owner), AccessGroups(0b01))
+			.id;
+
+		let result = SetObjectAccessGroupsCommand {
+			object_id,
+			access_groups: AccessGroups(0b100),
+		}
+		.execute(&mut room, owner);
+		assert!(matches!(result, Err(ServerCommandError::Error(_))));
+		assert_eq!(room.get_object(object_id).unwrap().access_groups, AccessGroups(0b01));
+	}
+
+	#[test]
+	fn should_change_room_object_access_groups_by_super_member() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let super_member = room.register_member(MemberTemplate::new_super_member());
+		let member = room.register_member(MemberTemplate::stub(AccessGroups(0b11)));
+		let object_id = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(0b01)).id;
+		let command = SetObjectAccessGroupsCommand {
+			object_id,
+			access_groups: AccessGroups(0b10),
+		};
+
+		command.execute(&mut room, member).unwrap_err();
+		command.execute(&mut room, super_member).unwrap();
+		assert_eq!(room.get_object(object_id).unwrap().access_groups, AccessGroups(0b10));
+	}
+
+	///
+	/// Группы дочерних объектов с наследованием изменяются вместе с родительским, родительский создается раньше дочернего
+	///
+	#[test]
+	fn should_change_access_groups_of_inheriting_children() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let owner = room.register_member(MemberTemplate::stub(AccessGroups(0b11)));
+		let member = room.register_member(MemberTemplate::stub(AccessGroups(0b10)));
+		room.test_mark_as_connected(member).unwrap();
+		let parent_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(owner), AccessGroups(0b01))
+			.id;
+		let mut create_child = |inherit_access_groups| {
+			let child = room.test_create_object_with_created_state(GameObjectOwner::Member(owner), AccessGroups(0b01));
+			child.parent = Some(parent_id);
+			child.inherit_access_groups = inherit_access_groups;
+			child.id
+		};
+		let inheriting_id = create_child(true);
+		let other_id = create_child(false);
+
+		SetObjectAccessGroupsCommand {
+			object_id: parent_id,
+			access_groups: AccessGroups(0b10),
+		}
+		.execute(&mut room, owner)
+		.unwrap();
+
+		assert_eq!(room.get_object(inheriting_id).unwrap().access_groups, AccessGroups(0b10));
+		assert_eq!(room.get_object(other_id).unwrap().access_groups, AccessGroups(0b01));
+		let created: Vec<_> = room
+			.test_get_member_out_commands(member)
+			.into_iter()
+			.filter_map(|command| match command {
+				S2CCommand::Create(command) => Some(command.object_id),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(created, vec![parent_id, inheriting_id]);
+	}
+}
@@ -0,0 +1,79 @@
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::bounds::Bounds;
+use cheetah_common::commands::FieldType;
+use cheetah_common::room::object::GameObjectId;
+
+use crate::room::command::ServerCommandError;
+use crate::room::template::config::FieldBounds;
+use crate::room::Room;
+
+impl Room {
+	///
+	/// Ограничение значения целочисленного поля из шаблона комнаты
+	///
+	pub(crate) fn get_long_bounds(&self, object_id: GameObjectId, field_id: FieldId) -> Option<Bounds<i64>> {
+		match self.get_field_bounds(object_id, field_id, FieldType::Long)? {
+			FieldBounds::Long(bounds) => Some(bounds),
+			FieldBounds::Double(_) => None,
+		}
+	}
+
+	///
+	/// Ограничение значения вещественного поля из шаблона комнаты
+	///
+	pub(crate) fn get_double_bounds(&self, object_id: GameObjectId, field_id: FieldId) -> Option<Bounds<f64>> {
+		match self.get_field_bounds(object_id, field_id, FieldType::Double)? {
+			FieldBounds::Double(bounds) => Some(bounds),
+			FieldBounds::Long(_) => None,
+		}
+	}
+
+	///
+	/// Ограничение целочисленного поля для команды с границами от клиента - пересечение с ограничением из шаблона комнаты
+	///
+	pub(crate) fn get_long_bounds_with(
+		&self,
+		object_id: GameObjectId,
+		field_id: FieldId,
+		bounds: Bounds<i64>,
+	) -> Result<Bounds<i64>, ServerCommandError> {
+		intersect(object_id, field_id, self.get_long_bounds(object_id, field_id), bounds)
+	}
+
+	///
+	/// Ограничение вещественного поля для команды с границами от клиента - пересечение с ограничением из шаблона комнаты
+	///
+	pub(crate) fn get_double_bounds_with(
+		&self,
+		object_id: GameObjectId,
+		field_id: FieldId,
+		bounds: Bounds<f64>,
+	) -> Result<Bounds<f64>, ServerCommandError> {
+		intersect(object_id, field_id, self.get_double_bounds(object_id, field_id), bounds)
+	}
+
+	fn get_field_bounds(&self, object_id: GameObjectId, field_id: FieldId, field_type: FieldType) -> Option<FieldBounds> {
+		if self.field_bounds.is_empty() {
+			return None;
+		}
+		let template_id = self.get_object(object_id).ok()?.template_id;
+		self.field_bounds.get(&(template_id, field_id, field_type)).copied()
+	}
+}
+
+///
+/// Границы клиента не могут расширить границы из шаблона, при непересекающихся границах команда отклоняется
+///
+fn intersect<T: PartialOrd + Copy>(
+	object_id: GameObjectId,
+	field_id: FieldId,
+	template: Option<Bounds<T>>,
+	bounds: Bounds<T>,
+) -> Result<Bounds<T>, ServerCommandError> {
+	match template {
+		None => Ok(bounds),
+		Some(template) => template
+			.intersect(&bounds)
+			.ok_or(ServerCommandError::IncrementOutOfBounds { object_id, field_id }),
+	}
+}
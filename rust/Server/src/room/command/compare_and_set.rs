@@ -6,7 +6,7 @@ use cheetah_common::commands::field::{Field, FieldId};
 use cheetah_common::commands::s2c::S2CCommandWithMeta;
 use cheetah_common::commands::{
 	s2c::S2CCommand,
-	types::{long::CompareAndSetLongCommand, structure::CompareAndSetStructureCommand},
+	types::{float::CompareAndSetDoubleCommand, long::CompareAndSetLongCommand, structure::CompareAndSetStructureCommand},
 };
 use cheetah_common::commands::{FieldType, FieldValue};
 use cheetah_common::room::object::GameObjectId;
@@ -33,6 +33,20 @@ impl ServerCommandExecutor for CompareAndSetLongCommand {
 	}
 }
 
+impl ServerCommandExecutor for CompareAndSetDoubleCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		perform_compare_and_set(
+			room,
+			member_id,
+			self.object_id,
+			self.field_id,
+			&FieldValue::Double(self.current),
+			FieldValue::Double(self.new),
+			&self.get_reset().map(FieldValue::Double),
+		)
+	}
+}
+
 impl ServerCommandExecutor for CompareAndSetStructureCommand {
 	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
 		perform_compare_and_set(
@@ -87,7 +101,7 @@ pub fn perform_compare_and_set(
 			}
 			Some(reset_value) => {
 				cls.insert((object_id, field_id, field_type), reset_value.clone())
-					.map_err(|_| ServerCommandError::Error("CompareAndSetCleaners overflow".to_owned()))?;
+					.map_err(|_| ServerCommandError::CompareAndSetCleanersOverflow { object_id, field_id })?;
 			}
 		}
 	}
@@ -124,6 +138,13 @@ pub fn apply_reset(
 					let command = reset_value(object, field, reset, member_id)?;
 					let groups = object.access_groups;
 					let template = object.template_id;
+					room.record_field_history(
+						object_id,
+						Field {
+							id: field,
+							field_type: reset.field_type(),
+						},
+					);
 					room.send_to_members(groups, Some(template), slice::from_ref(&command), |_| true)?;
 				}
 			}
@@ -158,6 +179,7 @@ mod tests {
 	use cheetah_common::commands::field::Field;
 	use cheetah_common::commands::field::FieldId;
 	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::float::CompareAndSetDoubleCommand;
 	use cheetah_common::commands::types::long::CompareAndSetLongCommand;
 	use cheetah_common::commands::types::structure::CompareAndSetStructureCommand;
 	use cheetah_common::commands::FieldType;
@@ -200,6 +222,36 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_compare_and_set_double() {
+		let (mut room, member1_id, _, object_id, field_id) = setup();
+		CompareAndSetDoubleCommand::new(object_id, field_id, 0.0, 1.5, None)
+			.execute(&mut room, member1_id)
+			.unwrap();
+		CompareAndSetDoubleCommand::new(object_id, field_id, 0.0, 2.5, None)
+			.execute(&mut room, member1_id)
+			.unwrap();
+		assert_eq!(*room.get_object_mut(object_id).unwrap().get_field::<f64>(field_id).unwrap(), 1.5);
+
+		CompareAndSetDoubleCommand::new(object_id, field_id, 1.5, 3.5, None)
+			.execute(&mut room, member1_id)
+			.unwrap();
+		assert_eq!(*room.get_object_mut(object_id).unwrap().get_field::<f64>(field_id).unwrap(), 3.5);
+	}
+
+	///
+	/// Проверяем что при выходе пользователя будет установлено заданное значение для поля Double
+	///
+	#[test]
+	fn should_reset_double() {
+		let (mut room, member1_id, _, object_id, field_id) = setup();
+		CompareAndSetDoubleCommand::new(object_id, field_id, 0.0, 10.5, Some(-1.0))
+			.execute(&mut room, member1_id)
+			.unwrap();
+		room.disconnect_member(member1_id).unwrap();
+		assert_eq!(*room.get_object_mut(object_id).unwrap().get_field::<f64>(field_id).unwrap(), -1.0);
+	}
+
 	#[test]
 	fn should_compare_and_set_structure() {
 		let (mut room, member1_id, _, object_id, field_id) = setup();
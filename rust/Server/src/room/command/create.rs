@@ -14,7 +14,10 @@ impl ServerCommandExecutor for CreateGameObjectCommand {
 			return Err(ServerCommandError::Error("0 is forbidden for game object id".to_owned()));
 		}
 
-		let groups = self.access_groups;
+		let groups = match self.parent {
+			Some(parent) if self.inherit_access_groups => room.get_object(parent)?.access_groups,
+			_ => self.access_groups,
+		};
 
 		if !groups.is_sub_groups(&member.template.groups) {
 			return Err(ServerCommandError::Error(format!(
@@ -35,7 +38,25 @@ impl ServerCommandExecutor for CreateGameObjectCommand {
 		if room.contains_object(&self.object_id) {
 			return Err(ServerCommandError::Error(format!("Object already exists with id {:?}", self.object_id)));
 		}
-		room.insert_object(GameObject::new(self.object_id, self.template, groups, false));
+
+		if let Some(parent) = self.parent {
+			let parent_groups = room.get_object(parent)?.access_groups;
+			if !parent_groups.contains_any(&member.template.groups) {
+				return Err(ServerCommandError::MemberCannotAccessToObject {
+					room_id: room.id,
+					member_id,
+					object_id: parent,
+					member_access_group: member.template.groups,
+					object_access_group: parent_groups,
+				});
+			}
+		}
+
+		let mut object = GameObject::new(self.object_id, self.template, groups, false);
+		object.expire_at = room.get_template_expire_at(self.template);
+		object.parent = self.parent;
+		object.inherit_access_groups = self.parent.is_some() && self.inherit_access_groups;
+		room.insert_object(object);
 		Ok(())
 	}
 }
@@ -61,6 +82,8 @@ mod tests {
 			object_id,
 			template: 100,
 			access_groups: AccessGroups(0b10),
+			parent: None,
+			inherit_access_groups: false,
 		};
 		command.execute(&mut room, member_id).unwrap();
 
@@ -84,6 +107,8 @@ mod tests {
 			object_id,
 			template: 100,
 			access_groups: AccessGroups(0b10),
+			parent: None,
+			inherit_access_groups: false,
 		};
 
 		assert!(matches!(command.execute(&mut room, member_id), Err(ServerCommandError::Error(_))));
@@ -101,6 +126,8 @@ mod tests {
 			object_id,
 			template: 100,
 			access_groups: AccessGroups(0b1000),
+			parent: None,
+			inherit_access_groups: false,
 		};
 
 		assert!(matches!(command.execute(&mut room, member_id), Err(ServerCommandError::Error(_))));
@@ -119,6 +146,8 @@ mod tests {
 			object_id,
 			template: 100,
 			access_groups: AccessGroups(0b11),
+			parent: None,
+			inherit_access_groups: false,
 		};
 		assert!(matches!(command.execute(&mut room, member_id), Err(ServerCommandError::Error(_))));
 		assert!(matches!(room.get_object_mut(object_id), Err(_)));
@@ -139,12 +168,54 @@ mod tests {
 			object_id,
 			template: 100,
 			access_groups: AccessGroups(0b1000),
+			parent: None,
+			inherit_access_groups: false,
 		};
 
 		assert!(matches!(command.execute(&mut room, member_id), Err(ServerCommandError::Error(_))));
 		assert!(matches!(room.get_object_mut(object_id), Ok(object) if object.template_id == 777));
 	}
 
+	#[test]
+	fn should_create_child_with_parent_access_groups() {
+		let (mut room, member_id) = setup(AccessGroups(0b11));
+		let parent_id = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(0b10)).id;
+
+		let object_id = GameObjectId::new(1, GameObjectOwner::Member(member_id));
+		let command = CreateGameObjectCommand {
+			object_id,
+			template: 100,
+			access_groups: AccessGroups(0b11),
+			parent: Some(parent_id),
+			inherit_access_groups: true,
+		};
+		command.execute(&mut room, member_id).unwrap();
+
+		let object = room.get_object(object_id).unwrap();
+		assert_eq!(object.parent, Some(parent_id));
+		assert_eq!(object.access_groups, AccessGroups(0b10));
+		assert!(object.inherit_access_groups);
+	}
+
+	#[test]
+	fn should_not_create_child_when_parent_not_found() {
+		let (mut room, member_id) = setup(AccessGroups(0b11));
+		let parent_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let object_id = GameObjectId::new(1, GameObjectOwner::Member(member_id));
+		let command = CreateGameObjectCommand {
+			object_id,
+			template: 100,
+			access_groups: AccessGroups(0b11),
+			parent: Some(parent_id),
+			inherit_access_groups: false,
+		};
+		assert_eq!(
+			command.execute(&mut room, member_id),
+			Err(ServerCommandError::GameObjectNotFound { object_id: parent_id })
+		);
+		assert!(!room.contains_object(&object_id));
+	}
+
 	fn setup(access_groups: AccessGroups) -> (Room, u16) {
 		let template = RoomTemplate::default();
 		let mut room = Room::from_template(template);
@@ -32,9 +32,17 @@ impl ServerCommandExecutor for C2SCreatedGameObjectCommand {
 				room.set_singleton_key(*singleton_key, new_room_object_id);
 			}
 			room.room_object_id_generator += 1;
-			let mut object = room.delete_object(member_object_id, member_id)?;
+			// объект еще не создан - удаляем без оповещения и без удаления дочерних объектов
+			let Some(mut object) = room.objects.shift_remove(&member_object_id) else {
+				return Err(ServerCommandError::GameObjectNotFound { object_id: member_object_id });
+			};
 			object.id = new_room_object_id;
+			object.owner = GameObjectOwner::Room;
 			room.insert_object(object);
+			room.objects
+				.values_mut()
+				.filter(|child| child.parent == Some(member_object_id))
+				.for_each(|child| child.parent = Some(new_room_object_id));
 			room.get_object_mut(new_room_object_id)?
 		} else {
 			object
@@ -42,11 +50,18 @@ impl ServerCommandExecutor for C2SCreatedGameObjectCommand {
 
 		let groups = object.access_groups;
 		object.created = true;
+		let object_id = object.id;
+		let owner = object.owner;
+		// создатель объекта уже загрузил его
+		if let (Some(interest), GameObjectOwner::Member(owner)) = (room.interest.as_mut(), owner) {
+			interest.set_visible(owner, object_id);
+		}
+		let object = room.get_object_mut(object_id)?;
 		// объект полностью загружен - теперь его надо загрузить остальным клиентам
 		let mut commands = CreateCommandsCollector::new();
 		object.collect_create_commands(&mut commands, member_id);
 		let template = object.template_id;
-		if object.id.get_owner() == GameObjectOwner::Room {
+		if object.owner == GameObjectOwner::Room {
 			room.send_to_members(groups, Some(template), commands.as_slice(), |_| true)?;
 		} else {
 			room.send_to_members(groups, Some(template), commands.as_slice(), |member| member.id != member_id)?;
@@ -131,6 +146,8 @@ mod tests {
 			object_id: member_object_id,
 			template: 777,
 			access_groups,
+			parent: None,
+			inherit_access_groups: false,
 		};
 		create_command.execute(&mut room, member_id).unwrap();
 
@@ -169,6 +186,8 @@ mod tests {
 			object_id: member_object_id_1,
 			template: 777,
 			access_groups,
+			parent: None,
+			inherit_access_groups: false,
 		};
 		create_command.execute(&mut room, member_id).unwrap();
 		let created_command = C2SCreatedGameObjectCommand::new(member_object_id_1, true, singleton_key);
@@ -181,6 +200,8 @@ mod tests {
 			object_id: member_object_id_2,
 			template: 777,
 			access_groups,
+			parent: None,
+			inherit_access_groups: false,
 		};
 		create_command.execute(&mut room, member_id).unwrap();
 		let created_command = C2SCreatedGameObjectCommand::new(member_object_id_2, true, singleton_key);
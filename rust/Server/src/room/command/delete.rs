@@ -1,5 +1,4 @@
 use cheetah_common::commands::types::delete::DeleteGameObjectCommand;
-use cheetah_common::room::owner::GameObjectOwner;
 use cheetah_common::room::RoomMemberId;
 
 use crate::room::command::{ServerCommandError, ServerCommandExecutor};
@@ -7,15 +6,8 @@ use crate::room::Room;
 
 impl ServerCommandExecutor for DeleteGameObjectCommand {
 	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
-		let member = room.get_member(&member_id)?;
-		if let GameObjectOwner::Member(object_id_member) = self.object_id.get_owner() {
-			if object_id_member != member.id {
-				return Err(ServerCommandError::MemberNotOwnerGameObject {
-					object_id: self.object_id,
-					member_id,
-				});
-			}
-		}
+		room.get_member(&member_id)?;
+		room.check_object_owner(self.object_id, member_id)?;
 		room.delete_object(self.object_id, member_id)?;
 		Ok(())
 	}
@@ -56,6 +48,61 @@ mod tests {
 		assert!(matches!(room.test_get_member_out_commands(member_b_id).pop_back(), Some(S2CCommand::Delete(c)) if c==command));
 	}
 
+	#[test]
+	fn should_delete_children() {
+		let access_groups = AccessGroups(0b11);
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_a_id = room.register_member(MemberTemplate::stub(access_groups));
+		let member_b_id = room.register_member(MemberTemplate::stub(access_groups));
+		room.test_mark_as_connected(member_a_id).unwrap();
+		room.test_mark_as_connected(member_b_id).unwrap();
+
+		let vehicle_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_a_id), access_groups)
+			.id;
+		let turret = room.test_create_object_with_created_state(GameObjectOwner::Member(member_a_id), access_groups);
+		turret.parent = Some(vehicle_id);
+		let turret_id = turret.id;
+		let barrel = room.test_create_object_with_created_state(GameObjectOwner::Member(member_b_id), access_groups);
+		barrel.parent = Some(turret_id);
+		let barrel_id = barrel.id;
+		room.test_out_commands.clear();
+
+		DeleteGameObjectCommand { object_id: vehicle_id }.execute(&mut room, member_a_id).unwrap();
+
+		assert!(room.objects.is_empty());
+		let mut commands = room.test_get_member_out_commands(member_b_id);
+		assert!(matches!(commands.pop_front(), Some(S2CCommand::Delete(c)) if c.object_id == barrel_id));
+		assert!(matches!(commands.pop_front(), Some(S2CCommand::Delete(c)) if c.object_id == turret_id));
+		assert!(matches!(commands.pop_front(), Some(S2CCommand::Delete(c)) if c.object_id == vehicle_id));
+	}
+
+	///
+	/// Удаление глубокой иерархии не ограничено размером стека
+	///
+	#[test]
+	fn should_delete_deep_hierarchy() {
+		let access_groups = AccessGroups(0b11);
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(access_groups));
+		let root_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_id), access_groups)
+			.id;
+		let mut parent_id = root_id;
+		for _ in 0..10_000 {
+			let child = room.test_create_object_with_created_state(GameObjectOwner::Member(member_id), access_groups);
+			child.parent = Some(parent_id);
+			parent_id = child.id;
+		}
+		let other_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_id), access_groups)
+			.id;
+
+		DeleteGameObjectCommand { object_id: root_id }.execute(&mut room, member_id).unwrap();
+
+		assert_eq!(room.objects.keys().copied().collect::<Vec<_>>(), vec![other_id]);
+	}
+
 	#[test]
 	fn should_not_delete_if_not_owner() {
 		let template = RoomTemplate::default();
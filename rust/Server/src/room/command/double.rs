@@ -1,8 +1,10 @@
-use cheetah_common::commands::field::Field;
+use cheetah_common::commands::field::{Field, FieldId};
 use cheetah_common::commands::s2c::S2CCommand;
+use cheetah_common::commands::types::bounds::Bounds;
 use cheetah_common::commands::types::field::SetFieldCommand;
-use cheetah_common::commands::types::float::IncrementDoubleC2SCommand;
+use cheetah_common::commands::types::float::{IncrementDoubleC2SCommand, IncrementDoubleWithBoundsC2SCommand};
 use cheetah_common::commands::FieldType;
+use cheetah_common::room::object::GameObjectId;
 use cheetah_common::room::RoomMemberId;
 
 use crate::room::command::{ServerCommandError, ServerCommandExecutor};
@@ -12,48 +14,73 @@ use crate::room::Room;
 
 impl ServerCommandExecutor for IncrementDoubleC2SCommand {
 	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
-		let field_id = self.field_id;
-		let object_id = self.object_id;
-
-		let action = |object: &mut GameObject| {
-			let value = if let Some(value) = object.get_field::<f64>(field_id) {
-				let new_value = value + self.increment;
-				object.set_field(field_id, new_value)?;
-				new_value
-			} else {
-				object.set_field(field_id, self.increment)?;
-				self.increment
-			};
-			Ok(Some(S2CCommand::SetField(SetFieldCommand {
-				object_id: self.object_id,
-				field_id,
-				value: value.into(),
-			})))
-		};
+		let bounds = room.get_double_bounds(self.object_id, self.field_id);
+		increment(room, member_id, self.object_id, self.field_id, self.increment, bounds)
+	}
+}
 
-		room.send_command_from_action(
-			object_id,
-			Field {
-				id: field_id,
-				field_type: FieldType::Double,
-			},
-			member_id,
-			Permission::Rw,
-			None,
-			action,
-		)
+impl ServerCommandExecutor for IncrementDoubleWithBoundsC2SCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let bounds = room.get_double_bounds_with(self.object_id, self.field_id, self.bounds)?;
+		increment(room, member_id, self.object_id, self.field_id, self.increment, Some(bounds))
 	}
 }
 
+fn increment(
+	room: &mut Room,
+	member_id: RoomMemberId,
+	object_id: GameObjectId,
+	field_id: FieldId,
+	increment: f64,
+	bounds: Option<Bounds<f64>>,
+) -> Result<(), ServerCommandError> {
+	let action = |object: &mut GameObject| increment_field(object, field_id, increment, bounds).map(Some);
+
+	room.send_command_from_action(
+		object_id,
+		Field {
+			id: field_id,
+			field_type: FieldType::Double,
+		},
+		member_id,
+		Permission::Rw,
+		None,
+		action,
+	)
+}
+
+pub(crate) fn increment_field(
+	object: &mut GameObject,
+	field_id: FieldId,
+	increment: f64,
+	bounds: Option<Bounds<f64>>,
+) -> Result<S2CCommand, ServerCommandError> {
+	let object_id = object.id;
+	let value = object.get_field::<f64>(field_id).map_or(increment, |value| value + increment);
+	let value = match bounds {
+		None => value,
+		Some(bounds) => bounds
+			.apply(value)
+			.ok_or(ServerCommandError::IncrementOutOfBounds { object_id, field_id })?,
+	};
+	object.set_field(field_id, value)?;
+	Ok(S2CCommand::SetField(SetFieldCommand {
+		object_id,
+		field_id,
+		value: value.into(),
+	}))
+}
+
 #[cfg(test)]
 mod tests {
 	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::bounds::{Bounds, BoundsMode};
 	use cheetah_common::commands::types::field::SetFieldCommand;
-	use cheetah_common::commands::types::float::IncrementDoubleC2SCommand;
+	use cheetah_common::commands::types::float::{IncrementDoubleC2SCommand, IncrementDoubleWithBoundsC2SCommand};
 	use cheetah_common::room::owner::GameObjectOwner;
 
 	use crate::room::command::tests::setup_one_player;
-	use crate::room::command::ServerCommandExecutor;
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
 
 	#[test]
 	#[allow(clippy::cast_sign_loss)]
@@ -106,4 +133,53 @@ mod tests {
 		room.test_out_commands.pop_back();
 		assert!(matches!(room.test_out_commands.pop_back(), Some((.., S2CCommand::SetField(c))) if c==result));
 	}
+
+	#[test]
+	fn should_clamp_increment_with_bounds() {
+		let (mut room, member_id, access_groups) = setup_one_player();
+		let object = room.test_create_object_with_not_created_state(GameObjectOwner::Member(member_id), access_groups);
+		object.created = true;
+		let object_id = object.id;
+		let command = IncrementDoubleWithBoundsC2SCommand {
+			object_id,
+			field_id: 10,
+			increment: 0.7,
+			bounds: Bounds {
+				min: 0.0,
+				max: 1.0,
+				mode: BoundsMode::Clamp,
+			},
+		};
+		command.clone().execute(&mut room, member_id).unwrap();
+		command.execute(&mut room, member_id).unwrap();
+
+		let object = room.get_object_mut(object_id).unwrap();
+		assert!((*object.get_field::<f64>(10).unwrap() - 1.0).abs() < f64::EPSILON);
+	}
+
+	#[test]
+	fn should_reject_nan_increment_with_bounds() {
+		let (mut room, member_id, access_groups) = setup_one_player();
+		let object = room.test_create_object_with_not_created_state(GameObjectOwner::Member(member_id), access_groups);
+		object.created = true;
+		object.set_field(10, 0.5).unwrap();
+		let object_id = object.id;
+		let command = IncrementDoubleWithBoundsC2SCommand {
+			object_id,
+			field_id: 10,
+			increment: f64::NAN,
+			bounds: Bounds {
+				min: 0.0,
+				max: 1.0,
+				mode: BoundsMode::Clamp,
+			},
+		};
+		assert_eq!(
+			command.execute(&mut room, member_id),
+			Err(ServerCommandError::IncrementOutOfBounds { object_id, field_id: 10 })
+		);
+
+		let object = room.get_object_mut(object_id).unwrap();
+		assert!((*object.get_field::<f64>(10).unwrap() - 0.5).abs() < f64::EPSILON);
+	}
 }
@@ -0,0 +1,114 @@
+use std::slice;
+
+use cheetah_common::commands::field::Field;
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+use cheetah_common::commands::types::field_history::{FieldHistoryQueryCommand, FieldHistoryValueCommand};
+use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+use crate::room::Room;
+
+///
+/// Запросить значение поля из истории могут только супер-пользователи, ответ отправляется только запросившему
+///
+impl ServerCommandExecutor for FieldHistoryQueryCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		if !room.get_member(&member_id)?.template.super_member {
+			return Err(ServerCommandError::FieldHistoryPermissionDenied { member_id });
+		}
+		let field = Field {
+			id: self.field_id,
+			field_type: self.field_type,
+		};
+		let command = S2CCommandWithMeta {
+			field: None,
+			creator: member_id,
+			command: S2CCommand::FieldHistory(FieldHistoryValueCommand {
+				object_id: self.object_id,
+				field_id: self.field_id,
+				field_type: self.field_type,
+				time: self.time,
+				value: room.get_field_history_value(self.object_id, field, self.time),
+			}),
+		};
+		room.send_to_members(AccessGroups::super_group(), None, slice::from_ref(&command), |member| {
+			member.id == member_id
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use cheetah_common::commands::field::Field;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::field::SetFieldCommand;
+	use cheetah_common::commands::types::field_history::FieldHistoryQueryCommand;
+	use cheetah_common::commands::{FieldType, FieldValue};
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::owner::GameObjectOwner;
+
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::template::config::{FieldHistoryTemplate, MemberTemplate, RoomTemplate};
+	use crate::room::Room;
+
+	const POSITION: Field = Field {
+		id: 1,
+		field_type: FieldType::Double,
+	};
+
+	#[test]
+	fn should_send_field_history_value_to_super_member() {
+		let mut room = Room::from_template(RoomTemplate {
+			field_history: vec![FieldHistoryTemplate {
+				template: 0,
+				field: POSITION,
+				depth: Duration::from_secs(1),
+			}],
+			..RoomTemplate::default()
+		});
+		let super_member = room.register_member(MemberTemplate::new_super_member());
+		let object_id = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(1)).id;
+		SetFieldCommand {
+			object_id,
+			field_id: POSITION.id,
+			value: 1.5.into(),
+		}
+		.execute(&mut room, super_member)
+		.unwrap();
+		room.test_out_commands.clear();
+
+		let command = FieldHistoryQueryCommand {
+			object_id,
+			field_id: POSITION.id,
+			field_type: POSITION.field_type,
+			time: u64::MAX,
+		};
+		command.execute(&mut room, super_member).unwrap();
+
+		assert!(matches!(
+			room.test_out_commands.pop_back(),
+			Some((_, S2CCommand::FieldHistory(command))) if command.object_id == object_id && command.value == Some(FieldValue::Double(1.5))
+		));
+	}
+
+	#[test]
+	fn should_reject_field_history_query_from_member() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(1)));
+		let object_id = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(1)).id;
+		let command = FieldHistoryQueryCommand {
+			object_id,
+			field_id: POSITION.id,
+			field_type: POSITION.field_type,
+			time: 0,
+		};
+		assert_eq!(
+			command.execute(&mut room, member_id),
+			Err(ServerCommandError::FieldHistoryPermissionDenied { member_id })
+		);
+		assert!(room.test_out_commands.is_empty());
+	}
+}
@@ -0,0 +1,295 @@
+use cheetah_common::commands::field::{Field, FieldId};
+use cheetah_common::commands::s2c::S2CCommand;
+use cheetah_common::commands::types::list::{
+	ListClearCommand, ListIndex, ListPushCommand, ListRemoveCommand, ListRemoveValueCommand, ListSetItemCommand, MAX_LIST_ITEMS,
+};
+use cheetah_common::commands::FieldType;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+use crate::room::object::GameObject;
+use crate::room::template::config::Permission;
+use crate::room::Room;
+
+impl ServerCommandExecutor for ListPushCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let action = |object: &mut GameObject| {
+			let items = object.get_or_create_list_mut(self.field_id)?;
+			if items.len() >= MAX_LIST_ITEMS {
+				return Err(ServerCommandError::ListOverflow {
+					object_id: self.object_id,
+					field_id: self.field_id,
+				});
+			}
+			items.push(self.value);
+			Ok(Some(S2CCommand::ListPush(self.clone())))
+		};
+		send_list_command(room, self.object_id, self.field_id, member_id, action)
+	}
+}
+
+impl ServerCommandExecutor for ListRemoveCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let action = |object: &mut GameObject| {
+			match object.get_list_mut(self.field_id) {
+				Some(items) if usize::from(self.index) < items.len() => {
+					items.remove(usize::from(self.index));
+				}
+				_ => return Err(index_out_of_range(self.object_id, self.field_id, self.index)),
+			}
+			Ok(Some(S2CCommand::ListRemove(self.clone())))
+		};
+		send_list_command(room, self.object_id, self.field_id, member_id, action)
+	}
+}
+
+impl ServerCommandExecutor for ListRemoveValueCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let action = |object: &mut GameObject| {
+			let Some(items) = object.get_list_mut(self.field_id) else {
+				return Ok(None);
+			};
+			let Some(position) = items.iter().position(|item| *item == self.value) else {
+				return Ok(None);
+			};
+			items.remove(position);
+			Ok(Some(S2CCommand::ListRemove(ListRemoveCommand {
+				object_id: self.object_id,
+				field_id: self.field_id,
+				index: ListIndex::try_from(position).expect("list index overflow"),
+			})))
+		};
+		send_list_command(room, self.object_id, self.field_id, member_id, action)
+	}
+}
+
+impl ServerCommandExecutor for ListSetItemCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let action = |object: &mut GameObject| {
+			let item = object
+				.get_list_mut(self.field_id)
+				.and_then(|items| items.get_mut(usize::from(self.index)))
+				.ok_or_else(|| index_out_of_range(self.object_id, self.field_id, self.index))?;
+			*item = self.value;
+			Ok(Some(S2CCommand::ListSetItem(self.clone())))
+		};
+		send_list_command(room, self.object_id, self.field_id, member_id, action)
+	}
+}
+
+impl ServerCommandExecutor for ListClearCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let action = |object: &mut GameObject| {
+			object.get_or_create_list_mut(self.field_id)?.clear();
+			Ok(Some(S2CCommand::ListClear(self.clone())))
+		};
+		send_list_command(room, self.object_id, self.field_id, member_id, action)
+	}
+}
+
+fn send_list_command<T>(
+	room: &mut Room,
+	object_id: GameObjectId,
+	field_id: FieldId,
+	member_id: RoomMemberId,
+	action: T,
+) -> Result<(), ServerCommandError>
+where
+	T: FnOnce(&mut GameObject) -> Result<Option<S2CCommand>, ServerCommandError>,
+{
+	room.send_command_from_action(
+		object_id,
+		Field {
+			id: field_id,
+			field_type: FieldType::List,
+		},
+		member_id,
+		Permission::Rw,
+		None,
+		action,
+	)
+}
+
+fn index_out_of_range(object_id: GameObjectId, field_id: FieldId, index: ListIndex) -> ServerCommandError {
+	ServerCommandError::ListIndexOutOfRange { object_id, field_id, index }
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::binary_value::BinaryValue;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::list::{
+		ListClearCommand, ListPushCommand, ListRemoveCommand, ListRemoveValueCommand, ListSetItemCommand, MAX_LIST_ITEMS,
+	};
+	use cheetah_common::commands::FieldType;
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::object::GameObjectId;
+	use cheetah_common::room::owner::GameObjectOwner;
+	use cheetah_common::room::RoomMemberId;
+
+	use crate::room::command::tests::setup_one_player;
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::template::config::{MemberTemplate, Permission, RoomTemplate};
+	use crate::room::Room;
+
+	const FIELD_ID: u16 = 10;
+
+	#[test]
+	fn should_push_set_and_clear() {
+		let (mut room, member_id, object_id) = setup();
+		push(&mut room, member_id, object_id, 1);
+		push(&mut room, member_id, object_id, 2);
+		ListSetItemCommand {
+			object_id,
+			field_id: FIELD_ID,
+			index: 0,
+			value: value(3),
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+		assert_eq!(get_list(&room, object_id), vec![value(3), value(2)]);
+		assert!(matches!(room.test_out_commands.pop_front(), Some((.., S2CCommand::ListSetItem(c))) if c.index == 0 && c.value == value(3)));
+
+		ListClearCommand {
+			object_id,
+			field_id: FIELD_ID,
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+		assert!(get_list(&room, object_id).is_empty());
+	}
+
+	#[test]
+	fn should_remove_by_index_and_value() {
+		let (mut room, member_id, object_id) = setup();
+		for v in 1..=3 {
+			push(&mut room, member_id, object_id, v);
+		}
+		ListRemoveCommand {
+			object_id,
+			field_id: FIELD_ID,
+			index: 0,
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+		ListRemoveValueCommand {
+			object_id,
+			field_id: FIELD_ID,
+			value: value(3),
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+
+		assert_eq!(get_list(&room, object_id), vec![value(2)]);
+		// удаление по значению отправляется клиентам как удаление по индексу
+		assert!(matches!(room.test_out_commands.pop_front(), Some((.., S2CCommand::ListRemove(c))) if c.index == 1));
+	}
+
+	#[test]
+	fn should_not_send_when_value_not_found() {
+		let (mut room, member_id, object_id) = setup();
+		push(&mut room, member_id, object_id, 1);
+		room.test_out_commands.clear();
+		ListRemoveValueCommand {
+			object_id,
+			field_id: FIELD_ID,
+			value: value(2),
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+		assert!(room.test_out_commands.is_empty());
+	}
+
+	#[test]
+	fn should_reject_index_out_of_range() {
+		let (mut room, member_id, object_id) = setup();
+		push(&mut room, member_id, object_id, 1);
+		let result = ListRemoveCommand {
+			object_id,
+			field_id: FIELD_ID,
+			index: 1,
+		}
+		.execute(&mut room, member_id);
+		assert_eq!(
+			result,
+			Err(ServerCommandError::ListIndexOutOfRange {
+				object_id,
+				field_id: FIELD_ID,
+				index: 1
+			})
+		);
+	}
+
+	#[test]
+	fn should_reject_list_overflow() {
+		let (mut room, member_id, object_id) = setup();
+		for _ in 0..MAX_LIST_ITEMS {
+			push(&mut room, member_id, object_id, 1);
+		}
+		let result = ListPushCommand {
+			object_id,
+			field_id: FIELD_ID,
+			value: value(1),
+		}
+		.execute(&mut room, member_id);
+		assert_eq!(
+			result,
+			Err(ServerCommandError::ListOverflow {
+				object_id,
+				field_id: FIELD_ID
+			})
+		);
+	}
+
+	#[test]
+	fn should_check_list_field_permission() {
+		let groups = AccessGroups(0b11);
+		let mut template = RoomTemplate::default();
+		template
+			.permissions
+			.set_permission(0, &FIELD_ID, FieldType::List, &groups, Permission::Ro);
+		let mut room = Room::from_template(template);
+		let owner = room.register_member(MemberTemplate::stub(groups));
+		let member_id = room.register_member(MemberTemplate::stub(groups));
+		let object_id = room.test_create_object_with_created_state(GameObjectOwner::Member(owner), groups).id;
+
+		let result = ListPushCommand {
+			object_id,
+			field_id: FIELD_ID,
+			value: value(1),
+		}
+		.execute(&mut room, member_id);
+		assert!(matches!(result, Err(ServerCommandError::MemberCannotAccessToObjectField { .. })));
+	}
+
+	fn setup() -> (Room, RoomMemberId, GameObjectId) {
+		let (mut room, member_id, access_groups) = setup_one_player();
+		let object = room.test_create_object_with_not_created_state(GameObjectOwner::Member(member_id), access_groups);
+		object.created = true;
+		let object_id = object.id;
+		(room, member_id, object_id)
+	}
+
+	fn push(room: &mut Room, member_id: RoomMemberId, object_id: GameObjectId, v: u8) {
+		ListPushCommand {
+			object_id,
+			field_id: FIELD_ID,
+			value: value(v),
+		}
+		.execute(room, member_id)
+		.unwrap();
+	}
+
+	fn get_list(room: &Room, object_id: GameObjectId) -> Vec<BinaryValue> {
+		room.get_object(object_id)
+			.unwrap()
+			.get_field::<Vec<BinaryValue>>(FIELD_ID)
+			.unwrap()
+			.clone()
+	}
+
+	fn value(v: u8) -> BinaryValue {
+		BinaryValue::from([v].as_slice())
+	}
+}
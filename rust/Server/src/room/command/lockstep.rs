@@ -0,0 +1,106 @@
+use cheetah_common::commands::types::lockstep::{LockstepHistoryCommand, LockstepInputCommand};
+use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+use crate::room::lockstep::{bundle_to_commands, LOCKSTEP_CHANNEL};
+use crate::room::Room;
+
+impl ServerCommandExecutor for LockstepInputCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let room_id = room.id;
+		room.lockstep
+			.as_mut()
+			.ok_or(ServerCommandError::LockstepNotEnabled { room_id })?
+			.add_input(member_id, self)
+	}
+}
+
+impl ServerCommandExecutor for LockstepHistoryCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let room_id = room.id;
+		let commands: Vec<_> = room
+			.lockstep
+			.as_ref()
+			.ok_or(ServerCommandError::LockstepNotEnabled { room_id })?
+			.get_history(self.from_tick)
+			.flat_map(|bundle| bundle_to_commands(&bundle))
+			.collect();
+		room.send_to_members_with_channel(LOCKSTEP_CHANNEL, AccessGroups::super_group(), &commands, |member| member.id == member_id)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{Duration, Instant};
+
+	use cheetah_common::commands::binary_value::BinaryValue;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::lockstep::{LockstepHistoryCommand, LockstepInputCommand};
+	use cheetah_common::room::access::AccessGroups;
+
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::template::config::{MemberTemplate, RoomTemplate};
+	use crate::room::Room;
+
+	#[test]
+	fn should_send_tick_inputs_to_all_members() {
+		let mut room = setup();
+		let member_1 = room.register_member(MemberTemplate::stub(AccessGroups(0b1)));
+		let member_2 = room.register_member(MemberTemplate::stub(AccessGroups(0b1)));
+		room.test_mark_as_connected(member_1).unwrap();
+		room.test_mark_as_connected(member_2).unwrap();
+
+		input(0, 2).execute(&mut room, member_2).unwrap();
+		input(0, 1).execute(&mut room, member_1).unwrap();
+		room.release_lockstep_ticks(Instant::now());
+
+		let commands = room.test_get_member_out_commands(member_2);
+		assert!(matches!(commands[0], S2CCommand::LockstepInput(ref c) if c.member_id == member_1 && c.tick == 0));
+		assert!(matches!(commands[1], S2CCommand::LockstepInput(ref c) if c.member_id == member_2 && c.tick == 0));
+		assert!(matches!(commands[2], S2CCommand::LockstepTick(ref c) if c.tick == 0 && c.inputs == 2));
+	}
+
+	#[test]
+	fn should_send_history_to_member() {
+		let mut room = setup();
+		let member_1 = room.register_member(MemberTemplate::stub(AccessGroups(0b1)));
+		room.test_mark_as_connected(member_1).unwrap();
+		input(0, 1).execute(&mut room, member_1).unwrap();
+		room.release_lockstep_ticks(Instant::now());
+
+		let member_2 = room.register_member(MemberTemplate::stub(AccessGroups(0b1)));
+		room.test_mark_as_connected(member_2).unwrap();
+		room.test_clear_member_out_commands(member_1);
+		LockstepHistoryCommand { from_tick: 0 }.execute(&mut room, member_2).unwrap();
+
+		assert!(room.test_get_member_out_commands(member_1).is_empty());
+		let commands = room.test_get_member_out_commands(member_2);
+		assert!(matches!(commands[0], S2CCommand::LockstepInput(ref c) if c.member_id == member_1 && c.tick == 0));
+		assert!(matches!(commands[1], S2CCommand::LockstepTick(ref c) if c.tick == 0 && c.inputs == 1));
+	}
+
+	#[test]
+	fn should_reject_input_when_lockstep_disabled() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(0b1)));
+		assert_eq!(
+			input(0, 1).execute(&mut room, member_id),
+			Err(ServerCommandError::LockstepNotEnabled { room_id: room.id })
+		);
+	}
+
+	fn setup() -> Room {
+		Room::from_template(RoomTemplate {
+			lockstep_deadline: Some(Duration::from_millis(100)),
+			..RoomTemplate::default()
+		})
+	}
+
+	fn input(tick: u64, value: u8) -> LockstepInputCommand {
+		LockstepInputCommand {
+			tick,
+			input: BinaryValue::from([value].as_slice()),
+		}
+	}
+}
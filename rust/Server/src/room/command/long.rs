@@ -1,8 +1,10 @@
-use cheetah_common::commands::field::Field;
+use cheetah_common::commands::field::{Field, FieldId};
 use cheetah_common::commands::s2c::S2CCommand;
+use cheetah_common::commands::types::bounds::Bounds;
 use cheetah_common::commands::types::field::SetFieldCommand;
-use cheetah_common::commands::types::long::IncrementLongC2SCommand;
-use cheetah_common::commands::FieldType;
+use cheetah_common::commands::types::long::{IncrementLongC2SCommand, IncrementLongWithBoundsC2SCommand};
+use cheetah_common::commands::{FieldType, FieldValue};
+use cheetah_common::room::object::GameObjectId;
 use cheetah_common::room::RoomMemberId;
 
 use crate::room::command::{ServerCommandError, ServerCommandExecutor};
@@ -12,40 +14,96 @@ use crate::room::Room;
 
 impl ServerCommandExecutor for IncrementLongC2SCommand {
 	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
-		let action = |object: &mut GameObject| {
-			let value = if let Some(value) = object.get_field::<i64>(self.field_id) {
-				match (*value).checked_add(self.increment) {
-					None => {
-						tracing::error!("[IncrementLongC2SCommand] overflow, current({:?}) increment({:?})", value, self.increment);
-						*value
-					}
-					Some(result) => {
-						object.set_field(self.field_id, result)?;
-						result
-					}
-				}
-			} else {
-				object.set_field(self.field_id, self.increment)?;
-				self.increment
-			};
-
-			Ok(Some(S2CCommand::SetField(SetFieldCommand {
-				object_id: self.object_id,
-				field_id: self.field_id,
-				value: value.into(),
-			})))
-		};
-		room.send_command_from_action(
-			self.object_id,
-			Field {
-				id: self.field_id,
-				field_type: FieldType::Long,
-			},
-			member_id,
-			Permission::Rw,
-			None,
-			action,
-		)
+		let bounds = room.get_long_bounds(self.object_id, self.field_id);
+		increment(room, member_id, self.object_id, self.field_id, self.increment, bounds)
+	}
+}
+
+impl ServerCommandExecutor for IncrementLongWithBoundsC2SCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let bounds = room.get_long_bounds_with(self.object_id, self.field_id, self.bounds)?;
+		increment(room, member_id, self.object_id, self.field_id, self.increment, Some(bounds))
+	}
+}
+
+fn increment(
+	room: &mut Room,
+	member_id: RoomMemberId,
+	object_id: GameObjectId,
+	field_id: FieldId,
+	increment: i64,
+	bounds: Option<Bounds<i64>>,
+) -> Result<(), ServerCommandError> {
+	let action = |object: &mut GameObject| increment_field(object, field_id, increment, bounds).map(Some);
+	room.send_command_from_action(
+		object_id,
+		Field {
+			id: field_id,
+			field_type: FieldType::Long,
+		},
+		member_id,
+		Permission::Rw,
+		None,
+		action,
+	)
+}
+
+///
+/// Инкремент с учетом границ, при наличии границ результат при переполнении ограничивается диапазоном i64
+///
+pub(crate) fn increment_field(
+	object: &mut GameObject,
+	field_id: FieldId,
+	increment: i64,
+	bounds: Option<Bounds<i64>>,
+) -> Result<S2CCommand, ServerCommandError> {
+	let object_id = object.id;
+	let current = object.get_field::<i64>(field_id).copied();
+	let value = match (current, bounds) {
+		(current, Some(bounds)) => {
+			let value = bounds
+				.apply(current.unwrap_or_default().saturating_add(increment))
+				.ok_or(ServerCommandError::IncrementOutOfBounds { object_id, field_id })?;
+			object.set_field(field_id, value)?;
+			value
+		}
+		(Some(value), None) => match value.checked_add(increment) {
+			None => {
+				tracing::error!("[IncrementLongC2SCommand] overflow, current({:?}) increment({:?})", value, increment);
+				value
+			}
+			Some(result) => {
+				object.set_field(field_id, result)?;
+				result
+			}
+		},
+		(None, None) => {
+			object.set_field(field_id, increment)?;
+			increment
+		}
+	};
+
+	Ok(S2CCommand::SetField(SetFieldCommand {
+		object_id,
+		field_id,
+		value: value.into(),
+	}))
+}
+
+///
+/// Проверка значения от клиента, списки и словари изменяются только поэлементно -
+/// целиком они могут не поместиться во фрейм при рассылке
+///
+pub(crate) fn check_set_field(room: &Room, command: &SetFieldCommand) -> Result<(), ServerCommandError> {
+	match &command.value {
+		FieldValue::String(value) => room.check_string_length(command.object_id, command.field_id, value),
+		FieldValue::List(_) | FieldValue::Map(_) => Err(ServerCommandError::Error(format!(
+			"Set whole {:?} value for field {} in object {:?} not supported",
+			command.value.field_type(),
+			command.field_id,
+			command.object_id
+		))),
+		FieldValue::Long(_) | FieldValue::Double(_) | FieldValue::Structure(_) => Ok(()),
 	}
 }
 
@@ -53,6 +111,7 @@ impl ServerCommandExecutor for SetFieldCommand {
 	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
 		let field_id = self.field_id;
 		let object_id = self.object_id;
+		check_set_field(room, self)?;
 
 		let action = |object: &mut GameObject| {
 			object.set_field_wrapped(self.field_id, self.value.clone())?;
@@ -76,16 +135,19 @@ impl ServerCommandExecutor for SetFieldCommand {
 #[cfg(test)]
 mod tests {
 	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::bounds::{Bounds, BoundsMode};
 	use cheetah_common::commands::types::field::SetFieldCommand;
-	use cheetah_common::commands::types::long::IncrementLongC2SCommand;
+	use cheetah_common::commands::types::long::{IncrementLongC2SCommand, IncrementLongWithBoundsC2SCommand};
+	use cheetah_common::commands::{FieldType, FieldValue};
 	use cheetah_common::room::access::AccessGroups;
 	use cheetah_common::room::object::GameObjectId;
 	use cheetah_common::room::owner::GameObjectOwner;
 	use cheetah_common::room::RoomMemberId;
 
-	use crate::room::command::ServerCommandExecutor;
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
 	use crate::room::template::config::MemberTemplate;
 	use crate::room::template::config::RoomTemplate;
+	use crate::room::template::config::{FieldBounds, FieldBoundsTemplate};
 	use crate::room::Room;
 
 	#[test]
@@ -144,8 +206,116 @@ mod tests {
 		command.execute(&mut room, member_id).unwrap();
 	}
 
+	#[test]
+	fn should_clamp_increment_by_template_bounds() {
+		let (mut room, member_id, object_id) = setup_with_template(RoomTemplate {
+			field_bounds: vec![FieldBoundsTemplate {
+				template: 0,
+				field_id: 10,
+				bounds: FieldBounds::Long(Bounds {
+					min: 0,
+					max: 100,
+					mode: BoundsMode::Clamp,
+				}),
+			}],
+			..RoomTemplate::default()
+		});
+		let command = IncrementLongC2SCommand {
+			object_id,
+			field_id: 10,
+			increment: 70,
+		};
+		command.clone().execute(&mut room, member_id).unwrap();
+		command.execute(&mut room, member_id).unwrap();
+		assert_eq!(*room.get_object_mut(object_id).unwrap().get_field::<i64>(10).unwrap(), 100);
+
+		IncrementLongC2SCommand {
+			object_id,
+			field_id: 10,
+			increment: -200,
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+		assert_eq!(*room.get_object_mut(object_id).unwrap().get_field::<i64>(10).unwrap(), 0);
+	}
+
+	#[test]
+	fn should_reject_increment_out_of_bounds() {
+		let (mut room, member_id, object_id) = setup();
+		let command = IncrementLongWithBoundsC2SCommand {
+			object_id,
+			field_id: 10,
+			increment: 60,
+			bounds: Bounds {
+				min: 0,
+				max: 100,
+				mode: BoundsMode::Reject,
+			},
+		};
+		command.clone().execute(&mut room, member_id).unwrap();
+		room.test_out_commands.clear();
+		assert_eq!(
+			command.execute(&mut room, member_id),
+			Err(ServerCommandError::IncrementOutOfBounds { object_id, field_id: 10 })
+		);
+		assert_eq!(*room.get_object_mut(object_id).unwrap().get_field::<i64>(10).unwrap(), 60);
+		assert!(room.test_out_commands.is_empty());
+	}
+
+	///
+	/// Границы клиента шире границ шаблона - применяются границы шаблона и режим отклонения из шаблона
+	///
+	#[test]
+	fn should_not_extend_template_bounds() {
+		let (mut room, member_id, object_id) = setup_with_template(RoomTemplate {
+			field_bounds: vec![FieldBoundsTemplate {
+				template: 0,
+				field_id: 10,
+				bounds: FieldBounds::Long(Bounds {
+					min: 0,
+					max: 100,
+					mode: BoundsMode::Reject,
+				}),
+			}],
+			..RoomTemplate::default()
+		});
+		let command = IncrementLongWithBoundsC2SCommand {
+			object_id,
+			field_id: 10,
+			increment: 60,
+			bounds: Bounds {
+				min: -1000,
+				max: 1000,
+				mode: BoundsMode::Clamp,
+			},
+		};
+		command.clone().execute(&mut room, member_id).unwrap();
+		assert_eq!(
+			command.execute(&mut room, member_id),
+			Err(ServerCommandError::IncrementOutOfBounds { object_id, field_id: 10 })
+		);
+		assert_eq!(*room.get_object_mut(object_id).unwrap().get_field::<i64>(10).unwrap(), 60);
+	}
+
+	#[test]
+	fn should_reject_set_whole_list() {
+		let (mut room, member_id, object_id) = setup();
+		room.test_out_commands.clear();
+		let command = SetFieldCommand {
+			object_id,
+			field_id: 10,
+			value: FieldValue::List(vec![[1, 2, 3].as_slice().into()]),
+		};
+		assert!(matches!(command.execute(&mut room, member_id), Err(ServerCommandError::Error(_))));
+		assert!(room.get_object_mut(object_id).unwrap().get_field_wrapped(10, FieldType::List).is_none());
+		assert!(room.test_out_commands.is_empty());
+	}
+
 	fn setup() -> (Room, RoomMemberId, GameObjectId) {
-		let template = RoomTemplate::default();
+		setup_with_template(RoomTemplate::default())
+	}
+
+	fn setup_with_template(template: RoomTemplate) -> (Room, RoomMemberId, GameObjectId) {
 		let access_groups = AccessGroups(10);
 		let mut room = Room::from_template(template);
 		let member_id = room.register_member(MemberTemplate::stub(access_groups));
@@ -0,0 +1,226 @@
+use cheetah_common::commands::field::{Field, FieldId};
+use cheetah_common::commands::s2c::S2CCommand;
+use cheetah_common::commands::types::map::{MapDeleteCommand, MapIncrementCommand, MapSetCommand, MapValue, MAX_MAP_ITEMS};
+use cheetah_common::commands::FieldType;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+use crate::room::object::GameObject;
+use crate::room::template::config::Permission;
+use crate::room::Room;
+
+impl ServerCommandExecutor for MapSetCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let action = |object: &mut GameObject| {
+			let items = object.get_or_create_map_mut(self.field_id)?;
+			if items.len() >= MAX_MAP_ITEMS && !items.contains_key(&self.key) {
+				return Err(ServerCommandError::MapOverflow {
+					object_id: self.object_id,
+					field_id: self.field_id,
+				});
+			}
+			items.insert(self.key, self.value.clone());
+			Ok(Some(S2CCommand::MapSet(self.clone())))
+		};
+		send_map_command(room, self.object_id, self.field_id, member_id, action)
+	}
+}
+
+impl ServerCommandExecutor for MapDeleteCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let action = |object: &mut GameObject| {
+			let removed = object.get_map_mut(self.field_id).and_then(|items| items.remove(&self.key));
+			Ok(removed.map(|_| S2CCommand::MapDelete(self.clone())))
+		};
+		send_map_command(room, self.object_id, self.field_id, member_id, action)
+	}
+}
+
+impl ServerCommandExecutor for MapIncrementCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let action = |object: &mut GameObject| {
+			let items = object.get_or_create_map_mut(self.field_id)?;
+			let value = match (items.get(&self.key), &self.increment) {
+				(None, MapValue::Long(_) | MapValue::Double(_)) => {
+					if items.len() >= MAX_MAP_ITEMS {
+						return Err(ServerCommandError::MapOverflow {
+							object_id: self.object_id,
+							field_id: self.field_id,
+						});
+					}
+					self.increment.clone()
+				}
+				(Some(MapValue::Long(value)), MapValue::Long(increment)) => match value.checked_add(*increment) {
+					None => {
+						tracing::error!("[MapIncrementCommand] overflow, current({:?}) increment({:?})", value, increment);
+						MapValue::Long(*value)
+					}
+					Some(result) => MapValue::Long(result),
+				},
+				(Some(MapValue::Double(value)), MapValue::Double(increment)) => MapValue::Double(value + increment),
+				_ => {
+					return Err(ServerCommandError::MapValueTypeMismatch {
+						object_id: self.object_id,
+						field_id: self.field_id,
+						key: self.key,
+					})
+				}
+			};
+			items.insert(self.key, value.clone());
+			Ok(Some(S2CCommand::MapSet(MapSetCommand {
+				object_id: self.object_id,
+				field_id: self.field_id,
+				key: self.key,
+				value,
+			})))
+		};
+		send_map_command(room, self.object_id, self.field_id, member_id, action)
+	}
+}
+
+fn send_map_command<T>(
+	room: &mut Room,
+	object_id: GameObjectId,
+	field_id: FieldId,
+	member_id: RoomMemberId,
+	action: T,
+) -> Result<(), ServerCommandError>
+where
+	T: FnOnce(&mut GameObject) -> Result<Option<S2CCommand>, ServerCommandError>,
+{
+	room.send_command_from_action(
+		object_id,
+		Field {
+			id: field_id,
+			field_type: FieldType::Map,
+		},
+		member_id,
+		Permission::Rw,
+		None,
+		action,
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::binary_value::BinaryValue;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::map::{MapDeleteCommand, MapIncrementCommand, MapItems, MapKey, MapSetCommand, MapValue, MAX_MAP_ITEMS};
+	use cheetah_common::room::object::GameObjectId;
+	use cheetah_common::room::owner::GameObjectOwner;
+	use cheetah_common::room::RoomMemberId;
+
+	use crate::room::command::tests::setup_one_player;
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::Room;
+
+	const FIELD_ID: u16 = 10;
+
+	#[test]
+	fn should_set_and_delete_key() {
+		let (mut room, member_id, object_id) = setup();
+		set(&mut room, member_id, object_id, 1, MapValue::Long(100)).unwrap();
+		set(&mut room, member_id, object_id, 2, MapValue::Binary(BinaryValue::from([1, 2].as_ref()))).unwrap();
+		assert!(matches!(room.test_out_commands.pop_front(), Some((.., S2CCommand::MapSet(c))) if c.key == 2));
+
+		MapDeleteCommand {
+			object_id,
+			field_id: FIELD_ID,
+			key: 1,
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+		assert!(matches!(room.test_out_commands.pop_front(), Some((.., S2CCommand::MapDelete(c))) if c.key == 1));
+		assert_eq!(
+			get_map(&room, object_id),
+			MapItems::from([(2, MapValue::Binary(BinaryValue::from([1, 2].as_ref())))])
+		);
+	}
+
+	#[test]
+	fn should_not_send_delete_for_missing_key() {
+		let (mut room, member_id, object_id) = setup();
+		MapDeleteCommand {
+			object_id,
+			field_id: FIELD_ID,
+			key: 1,
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+		assert!(room.test_out_commands.is_empty());
+	}
+
+	#[test]
+	fn should_increment_key() {
+		let (mut room, member_id, object_id) = setup();
+		increment(&mut room, member_id, object_id, MapValue::Long(10)).unwrap();
+		increment(&mut room, member_id, object_id, MapValue::Long(5)).unwrap();
+		assert_eq!(get_map(&room, object_id), MapItems::from([(1, MapValue::Long(15))]));
+		// клиентам отправляется итоговое значение
+		assert!(matches!(room.test_out_commands.pop_front(), Some((.., S2CCommand::MapSet(c))) if c.value == MapValue::Long(15)));
+	}
+
+	#[test]
+	fn should_reject_increment_with_other_type() {
+		let (mut room, member_id, object_id) = setup();
+		increment(&mut room, member_id, object_id, MapValue::Long(10)).unwrap();
+		assert_eq!(
+			increment(&mut room, member_id, object_id, MapValue::Double(1.0)),
+			Err(ServerCommandError::MapValueTypeMismatch {
+				object_id,
+				field_id: FIELD_ID,
+				key: 1
+			})
+		);
+	}
+
+	#[test]
+	fn should_reject_map_overflow() {
+		let (mut room, member_id, object_id) = setup();
+		for key in 0..MAX_MAP_ITEMS as MapKey {
+			set(&mut room, member_id, object_id, key, MapValue::Long(1)).unwrap();
+		}
+		// существующий ключ можно изменить
+		set(&mut room, member_id, object_id, 0, MapValue::Long(2)).unwrap();
+		assert_eq!(
+			set(&mut room, member_id, object_id, MAX_MAP_ITEMS as MapKey, MapValue::Long(1)),
+			Err(ServerCommandError::MapOverflow {
+				object_id,
+				field_id: FIELD_ID
+			})
+		);
+	}
+
+	fn setup() -> (Room, RoomMemberId, GameObjectId) {
+		let (mut room, member_id, access_groups) = setup_one_player();
+		let object = room.test_create_object_with_not_created_state(GameObjectOwner::Member(member_id), access_groups);
+		object.created = true;
+		let object_id = object.id;
+		(room, member_id, object_id)
+	}
+
+	fn set(room: &mut Room, member_id: RoomMemberId, object_id: GameObjectId, key: MapKey, value: MapValue) -> Result<(), ServerCommandError> {
+		MapSetCommand {
+			object_id,
+			field_id: FIELD_ID,
+			key,
+			value,
+		}
+		.execute(room, member_id)
+	}
+
+	fn increment(room: &mut Room, member_id: RoomMemberId, object_id: GameObjectId, increment: MapValue) -> Result<(), ServerCommandError> {
+		MapIncrementCommand {
+			object_id,
+			field_id: FIELD_ID,
+			key: 1,
+			increment,
+		}
+		.execute(room, member_id)
+	}
+
+	fn get_map(room: &Room, object_id: GameObjectId) -> MapItems {
+		room.get_object(object_id).unwrap().get_field::<MapItems>(FIELD_ID).unwrap().clone()
+	}
+}
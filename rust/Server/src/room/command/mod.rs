@@ -1,7 +1,13 @@
 use thiserror::Error;
 
 use cheetah_common::commands::c2s::C2SCommand;
-use cheetah_common::commands::field::Field;
+use cheetah_common::commands::field::{Field, FieldId};
+use cheetah_common::commands::types::list::ListIndex;
+use cheetah_common::commands::types::lockstep::LockstepTickId;
+use cheetah_common::commands::types::map::MapKey;
+use cheetah_common::commands::types::rejected::CommandRejectReason;
+use cheetah_common::commands::types::room_event::RoomEventId;
+use cheetah_common::commands::types::rpc::RpcCallId;
 use cheetah_common::constants::GameObjectTemplateId;
 use cheetah_common::room::access::AccessGroups;
 use cheetah_common::room::object::GameObjectId;
@@ -11,6 +17,8 @@ use crate::room::object::GameObjectError;
 use crate::room::Room;
 use crate::server::rooms::RoomNotFoundError;
 
+pub mod access_groups;
+pub mod bounds;
 pub mod compare_and_set;
 pub mod create;
 pub mod created;
@@ -18,10 +26,22 @@ pub mod delete;
 pub mod double;
 pub mod event;
 pub mod field;
+pub mod field_history;
 pub mod forwarded;
+pub mod list;
+pub mod lockstep;
 pub mod long;
+pub mod map;
+pub mod ownership;
 pub mod room;
+pub mod room_event;
+pub mod rpc;
+pub mod string;
 pub mod structure;
+pub mod subscription;
+pub mod timer;
+pub mod transaction;
+pub mod ttl;
 
 ///
 /// Выполнение серверной команды
@@ -80,6 +100,70 @@ pub enum ServerCommandError {
 		sender_member_id: RoomMemberId,
 		creator_member_id: RoomMemberId,
 	},
+
+	#[error("CompareAndSetCleaners overflow for field {field_id} in object {object_id:?}")]
+	CompareAndSetCleanersOverflow { object_id: GameObjectId, field_id: FieldId },
+
+	#[error("Lockstep mode is not enabled in room {room_id:?}")]
+	LockstepNotEnabled { room_id: RoomId },
+
+	#[error("Lockstep tick {tick} out of range, current tick {current_tick}")]
+	LockstepTickOutOfRange { tick: LockstepTickId, current_tick: LockstepTickId },
+
+	#[error("List index {index} out of range for field {field_id} in object {object_id:?}")]
+	ListIndexOutOfRange {
+		object_id: GameObjectId,
+		field_id: FieldId,
+		index: ListIndex,
+	},
+
+	#[error("List overflow for field {field_id} in object {object_id:?}")]
+	ListOverflow { object_id: GameObjectId, field_id: FieldId },
+
+	#[error("Map overflow for field {field_id} in object {object_id:?}")]
+	MapOverflow { object_id: GameObjectId, field_id: FieldId },
+
+	#[error("Map value type mismatch for key {key} in field {field_id} in object {object_id:?}")]
+	MapValueTypeMismatch {
+		object_id: GameObjectId,
+		field_id: FieldId,
+		key: MapKey,
+	},
+
+	#[error("String length {length} exceeds limit {max_length} for field {field_id} in object {object_id:?}")]
+	StringTooLong {
+		object_id: GameObjectId,
+		field_id: FieldId,
+		length: usize,
+		max_length: usize,
+	},
+
+	#[error("Increment result out of bounds for field {field_id} in object {object_id:?}")]
+	IncrementOutOfBounds { object_id: GameObjectId, field_id: FieldId },
+
+	#[error("Transaction precondition {index} failed")]
+	TransactionPreconditionFailed { index: usize },
+
+	#[error("Timers overflow for member {member_id}")]
+	TimersOverflow { member_id: RoomMemberId },
+
+	#[error("Member {member_id} cannot create room timer")]
+	TimerPermissionDenied { member_id: RoomMemberId },
+
+	#[error("Rpc calls overflow for member {member_id}")]
+	RpcCallsOverflow { member_id: RoomMemberId },
+
+	#[error("Rpc call {call_id} already exists for member {member_id}")]
+	RpcCallAlreadyExists { member_id: RoomMemberId, call_id: RpcCallId },
+
+	#[error("Member {member_id} cannot send room event {event_id}")]
+	RoomEventPermissionDenied { member_id: RoomMemberId, event_id: RoomEventId },
+
+	#[error("Field {field_id} validation failed in object {object_id:?}")]
+	FieldValidationFailed { object_id: GameObjectId, field_id: FieldId },
+
+	#[error("Member {member_id} cannot query field history")]
+	FieldHistoryPermissionDenied { member_id: RoomMemberId },
 }
 
 impl ServerCommandError {
@@ -96,6 +180,39 @@ impl ServerCommandError {
 	pub fn log_error(&self, room_id: RoomId, room_member_id: RoomMemberId) {
 		tracing::error!("Error in room {:?} for client {:?} : {:?}", room_id, room_member_id, self);
 	}
+
+	///
+	/// Причина отказа для отправки пользователю
+	///
+	#[must_use]
+	pub fn get_reject_reason(&self) -> CommandRejectReason {
+		match self {
+			ServerCommandError::Error(_) | ServerCommandError::RoomNotFound(_) | ServerCommandError::MemberNotFound(_) => CommandRejectReason::Other,
+			ServerCommandError::GameObjectError(GameObjectError::FieldCountOverflow(..)) => CommandRejectReason::FieldCountOverflow,
+			ServerCommandError::MemberNotOwnerGameObject { .. } => CommandRejectReason::MemberNotOwnerGameObject,
+			ServerCommandError::MemberCannotAccessToObject { .. } => CommandRejectReason::MemberCannotAccessToObject,
+			ServerCommandError::MemberCannotAccessToObjectField { .. } => CommandRejectReason::MemberCannotAccessToObjectField,
+			ServerCommandError::GameObjectNotFound { .. } => CommandRejectReason::GameObjectNotFound,
+			ServerCommandError::ForwardedCommandPermissionDenied { .. } => CommandRejectReason::ForwardedCommandPermissionDenied,
+			ServerCommandError::CompareAndSetCleanersOverflow { .. } => CommandRejectReason::CompareAndSetOverflow,
+			ServerCommandError::LockstepNotEnabled { .. } => CommandRejectReason::LockstepNotEnabled,
+			ServerCommandError::LockstepTickOutOfRange { .. } => CommandRejectReason::LockstepTickOutOfRange,
+			ServerCommandError::ListIndexOutOfRange { .. } => CommandRejectReason::ListIndexOutOfRange,
+			ServerCommandError::ListOverflow { .. } => CommandRejectReason::ListOverflow,
+			ServerCommandError::MapOverflow { .. } => CommandRejectReason::MapOverflow,
+			ServerCommandError::MapValueTypeMismatch { .. } => CommandRejectReason::MapValueTypeMismatch,
+			ServerCommandError::StringTooLong { .. } => CommandRejectReason::StringTooLong,
+			ServerCommandError::IncrementOutOfBounds { .. } => CommandRejectReason::IncrementOutOfBounds,
+			ServerCommandError::TransactionPreconditionFailed { .. } => CommandRejectReason::TransactionPreconditionFailed,
+			ServerCommandError::TimersOverflow { .. } => CommandRejectReason::TimersOverflow,
+			ServerCommandError::TimerPermissionDenied { .. } => CommandRejectReason::TimerPermissionDenied,
+			ServerCommandError::RpcCallsOverflow { .. } => CommandRejectReason::RpcCallsOverflow,
+			ServerCommandError::RpcCallAlreadyExists { .. } => CommandRejectReason::RpcCallAlreadyExists,
+			ServerCommandError::RoomEventPermissionDenied { .. } => CommandRejectReason::RoomEventPermissionDenied,
+			ServerCommandError::FieldValidationFailed { .. } => CommandRejectReason::FieldValidationFailed,
+			ServerCommandError::FieldHistoryPermissionDenied { .. } => CommandRejectReason::FieldHistoryPermissionDenied,
+		}
+	}
 }
 
 pub fn execute(command: &C2SCommand, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
@@ -104,7 +221,10 @@ pub fn execute(command: &C2SCommand, room: &mut Room, member_id: RoomMemberId) -
 		C2SCommand::SetField(command) => command.execute(room, member_id),
 		C2SCommand::IncrementLongValue(command) => command.execute(room, member_id),
 		C2SCommand::CompareAndSetLong(command) => command.execute(room, member_id),
+		C2SCommand::CompareAndSetDouble(command) => command.execute(room, member_id),
 		C2SCommand::IncrementDouble(command) => command.execute(room, member_id),
+		C2SCommand::IncrementLongWithBounds(command) => command.execute(room, member_id),
+		C2SCommand::IncrementDoubleWithBounds(command) => command.execute(room, member_id),
 		C2SCommand::CompareAndSetStructure(command) => command.execute(room, member_id),
 		C2SCommand::Event(command) => command.execute(room, member_id),
 		C2SCommand::Delete(command) => command.execute(room, member_id),
@@ -114,6 +234,28 @@ pub fn execute(command: &C2SCommand, room: &mut Room, member_id: RoomMemberId) -
 		C2SCommand::TargetEvent(command) => command.execute(room, member_id),
 		C2SCommand::DeleteField(command) => command.execute(room, member_id),
 		C2SCommand::Forwarded(command) => command.execute(room, member_id),
+		C2SCommand::LockstepInput(command) => command.execute(room, member_id),
+		C2SCommand::LockstepHistory(command) => command.execute(room, member_id),
+		C2SCommand::ListPush(command) => command.execute(room, member_id),
+		C2SCommand::ListRemove(command) => command.execute(room, member_id),
+		C2SCommand::ListRemoveValue(command) => command.execute(room, member_id),
+		C2SCommand::ListSetItem(command) => command.execute(room, member_id),
+		C2SCommand::ListClear(command) => command.execute(room, member_id),
+		C2SCommand::MapSet(command) => command.execute(room, member_id),
+		C2SCommand::MapDelete(command) => command.execute(room, member_id),
+		C2SCommand::MapIncrement(command) => command.execute(room, member_id),
+		C2SCommand::Transaction(command) => command.execute(room, member_id),
+		C2SCommand::ScheduleTimer(command) => command.execute(room, member_id),
+		C2SCommand::CancelTimer(command) => command.execute(room, member_id),
+		C2SCommand::SetObjectTtl(command) => command.execute(room, member_id),
+		C2SCommand::TransferOwnership(command) => command.execute(room, member_id),
+		C2SCommand::SetObjectAccessGroups(command) => command.execute(room, member_id),
+		C2SCommand::Subscribe(command) => room.update_subscription(member_id, |subscription| subscription.subscribe(command)),
+		C2SCommand::Unsubscribe(command) => room.update_subscription(member_id, |subscription| subscription.unsubscribe(command)),
+		C2SCommand::RpcRequest(command) => command.execute(room, member_id),
+		C2SCommand::RpcResponse(command) => command.execute(room, member_id),
+		C2SCommand::RoomEvent(command) => command.execute(room, member_id),
+		C2SCommand::FieldHistory(command) => command.execute(room, member_id),
 	}
 }
 
@@ -0,0 +1,286 @@
+use std::mem;
+
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+use cheetah_common::commands::types::ownership::TransferOwnershipCommand;
+use cheetah_common::room::owner::GameObjectOwner;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+use crate::room::Room;
+
+///
+/// Передать владение объектом может его текущий владелец, объектом комнаты - только super member,
+/// новый владелец должен иметь доступ к объекту
+///
+impl ServerCommandExecutor for TransferOwnershipCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let member = room.get_member(&member_id)?;
+		let super_member = member.template.super_member;
+		let object = room.get_object(self.object_id)?;
+		if !super_member && object.owner != GameObjectOwner::Member(member_id) {
+			return Err(ServerCommandError::MemberNotOwnerGameObject {
+				object_id: self.object_id,
+				member_id,
+			});
+		}
+
+		let new_owner_groups = room.get_member(&self.new_owner)?.template.groups;
+		if !object.access_groups.contains_any(&new_owner_groups) {
+			return Err(ServerCommandError::MemberCannotAccessToObject {
+				room_id: room.id,
+				member_id: self.new_owner,
+				object_id: self.object_id,
+				member_access_group: new_owner_groups,
+				object_access_group: object.access_groups,
+			});
+		}
+
+		let object = room.get_object_mut(self.object_id)?;
+		if object.owner == GameObjectOwner::Member(self.new_owner) {
+			return Ok(());
+		}
+		let previous_owner = mem::replace(&mut object.owner, GameObjectOwner::Member(self.new_owner));
+		let template = object.template_id;
+		if let GameObjectOwner::Member(previous_owner) = previous_owner {
+			room.timers.remove_object_delete_timers(previous_owner, self.object_id);
+			// объект с позицией больше не определяет область интереса предыдущего владельца
+			if let Some(interest) = room.interest.as_mut().filter(|interest| interest.is_positional(template)) {
+				interest.mark_member_dirty(previous_owner);
+			}
+		}
+		let object = room.get_object(self.object_id)?;
+		if !object.created {
+			return Ok(());
+		}
+		let groups = object.access_groups;
+		let command = S2CCommandWithMeta {
+			field: None,
+			creator: member_id,
+			command: S2CCommand::TransferOwnership(self.clone()),
+		};
+		room.send_to_members(groups, Some(template), &[command], |member| member.id != member_id)?;
+
+		// поля, доступные только владельцу, отправляются новому владельцу и удаляются у предыдущего
+		room.resync_object_fields(self.new_owner, self.object_id, new_owner_groups, false)?;
+		if let GameObjectOwner::Member(previous_owner) = previous_owner {
+			if let Ok(previous_owner_member) = room.get_member(&previous_owner) {
+				let previous_owner_groups = previous_owner_member.template.groups;
+				room.resync_object_fields(previous_owner, self.object_id, previous_owner_groups, true)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::field::Field;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::delete::DeleteGameObjectCommand;
+	use cheetah_common::commands::types::ownership::TransferOwnershipCommand;
+	use cheetah_common::commands::{FieldType, FieldValue};
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::owner::GameObjectOwner;
+	use cheetah_common::room::RoomMemberId;
+
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::template::config::{
+		GameObjectTemplatePermission, GroupsPermissionRule, MemberTemplate, Permission, PermissionField, Permissions, RoomTemplate,
+	};
+	use crate::room::Room;
+
+	const ACCESS_GROUPS: AccessGroups = AccessGroups(10);
+
+	#[test]
+	fn should_transfer_member_object() {
+		let (mut room, member_a, member_b) = setup();
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_a), ACCESS_GROUPS)
+			.id;
+		room.test_out_commands.clear();
+
+		TransferOwnershipCommand {
+			object_id,
+			new_owner: member_b,
+		}
+		.execute(&mut room, member_a)
+		.unwrap();
+
+		assert_eq!(room.get_object(object_id).unwrap().owner, GameObjectOwner::Member(member_b));
+		assert!(matches!(
+			room.test_get_member_out_commands(member_b).pop_back(),
+			Some(S2CCommand::TransferOwnership(c)) if c.object_id == object_id && c.new_owner == member_b
+		));
+		assert!(room.test_get_member_out_commands(member_a).is_empty());
+	}
+
+	///
+	/// Объект удаляется при выходе текущего владельца, а не создателя
+	///
+	#[test]
+	fn should_delete_object_when_new_owner_disconnect() {
+		let (mut room, member_a, member_b) = setup();
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_a), ACCESS_GROUPS)
+			.id;
+		TransferOwnershipCommand {
+			object_id,
+			new_owner: member_b,
+		}
+		.execute(&mut room, member_a)
+		.unwrap();
+
+		room.disconnect_member(member_a).unwrap();
+		assert!(room.contains_object(&object_id));
+
+		room.disconnect_member(member_b).unwrap();
+		assert!(!room.contains_object(&object_id));
+	}
+
+	#[test]
+	fn should_allow_delete_only_by_new_owner() {
+		let (mut room, member_a, member_b) = setup();
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_a), ACCESS_GROUPS)
+			.id;
+		TransferOwnershipCommand {
+			object_id,
+			new_owner: member_b,
+		}
+		.execute(&mut room, member_a)
+		.unwrap();
+
+		assert_eq!(
+			DeleteGameObjectCommand { object_id }.execute(&mut room, member_a),
+			Err(ServerCommandError::MemberNotOwnerGameObject {
+				object_id,
+				member_id: member_a
+			})
+		);
+		DeleteGameObjectCommand { object_id }.execute(&mut room, member_b).unwrap();
+		assert!(!room.contains_object(&object_id));
+	}
+
+	#[test]
+	fn should_transfer_room_object_by_super_member() {
+		let (mut room, member_a, _) = setup();
+		let super_member_id = room.register_member(MemberTemplate::new_super_member());
+		let object_id = room.test_create_object_with_created_state(GameObjectOwner::Room, ACCESS_GROUPS).id;
+
+		assert_eq!(
+			TransferOwnershipCommand {
+				object_id,
+				new_owner: member_a
+			}
+			.execute(&mut room, member_a),
+			Err(ServerCommandError::MemberNotOwnerGameObject {
+				object_id,
+				member_id: member_a
+			})
+		);
+
+		TransferOwnershipCommand {
+			object_id,
+			new_owner: member_a,
+		}
+		.execute(&mut room, super_member_id)
+		.unwrap();
+		assert_eq!(room.get_object(object_id).unwrap().owner, GameObjectOwner::Member(member_a));
+	}
+
+	#[test]
+	fn should_not_transfer_not_owned_object() {
+		let (mut room, member_a, member_b) = setup();
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_a), ACCESS_GROUPS)
+			.id;
+
+		assert_eq!(
+			TransferOwnershipCommand {
+				object_id,
+				new_owner: member_b
+			}
+			.execute(&mut room, member_b),
+			Err(ServerCommandError::MemberNotOwnerGameObject {
+				object_id,
+				member_id: member_b
+			})
+		);
+		assert_eq!(room.get_object(object_id).unwrap().owner, GameObjectOwner::Member(member_a));
+	}
+
+	#[test]
+	fn should_not_transfer_to_member_without_access() {
+		let (mut room, member_a, _) = setup();
+		let member_c = room.register_member(MemberTemplate::stub(AccessGroups(0b1_0000)));
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_a), ACCESS_GROUPS)
+			.id;
+
+		assert!(matches!(
+			TransferOwnershipCommand { object_id, new_owner: member_c }.execute(&mut room, member_a),
+			Err(ServerCommandError::MemberCannotAccessToObject { member_id, .. }) if member_id == member_c
+		));
+	}
+
+	///
+	/// Поля, доступные только владельцу, передаются новому владельцу и удаляются у предыдущего
+	///
+	#[test]
+	fn should_resync_owner_fields() {
+		let field = Field {
+			id: 1,
+			field_type: FieldType::Long,
+		};
+		let (mut room, member_a, member_b) = setup_with_template(RoomTemplate {
+			permissions: Permissions {
+				templates: vec![GameObjectTemplatePermission {
+					template: 0,
+					rules: Default::default(),
+					fields: vec![PermissionField {
+						field,
+						rules: vec![GroupsPermissionRule {
+							groups: ACCESS_GROUPS,
+							permission: Permission::Owner,
+						}],
+					}],
+				}],
+				..Default::default()
+			},
+			..Default::default()
+		});
+		let object = room.test_create_object_with_created_state(GameObjectOwner::Member(member_a), ACCESS_GROUPS);
+		object.set_field(field.id, 100).unwrap();
+		let object_id = object.id;
+		room.test_out_commands.clear();
+
+		TransferOwnershipCommand {
+			object_id,
+			new_owner: member_b,
+		}
+		.execute(&mut room, member_a)
+		.unwrap();
+
+		assert!(matches!(
+			room.test_get_member_out_commands(member_b).pop_back(),
+			Some(S2CCommand::SetField(c)) if c.object_id == object_id && c.field_id == field.id && c.value == FieldValue::Long(100)
+		));
+		assert!(matches!(
+			room.test_get_member_out_commands(member_a).pop_back(),
+			Some(S2CCommand::DeleteField(c)) if c.object_id == object_id && c.field_id == field.id && c.field_type == FieldType::Long
+		));
+	}
+
+	fn setup() -> (Room, RoomMemberId, RoomMemberId) {
+		setup_with_template(RoomTemplate::default())
+	}
+
+	fn setup_with_template(template: RoomTemplate) -> (Room, RoomMemberId, RoomMemberId) {
+		let mut room = Room::from_template(template);
+		let member_a = room.register_member(MemberTemplate::stub(ACCESS_GROUPS));
+		let member_b = room.register_member(MemberTemplate::stub(ACCESS_GROUPS));
+		room.test_mark_as_connected(member_a).unwrap();
+		room.test_mark_as_connected(member_b).unwrap();
+		(room, member_a, member_b)
+	}
+}
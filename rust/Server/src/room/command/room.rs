@@ -2,26 +2,38 @@ use cheetah_common::room::RoomMemberId;
 use std::rc::Rc;
 
 use crate::room::command::ServerCommandError;
-use crate::room::object::CreateCommandsCollector;
+use crate::room::object::{CreateCommandsCollector, GameObject};
 use crate::room::Room;
 
 pub fn attach_to_room(room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
 	let member = room.get_member_mut(&member_id)?;
 	member.attached = true;
+	member.structure_delta_encoder.clear();
 	let access_group = member.template.groups;
+	let subscription = member.subscription.clone();
+	// объекты с позицией загружаются при пересчете области интереса
+	if let Some(interest) = room.interest.as_mut() {
+		interest.remove_member(member_id);
+	}
 	let command_collector_rc = Rc::clone(&room.tmp_command_collector);
 	let mut command_collector = (*command_collector_rc).borrow_mut();
 	command_collector.clear();
-	room.objects
-		.iter()
-		.filter(|(_, o)| o.created)
-		.filter(|(_, o)| o.access_groups.contains_any(&access_group))
-		.map(|(_, o)| {
+	let mut objects: Vec<_> = room
+		.objects
+		.values()
+		.filter(|o| o.created)
+		.filter(|o| o.access_groups.contains_any(&access_group))
+		.filter(|o| subscription.is_template_subscribed(o.template_id))
+		.collect();
+	// родительские объекты загружаются раньше дочерних
+	objects.sort_by_cached_key(|o| get_depth(room, o));
+	objects
+		.into_iter()
+		.map(|o| {
 			let mut commands = CreateCommandsCollector::new();
 			o.collect_create_commands(&mut commands, member_id);
 			(o.template_id, commands)
 		})
-		.clone()
 		.for_each(|v| command_collector.push(v));
 
 	for (template, commands) in command_collector.iter() {
@@ -30,9 +42,29 @@ pub fn attach_to_room(room: &mut Room, member_id: RoomMemberId) -> Result<(), Se
 	Ok(())
 }
 
+///
+/// Количество родительских объектов в иерархии
+///
+pub(crate) fn get_depth(room: &Room, object: &GameObject) -> usize {
+	let mut depth = 0;
+	let mut parent = object.parent;
+	while let Some(parent_id) = parent {
+		if depth >= room.objects.len() {
+			break;
+		}
+		depth += 1;
+		parent = room.objects.get(&parent_id).and_then(|o| o.parent);
+	}
+	depth
+}
+
 pub fn detach_from_room(room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
 	let member = room.get_member_mut(&member_id)?;
 	member.attached = false;
+	member.structure_delta_encoder.clear();
+	if let Some(interest) = room.interest.as_mut() {
+		interest.remove_member(member_id);
+	}
 	Ok(())
 }
 
@@ -77,4 +109,25 @@ mod tests {
 		assert!(matches!(commands.pop_front(), Some(S2CCommand::Created(c)) if c.object_id==object_a_1_id));
 		assert!(matches!(commands.pop_front(), None));
 	}
+
+	#[test]
+	pub(crate) fn should_load_parent_before_child() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let groups = AccessGroups(0b100);
+		let member_a = room.register_member(MemberTemplate::stub(groups));
+		let member_b = room.register_member(MemberTemplate::stub(groups));
+		room.test_mark_as_connected(member_a).unwrap();
+		room.test_mark_as_connected(member_b).unwrap();
+
+		let child_id = room.test_create_object_with_created_state(GameObjectOwner::Member(member_b), groups).id;
+		let parent_id = room.test_create_object_with_created_state(GameObjectOwner::Member(member_b), groups).id;
+		room.get_object_mut(child_id).unwrap().parent = Some(parent_id);
+
+		attach_to_room(&mut room, member_a).unwrap();
+
+		let mut commands = room.test_get_member_out_commands(member_a);
+		assert!(matches!(commands.pop_front(), Some(S2CCommand::Create(c)) if c.object_id == parent_id));
+		assert!(matches!(commands.pop_front(), Some(S2CCommand::Created(c)) if c.object_id == parent_id));
+		assert!(matches!(commands.pop_front(), Some(S2CCommand::Create(c)) if c.object_id == child_id && c.parent == Some(parent_id)));
+	}
 }
@@ -0,0 +1,148 @@
+use std::rc::Rc;
+use std::slice;
+
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+use cheetah_common::commands::types::room_event::{RoomEventCommand, RoomEventS2CCommand, RoomEventTarget};
+use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+use crate::room::template::config::Permission;
+use crate::room::Room;
+
+///
+/// Отправить событие могут пользователи с правом [`Permission::Rw`] на событие, получают - с правом не ниже [`Permission::Ro`]
+///
+impl ServerCommandExecutor for RoomEventCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let member = room.get_member(&member_id)?;
+		let event_id = self.event_id;
+		let permission_manager = Rc::clone(&room.permission_manager);
+		if !member.template.super_member && permission_manager.borrow().get_room_event_permission(event_id, member.template.groups) < Permission::Rw {
+			return Err(ServerCommandError::RoomEventPermissionDenied { member_id, event_id });
+		}
+
+		let command = S2CCommandWithMeta {
+			field: None,
+			creator: member_id,
+			command: S2CCommand::RoomEvent(RoomEventS2CCommand { event_id, event: self.event }),
+		};
+		let (access_groups, members) = match &self.target {
+			RoomEventTarget::Groups(groups) => (*groups, None),
+			RoomEventTarget::Members(members) => (AccessGroups::super_group(), Some(members)),
+		};
+		room.send_to_members(access_groups, None, slice::from_ref(&command), |member| {
+			let target = match members {
+				None => true,
+				Some(members) => members.contains(&member.id),
+			};
+			target
+				&& member.id != member_id
+				&& permission_manager.borrow().get_room_event_permission(event_id, member.template.groups) > Permission::Deny
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::binary_value::BinaryValue;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::room_event::{RoomEventCommand, RoomEventTarget};
+	use cheetah_common::room::access::AccessGroups;
+
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::template::config::{GroupsPermissionRule, MemberTemplate, Permission, RoomEventPermission, RoomTemplate};
+	use crate::room::Room;
+
+	const EVENT_ID: u16 = 5;
+
+	#[test]
+	fn should_send_room_event_to_groups() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let sender = room.register_member(MemberTemplate::stub(AccessGroups(0b11)));
+		let member_a = room.register_member(MemberTemplate::stub(AccessGroups(0b01)));
+		let member_b = room.register_member(MemberTemplate::stub(AccessGroups(0b10)));
+		room.test_mark_as_connected(sender).unwrap();
+		room.test_mark_as_connected(member_a).unwrap();
+		room.test_mark_as_connected(member_b).unwrap();
+
+		event(RoomEventTarget::Groups(AccessGroups(0b01))).execute(&mut room, sender).unwrap();
+
+		assert!(room.test_get_member_out_commands(sender).is_empty());
+		assert!(
+			matches!(room.test_get_member_out_commands(member_a).get(0), Some(S2CCommand::RoomEvent(c))
+			if c.event_id == EVENT_ID && c.event == payload())
+		);
+		assert!(room.test_get_member_out_commands(member_b).is_empty());
+	}
+
+	#[test]
+	fn should_send_room_event_to_members() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let sender = room.register_member(MemberTemplate::stub(AccessGroups(0b01)));
+		let member_a = room.register_member(MemberTemplate::stub(AccessGroups(0b01)));
+		let member_b = room.register_member(MemberTemplate::stub(AccessGroups(0b10)));
+		room.test_mark_as_connected(sender).unwrap();
+		room.test_mark_as_connected(member_a).unwrap();
+		room.test_mark_as_connected(member_b).unwrap();
+
+		event(RoomEventTarget::Members(vec![member_b])).execute(&mut room, sender).unwrap();
+
+		assert!(room.test_get_member_out_commands(member_a).is_empty());
+		assert!(matches!(room.test_get_member_out_commands(member_b).get(0), Some(S2CCommand::RoomEvent(c)) if c.event_id == EVENT_ID));
+	}
+
+	#[test]
+	fn should_check_room_event_permissions() {
+		let mut template = RoomTemplate::default();
+		template.permissions.room_events.push(RoomEventPermission {
+			event_id: EVENT_ID,
+			rules: vec![
+				GroupsPermissionRule {
+					groups: AccessGroups(0b01),
+					permission: Permission::Rw,
+				},
+				GroupsPermissionRule {
+					groups: AccessGroups(0b10),
+					permission: Permission::Ro,
+				},
+				GroupsPermissionRule {
+					groups: AccessGroups(0b100),
+					permission: Permission::Deny,
+				},
+			],
+		});
+		let mut room = Room::from_template(template);
+		let writer = room.register_member(MemberTemplate::stub(AccessGroups(0b01)));
+		let reader = room.register_member(MemberTemplate::stub(AccessGroups(0b10)));
+		let denied = room.register_member(MemberTemplate::stub(AccessGroups(0b100)));
+		room.test_mark_as_connected(writer).unwrap();
+		room.test_mark_as_connected(reader).unwrap();
+		room.test_mark_as_connected(denied).unwrap();
+
+		let command = event(RoomEventTarget::Groups(AccessGroups(0b111)));
+		assert_eq!(
+			command.execute(&mut room, reader),
+			Err(ServerCommandError::RoomEventPermissionDenied {
+				member_id: reader,
+				event_id: EVENT_ID
+			})
+		);
+		command.execute(&mut room, writer).unwrap();
+
+		assert!(matches!(room.test_get_member_out_commands(reader).get(0), Some(S2CCommand::RoomEvent(_))));
+		assert!(room.test_get_member_out_commands(denied).is_empty());
+	}
+
+	fn event(target: RoomEventTarget) -> RoomEventCommand {
+		RoomEventCommand {
+			event_id: EVENT_ID,
+			target,
+			event: payload(),
+		}
+	}
+
+	fn payload() -> BinaryValue {
+		BinaryValue::from([1, 2, 3].as_ref())
+	}
+}
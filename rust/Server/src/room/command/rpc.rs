@@ -0,0 +1,231 @@
+use std::slice;
+use std::time::Instant;
+
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+use cheetah_common::commands::types::rpc::{RpcRequestCommand, RpcResponseCommand, RpcStatus};
+use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+use crate::room::rpc::RpcCall;
+use crate::room::Room;
+
+///
+/// Запрос пересылается вызываемому пользователю, если пользователь не подключен или не присоединен к комнате - вызывающему
+/// сразу отправляется ошибка
+///
+impl ServerCommandExecutor for RpcRequestCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		room.get_member(&member_id)?;
+		let call = RpcCall {
+			caller: member_id,
+			call_id: self.call_id,
+			target: self.member_id,
+			deadline: room.get_server_time(Instant::now()).saturating_add(u64::from(self.timeout)),
+			channel: room.current_channel,
+		};
+		let available = room
+			.members
+			.get(&self.member_id)
+			.is_some_and(|target| target.connected && target.attached);
+		if !available {
+			return reply_error(room, &call, RpcStatus::TargetNotFound);
+		}
+		room.rpc_calls.add(call)?;
+
+		let command = S2CCommandWithMeta {
+			field: None,
+			creator: member_id,
+			command: S2CCommand::RpcRequest(RpcRequestCommand {
+				member_id,
+				call_id: self.call_id,
+				timeout: self.timeout,
+				payload: self.payload,
+			}),
+		};
+		let target = self.member_id;
+		room.send_to_members(AccessGroups::super_group(), None, slice::from_ref(&command), |member| member.id == target)
+	}
+}
+
+///
+/// Ответ пересылается вызывающему пользователю, ответ на завершенный вызов или от другого пользователя игнорируется
+///
+impl ServerCommandExecutor for RpcResponseCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		room.get_member(&member_id)?;
+		match room.rpc_calls.take(self.member_id, self.call_id, member_id) {
+			None => Ok(()),
+			Some(call) => send_response(room, &call, self.status, self.payload),
+		}
+	}
+}
+
+///
+/// Отправить вызывающему пользователю ответ, сформированный сервером
+///
+pub(crate) fn reply_error(room: &mut Room, call: &RpcCall, status: RpcStatus) -> Result<(), ServerCommandError> {
+	let current_channel = room.current_channel;
+	room.current_channel = call.channel;
+	let result = send_response(room, call, status, BinaryValue::default());
+	room.current_channel = current_channel;
+	result
+}
+
+fn send_response(room: &mut Room, call: &RpcCall, status: RpcStatus, payload: BinaryValue) -> Result<(), ServerCommandError> {
+	let command = S2CCommandWithMeta {
+		field: None,
+		creator: call.target,
+		command: S2CCommand::RpcResponse(RpcResponseCommand {
+			member_id: call.target,
+			call_id: call.call_id,
+			status,
+			payload,
+		}),
+	};
+	let caller = call.caller;
+	room.send_to_members(AccessGroups::super_group(), None, slice::from_ref(&command), |member| member.id == caller)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use cheetah_common::commands::binary_value::BinaryValue;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::rpc::{RpcRequestCommand, RpcResponseCommand, RpcStatus};
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::RoomMemberId;
+
+	use crate::room::command::ServerCommandExecutor;
+	use crate::room::template::config::{MemberTemplate, RoomTemplate};
+	use crate::room::Room;
+
+	#[test]
+	fn should_route_request_and_response() {
+		let (mut room, caller, target) = setup();
+		request(target, 1000).execute(&mut room, caller).unwrap();
+
+		let commands = room.test_get_member_out_commands(target);
+		assert!(matches!(commands.get(0), Some(S2CCommand::RpcRequest(c)) if c.member_id == caller && c.call_id == 1 && c.payload == payload()));
+		assert!(room.test_get_member_out_commands(caller).is_empty());
+
+		response(caller, RpcStatus::Ok).execute(&mut room, target).unwrap();
+		let commands = room.test_get_member_out_commands(caller);
+		assert!(matches!(commands.get(0), Some(S2CCommand::RpcResponse(c))
+			if c.member_id == target && c.call_id == 1 && c.status == RpcStatus::Ok && c.payload == payload()));
+		assert_eq!(room.rpc_calls.iter().count(), 0);
+	}
+
+	#[test]
+	fn should_ignore_response_from_not_target() {
+		let (mut room, caller, target) = setup();
+		let other = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		room.test_mark_as_connected(other).unwrap();
+		request(target, 1000).execute(&mut room, caller).unwrap();
+
+		response(caller, RpcStatus::Ok).execute(&mut room, other).unwrap();
+		assert!(room.test_get_member_out_commands(caller).is_empty());
+		assert_eq!(room.rpc_calls.iter().count(), 1);
+	}
+
+	#[test]
+	fn should_reply_target_not_found() {
+		let (mut room, caller, _) = setup();
+		request(1000, 1000).execute(&mut room, caller).unwrap();
+
+		let commands = room.test_get_member_out_commands(caller);
+		assert!(matches!(commands.get(0), Some(S2CCommand::RpcResponse(c)) if c.member_id == 1000 && c.status == RpcStatus::TargetNotFound));
+		assert_eq!(room.rpc_calls.iter().count(), 0);
+	}
+
+	#[test]
+	fn should_reply_target_not_found_when_target_not_attached() {
+		let (mut room, caller, target) = setup();
+		room.get_member_mut(&target).unwrap().attached = false;
+		request(target, 1000).execute(&mut room, caller).unwrap();
+
+		let commands = room.test_get_member_out_commands(caller);
+		assert!(matches!(commands.front(), Some(S2CCommand::RpcResponse(c)) if c.member_id == target && c.status == RpcStatus::TargetNotFound));
+		assert_eq!(room.rpc_calls.iter().count(), 0);
+
+		room.get_member_mut(&target).unwrap().connected = false;
+		room.get_member_mut(&target).unwrap().attached = true;
+		request(target, 1000).execute(&mut room, caller).unwrap();
+		assert_eq!(room.test_get_member_out_commands(caller).len(), 2);
+		assert_eq!(room.rpc_calls.iter().count(), 0);
+	}
+
+	#[test]
+	fn should_reply_timeout() {
+		let (mut room, caller, target) = setup();
+		request(target, 1000).execute(&mut room, caller).unwrap();
+
+		expire_rpc_calls(&mut room, 500);
+		assert!(room.test_get_member_out_commands(caller).is_empty());
+
+		expire_rpc_calls(&mut room, 2000);
+		let commands = room.test_get_member_out_commands(caller);
+		assert!(matches!(commands.get(0), Some(S2CCommand::RpcResponse(c)) if c.call_id == 1 && c.status == RpcStatus::Timeout));
+
+		// ответ после истечения времени ожидания не пересылается
+		response(caller, RpcStatus::Ok).execute(&mut room, target).unwrap();
+		assert_eq!(room.test_get_member_out_commands(caller).len(), 1);
+	}
+
+	#[test]
+	fn should_reply_target_not_found_when_target_disconnected() {
+		let (mut room, caller, target) = setup();
+		request(target, 1000).execute(&mut room, caller).unwrap();
+		room.disconnect_member(target).unwrap();
+
+		let commands = room.test_get_member_out_commands(caller);
+		assert!(matches!(commands.get(0), Some(S2CCommand::RpcResponse(c)) if c.call_id == 1 && c.status == RpcStatus::TargetNotFound));
+		assert_eq!(room.rpc_calls.iter().count(), 0);
+	}
+
+	#[test]
+	fn should_remove_calls_when_caller_disconnected() {
+		let (mut room, caller, target) = setup();
+		request(target, 1000).execute(&mut room, caller).unwrap();
+		room.disconnect_member(caller).unwrap();
+		assert_eq!(room.rpc_calls.iter().count(), 0);
+	}
+
+	fn setup() -> (Room, RoomMemberId, RoomMemberId) {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let caller = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		let target = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		room.test_mark_as_connected(caller).unwrap();
+		room.test_mark_as_connected(target).unwrap();
+		(room, caller, target)
+	}
+
+	fn request(target: RoomMemberId, timeout: u32) -> RpcRequestCommand {
+		RpcRequestCommand {
+			member_id: target,
+			call_id: 1,
+			timeout,
+			payload: payload(),
+		}
+	}
+
+	fn response(caller: RoomMemberId, status: RpcStatus) -> RpcResponseCommand {
+		RpcResponseCommand {
+			member_id: caller,
+			call_id: 1,
+			status,
+			payload: payload(),
+		}
+	}
+
+	fn payload() -> BinaryValue {
+		BinaryValue::from([1, 2, 3].as_ref())
+	}
+
+	fn expire_rpc_calls(room: &mut Room, server_time: u64) {
+		let now = room.start_application_time + Duration::from_millis(server_time);
+		room.expire_rpc_calls(now);
+	}
+}
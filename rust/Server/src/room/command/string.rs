@@ -0,0 +1,85 @@
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::room::object::GameObjectId;
+
+use crate::room::command::ServerCommandError;
+use crate::room::Room;
+
+impl Room {
+	///
+	/// Проверка длины строки в символах по ограничению из шаблона комнаты, размер в байтах
+	/// дополнительно ограничен [`MAX_STRING_SIZE`](cheetah_common::commands::types::string::MAX_STRING_SIZE) при декодировании команды
+	///
+	pub(crate) fn check_string_length(&self, object_id: GameObjectId, field_id: FieldId, value: &str) -> Result<(), ServerCommandError> {
+		let Some(max_length) = self.max_string_length else {
+			return Ok(());
+		};
+		let length = value.chars().count();
+		if length > max_length {
+			return Err(ServerCommandError::StringTooLong {
+				object_id,
+				field_id,
+				length,
+				max_length,
+			});
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::field::SetFieldCommand;
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::owner::GameObjectOwner;
+
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::template::config::{MemberTemplate, RoomTemplate};
+	use crate::room::Room;
+
+	#[test]
+	fn should_set_string() {
+		let (mut room, member_id, command) = setup("Игрок");
+		command.execute(&mut room, member_id).unwrap();
+
+		let object = room.get_object_mut(command.object_id).unwrap();
+		assert_eq!(object.get_field::<String>(command.field_id).unwrap(), "Игрок");
+		assert!(matches!(room.test_out_commands.pop_back(), Some((.., S2CCommand::SetField(c))) if c == command));
+	}
+
+	#[test]
+	fn should_reject_too_long_string() {
+		let (mut room, member_id, command) = setup("Игрок 123");
+		assert_eq!(
+			command.execute(&mut room, member_id),
+			Err(ServerCommandError::StringTooLong {
+				object_id: command.object_id,
+				field_id: command.field_id,
+				length: 9,
+				max_length: 5,
+			})
+		);
+		let object = room.get_object_mut(command.object_id).unwrap();
+		assert!(object.get_field::<String>(command.field_id).is_none());
+		assert!(room.test_out_commands.is_empty());
+	}
+
+	fn setup(value: &str) -> (Room, u16, SetFieldCommand) {
+		let mut room = Room::from_template(RoomTemplate {
+			max_string_length: Some(5),
+			..RoomTemplate::default()
+		});
+		let access_groups = AccessGroups(10);
+		let member_id = room.register_member(MemberTemplate::stub(access_groups));
+		let object = room.test_create_object_with_not_created_state(GameObjectOwner::Member(member_id), access_groups);
+		object.created = true;
+		let object_id = object.id;
+		room.test_out_commands.clear();
+		let command = SetFieldCommand {
+			object_id,
+			field_id: 10,
+			value: value.into(),
+		};
+		(room, member_id, command)
+	}
+}
@@ -0,0 +1,211 @@
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::ServerCommandError;
+use crate::room::object::GameObject;
+use crate::room::subscription::Subscription;
+use crate::room::Room;
+
+impl Room {
+	///
+	/// Изменить подписку пользователя, пользователю отправляется создание объектов шаблонов, на которые он подписался,
+	/// и удаление объектов шаблонов, от которых он отписался. Для остальных загруженных объектов отправляются значения полей,
+	/// на которые пользователь подписался, и удаление полей, от которых он отписался
+	///
+	pub(crate) fn update_subscription<F>(&mut self, member_id: RoomMemberId, update: F) -> Result<(), ServerCommandError>
+	where
+		F: FnOnce(&mut Subscription),
+	{
+		let member = self.get_member(&member_id)?;
+		let mut subscription = member.subscription.clone();
+		update(&mut subscription);
+		if !member.attached {
+			self.get_member_mut(&member_id)?.subscription = subscription;
+			return Ok(());
+		}
+
+		let groups = member.template.groups;
+		let interest = self.interest.as_ref();
+		let loaded = |o: &GameObject| {
+			let visible = match interest {
+				None => true,
+				Some(interest) => interest.is_visible(member_id, o.template_id, &o.id),
+			};
+			visible && o.access_groups.contains_any(&groups)
+		};
+		let mut resync = self.collect_objects_resync(
+			member_id,
+			|o| loaded(o) && member.subscription.is_template_subscribed(o.template_id) != subscription.is_template_subscribed(o.template_id),
+			|o| subscription.is_template_subscribed(o.template_id),
+		);
+		let kept: Vec<_> = self
+			.objects
+			.values()
+			.filter(|o| {
+				o.created
+					&& loaded(o) && member.subscription.is_template_subscribed(o.template_id)
+					&& subscription.is_template_subscribed(o.template_id)
+			})
+			.map(|o| o.id)
+			.collect();
+
+		// удаление отправляется по старой подписке, создание - по новой
+		resync.send_deletes(self, member_id)?;
+		let previous = std::mem::replace(&mut self.get_member_mut(&member_id)?.subscription, subscription);
+		resync.send_creates(self, member_id)?;
+		for object_id in kept {
+			self.resync_subscribed_fields(member_id, object_id, &previous)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::c2s::C2SCommand;
+	use cheetah_common::commands::field::Field;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::field::SetFieldCommand;
+	use cheetah_common::commands::types::subscription::SubscriptionCommand;
+	use cheetah_common::commands::FieldType;
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::owner::GameObjectOwner;
+
+	use crate::room::command::execute;
+	use crate::room::command::room::attach_to_room;
+	use crate::room::template::config::{MemberTemplate, RoomTemplate};
+	use crate::room::Room;
+
+	const ACCESS_GROUPS: AccessGroups = AccessGroups(10);
+
+	#[test]
+	fn should_load_only_subscribed_templates_when_attach() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(ACCESS_GROUPS));
+		room.test_mark_as_connected(member_id).unwrap();
+		room.get_member_mut(&member_id).unwrap().attached = false;
+		let object_1 = room.test_create_object_with_created_state(GameObjectOwner::Room, ACCESS_GROUPS);
+		object_1.template_id = 1;
+		let object_1_id = object_1.id;
+		room.test_create_object_with_created_state(GameObjectOwner::Room, ACCESS_GROUPS)
+			.template_id = 2;
+
+		execute(&subscribe(vec![1], vec![]), &mut room, member_id).unwrap();
+		attach_to_room(&mut room, member_id).unwrap();
+
+		let commands = room.test_get_member_out_commands(member_id);
+		assert!(commands.iter().all(|c| c.get_object_id() == Some(object_1_id)));
+		assert!(matches!(commands.get(0), Some(S2CCommand::Create(c)) if c.object_id == object_1_id));
+	}
+
+	#[test]
+	fn should_filter_fields_by_subscription() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(ACCESS_GROUPS));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object = room.test_create_object_with_created_state(GameObjectOwner::Room, ACCESS_GROUPS);
+		object.template_id = 1;
+		let object_id = object.id;
+		execute(&subscribe(vec![], vec![10]), &mut room, member_id).unwrap();
+
+		for field_id in [10, 11] {
+			let field = Field {
+				id: field_id,
+				field_type: FieldType::Long,
+			};
+			room.send_field_command(
+				object_id,
+				field,
+				u16::MAX,
+				None,
+				S2CCommand::SetField(SetFieldCommand {
+					object_id,
+					field_id,
+					value: 1.into(),
+				}),
+			)
+			.unwrap();
+		}
+
+		let commands = room.test_get_member_out_commands(member_id);
+		assert_eq!(commands.len(), 1);
+		assert!(matches!(commands.get(0), Some(S2CCommand::SetField(c)) if c.field_id == 10));
+	}
+
+	#[test]
+	fn should_resync_objects_when_subscription_changed() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(ACCESS_GROUPS));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object_1 = room.test_create_object_with_created_state(GameObjectOwner::Room, ACCESS_GROUPS);
+		object_1.template_id = 1;
+		let object_1_id = object_1.id;
+		let object_2 = room.test_create_object_with_created_state(GameObjectOwner::Room, ACCESS_GROUPS);
+		object_2.template_id = 2;
+		let object_2_id = object_2.id;
+
+		execute(&subscribe(vec![1], vec![]), &mut room, member_id).unwrap();
+		let commands = room.test_get_member_out_commands(member_id);
+		assert!(matches!(commands.get(0), Some(S2CCommand::Delete(c)) if c.object_id == object_2_id));
+		assert_eq!(commands.len(), 1);
+
+		execute(&C2SCommand::Unsubscribe(SubscriptionCommand::default()), &mut room, member_id).unwrap();
+		let commands = room.test_get_member_out_commands(member_id);
+		assert!(matches!(commands.get(1), Some(S2CCommand::Create(c)) if c.object_id == object_2_id));
+		assert!(!commands.iter().any(|c| matches!(c, S2CCommand::Create(c) if c.object_id == object_1_id)));
+	}
+
+	#[test]
+	fn should_resync_fields_when_subscription_changed() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(ACCESS_GROUPS));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object = room.test_create_object_with_created_state(GameObjectOwner::Room, ACCESS_GROUPS);
+		object.set_field(10, 100).unwrap();
+		object.set_field(11, 200).unwrap();
+		let object_id = object.id;
+
+		execute(&subscribe(vec![], vec![10]), &mut room, member_id).unwrap();
+		let commands = room.test_get_member_out_commands(member_id);
+		assert_eq!(commands.len(), 1);
+		assert!(matches!(commands.front(), Some(S2CCommand::DeleteField(c)) if c.object_id == object_id && c.field_id == 11));
+
+		execute(&C2SCommand::Unsubscribe(SubscriptionCommand::default()), &mut room, member_id).unwrap();
+		let commands = room.test_get_member_out_commands(member_id);
+		assert_eq!(commands.len(), 2);
+		assert!(matches!(commands.get(1), Some(S2CCommand::SetField(c)) if c.object_id == object_id && c.field_id == 11));
+	}
+
+	#[test]
+	fn should_unsubscribe_when_subscribed_to_all() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(ACCESS_GROUPS));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object_1 = room.test_create_object_with_created_state(GameObjectOwner::Room, ACCESS_GROUPS);
+		object_1.template_id = 1;
+		object_1.set_field(10, 100).unwrap();
+		let object_1_id = object_1.id;
+		let object_2 = room.test_create_object_with_created_state(GameObjectOwner::Room, ACCESS_GROUPS);
+		object_2.template_id = 2;
+		let object_2_id = object_2.id;
+
+		execute(
+			&C2SCommand::Unsubscribe(SubscriptionCommand {
+				templates: vec![2],
+				fields: vec![10],
+			}),
+			&mut room,
+			member_id,
+		)
+		.unwrap();
+		let commands = room.test_get_member_out_commands(member_id);
+		assert_eq!(commands.len(), 2);
+		assert!(commands.iter().any(|c| matches!(c, S2CCommand::Delete(c) if c.object_id == object_2_id)));
+		assert!(commands
+			.iter()
+			.any(|c| matches!(c, S2CCommand::DeleteField(c) if c.object_id == object_1_id && c.field_id == 10)));
+	}
+
+	fn subscribe(templates: Vec<u16>, fields: Vec<u16>) -> C2SCommand {
+		C2SCommand::Subscribe(SubscriptionCommand { templates, fields })
+	}
+}
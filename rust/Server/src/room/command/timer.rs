@@ -0,0 +1,343 @@
+use std::time::Instant;
+
+use cheetah_common::commands::field::Field;
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+use cheetah_common::commands::types::timer::{CancelTimerCommand, ScheduleTimerCommand, TimerAction, TimerOwner, TimerTime};
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::transaction::{apply, check_mutation};
+use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+use crate::room::template::config::Permission;
+use crate::room::timer::Timer;
+use crate::room::Room;
+
+///
+/// Права доступа проверяются при создании таймера, при срабатывании действие выполняется от имени создателя,
+/// изменение поля проверяется правилами шаблона комнаты в момент срабатывания
+///
+impl ServerCommandExecutor for ScheduleTimerCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		let member = room.get_member(&member_id)?;
+		if self.owner == TimerOwner::Room && !member.template.super_member {
+			return Err(ServerCommandError::TimerPermissionDenied { member_id });
+		}
+
+		match &self.action {
+			TimerAction::Mutation(mutation) => check_mutation(room, mutation, member_id)?,
+			TimerAction::Event(_) => {
+				if let Some(field) = self.action.get_field() {
+					room.check_field_access(self.action.get_object_id(), field, member_id, Permission::Rw)?;
+				}
+			}
+			TimerAction::Delete(command) => room.check_object_owner(command.object_id, member_id)?,
+		}
+
+		let fire_at = match self.time {
+			TimerTime::Delay(delay) => room.get_server_time(Instant::now()).saturating_add(delay),
+			TimerTime::ServerTime(time) => time,
+		};
+		let timer = Timer {
+			creator: member_id,
+			timer_id: self.timer_id,
+			owner: self.owner,
+			fire_at,
+			channel: room.current_channel,
+			action: self.action.clone(),
+		};
+		room.timers.schedule(timer)
+	}
+}
+
+impl ServerCommandExecutor for CancelTimerCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		room.get_member(&member_id)?;
+		room.timers.cancel(member_id, self.timer_id);
+		Ok(())
+	}
+}
+
+///
+/// Выполнить действие таймера, результат отправляется всем пользователям с доступом к объекту
+///
+pub(crate) fn fire(room: &mut Room, timer: Timer) {
+	let current_channel = room.current_channel;
+	room.current_channel = timer.channel;
+	if let Err(e) = fire_action(room, timer.creator, &timer.action) {
+		e.log_error(room.id, timer.creator);
+	}
+	room.current_channel = current_channel;
+}
+
+fn fire_action(room: &mut Room, creator: RoomMemberId, action: &TimerAction) -> Result<(), ServerCommandError> {
+	match action {
+		TimerAction::Mutation(mutation) => {
+			let object_id = mutation.get_object_id();
+			let field = mutation.get_field();
+			let mut object = room.get_object(object_id)?.clone();
+			let previous = room
+				.is_field_validated(object.template_id, field, creator)
+				.then(|| object.get_field_wrapped(field.id, field.field_type).cloned());
+			let command = apply(room, &mut object, mutation)?;
+			*room.get_object_mut(object_id)? = object;
+			let command = match previous {
+				Some(previous) => room.validate_field_change(object_id, field, creator, previous, command)?,
+				None => command,
+			};
+			send(room, object_id, field, creator, command)
+		}
+		TimerAction::Event(command) => match action.get_field() {
+			Some(field) => send(room, command.object_id, field, creator, S2CCommand::Event(command.clone())),
+			None => Ok(()),
+		},
+		TimerAction::Delete(command) => {
+			// владение объектом могло перейти к другому пользователю после создания таймера
+			room.check_object_owner(command.object_id, creator)?;
+			room.delete_object_with_filter(command.object_id, creator, |_| true).map(|_| ())
+		}
+	}
+}
+
+fn send(room: &mut Room, object_id: GameObjectId, field: Field, creator: RoomMemberId, command: S2CCommand) -> Result<(), ServerCommandError> {
+	room.record_field_history(object_id, field);
+	let object = room.get_object(object_id)?;
+	if !object.created {
+		return Ok(());
+	}
+	let groups = object.access_groups;
+	let template = object.template_id;
+	let command = S2CCommandWithMeta {
+		field: Some(field),
+		creator,
+		command,
+	};
+	room.send_to_members(groups, Some(template), &[command], |_| true)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use cheetah_common::commands::field::Field;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::delete::DeleteGameObjectCommand;
+	use cheetah_common::commands::types::event::EventCommand;
+	use cheetah_common::commands::types::field::SetFieldCommand;
+	use cheetah_common::commands::types::ownership::TransferOwnershipCommand;
+	use cheetah_common::commands::types::timer::{CancelTimerCommand, ScheduleTimerCommand, TimerAction, TimerOwner, TimerTime};
+	use cheetah_common::commands::types::transaction::TransactionMutation;
+	use cheetah_common::commands::FieldType;
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::object::GameObjectId;
+	use cheetah_common::room::owner::GameObjectOwner;
+	use cheetah_common::room::RoomMemberId;
+
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::template::config::{FieldValidationReaction, FieldValidationRule, FieldValidationTemplate, MemberTemplate, RoomTemplate};
+	use crate::room::Room;
+
+	const FIELD_ID: u16 = 1;
+
+	#[test]
+	fn should_set_field_on_server_time() {
+		let (mut room, member_id, object_id) = setup();
+		schedule(1, TimerTime::ServerTime(1000), TimerOwner::Member, set_field(object_id))
+			.execute(&mut room, member_id)
+			.unwrap();
+
+		fire_timers(&mut room, 999);
+		assert!(room.get_object(object_id).unwrap().get_field::<i64>(FIELD_ID).is_none());
+		assert!(room.test_out_commands.is_empty());
+
+		fire_timers(&mut room, 1000);
+		assert_eq!(*room.get_object(object_id).unwrap().get_field::<i64>(FIELD_ID).unwrap(), 100);
+		assert!(matches!(room.test_out_commands.pop_back(), Some((.., S2CCommand::SetField(c))) if c.field_id == FIELD_ID));
+		assert_eq!(room.timers.iter().count(), 0);
+	}
+
+	#[test]
+	fn should_fire_event_after_delay() {
+		let (mut room, member_id, object_id) = setup();
+		let event = TimerAction::Event(EventCommand {
+			object_id,
+			field_id: FIELD_ID,
+			event: Default::default(),
+		});
+		schedule(1, TimerTime::Delay(3000), TimerOwner::Member, event)
+			.execute(&mut room, member_id)
+			.unwrap();
+		assert!(room.timers.iter().all(|timer| timer.fire_at >= 3000));
+
+		fire_timers(&mut room, 60_000);
+		assert!(matches!(room.test_out_commands.pop_back(), Some((.., S2CCommand::Event(c))) if c.field_id == FIELD_ID));
+	}
+
+	#[test]
+	fn should_delete_object_and_notify_creator() {
+		let (mut room, member_id, object_id) = setup();
+		let delete = TimerAction::Delete(DeleteGameObjectCommand { object_id });
+		schedule(1, TimerTime::ServerTime(0), TimerOwner::Member, delete)
+			.execute(&mut room, member_id)
+			.unwrap();
+
+		fire_timers(&mut room, 0);
+		assert!(!room.contains_object(&object_id));
+		assert!(matches!(room.test_get_member_out_commands(member_id).pop_back(), Some(S2CCommand::Delete(c)) if c.object_id == object_id));
+	}
+
+	#[test]
+	fn should_cancel_timer() {
+		let (mut room, member_id, object_id) = setup();
+		schedule(1, TimerTime::ServerTime(0), TimerOwner::Member, set_field(object_id))
+			.execute(&mut room, member_id)
+			.unwrap();
+		CancelTimerCommand { timer_id: 1 }.execute(&mut room, member_id).unwrap();
+
+		fire_timers(&mut room, 0);
+		assert!(room.get_object(object_id).unwrap().get_field::<i64>(FIELD_ID).is_none());
+	}
+
+	#[test]
+	fn should_remove_member_timers_when_disconnect() {
+		let (mut room, member_id, _) = setup();
+		let object_id = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(10)).id;
+		schedule(1, TimerTime::ServerTime(0), TimerOwner::Member, set_field(object_id))
+			.execute(&mut room, member_id)
+			.unwrap();
+
+		room.disconnect_member(member_id).unwrap();
+		assert_eq!(room.timers.iter().count(), 0);
+	}
+
+	#[test]
+	fn should_keep_room_timers_when_disconnect() {
+		let (mut room, _, _) = setup();
+		let super_member_id = room.register_member(MemberTemplate::new_super_member());
+		let object_id = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(10)).id;
+		schedule(1, TimerTime::ServerTime(0), TimerOwner::Room, set_field(object_id))
+			.execute(&mut room, super_member_id)
+			.unwrap();
+
+		room.disconnect_member(super_member_id).unwrap();
+		fire_timers(&mut room, 0);
+		assert_eq!(*room.get_object(object_id).unwrap().get_field::<i64>(FIELD_ID).unwrap(), 100);
+	}
+
+	#[test]
+	fn should_not_schedule_room_timer_by_member() {
+		let (mut room, member_id, object_id) = setup();
+		assert_eq!(
+			schedule(1, TimerTime::ServerTime(0), TimerOwner::Room, set_field(object_id)).execute(&mut room, member_id),
+			Err(ServerCommandError::TimerPermissionDenied { member_id })
+		);
+	}
+
+	#[test]
+	fn should_not_schedule_delete_for_not_owned_object() {
+		let (mut room, member_id, _) = setup();
+		let other_member_id = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(other_member_id), AccessGroups(10))
+			.id;
+		let delete = TimerAction::Delete(DeleteGameObjectCommand { object_id });
+		assert_eq!(
+			schedule(1, TimerTime::ServerTime(0), TimerOwner::Member, delete).execute(&mut room, member_id),
+			Err(ServerCommandError::MemberNotOwnerGameObject { object_id, member_id })
+		);
+	}
+
+	#[test]
+	fn should_not_delete_object_after_transfer_ownership() {
+		let (mut room, member_id, object_id) = setup();
+		let other_member_id = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		let delete = TimerAction::Delete(DeleteGameObjectCommand { object_id });
+		schedule(1, TimerTime::ServerTime(0), TimerOwner::Member, delete)
+			.execute(&mut room, member_id)
+			.unwrap();
+		TransferOwnershipCommand {
+			object_id,
+			new_owner: other_member_id,
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+		assert_eq!(room.timers.iter().count(), 0);
+
+		fire_timers(&mut room, 0);
+		assert!(room.contains_object(&object_id));
+	}
+
+	#[test]
+	fn should_check_owner_when_fire_delete() {
+		let (mut room, member_id, object_id) = setup();
+		let other_member_id = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		let delete = TimerAction::Delete(DeleteGameObjectCommand { object_id });
+		schedule(1, TimerTime::ServerTime(0), TimerOwner::Member, delete)
+			.execute(&mut room, member_id)
+			.unwrap();
+		room.get_object_mut(object_id).unwrap().owner = GameObjectOwner::Member(other_member_id);
+
+		fire_timers(&mut room, 0);
+		assert!(room.contains_object(&object_id));
+	}
+
+	#[test]
+	fn should_validate_mutation_when_fire() {
+		let (mut room, member_id, object_id) = setup_with_template(RoomTemplate {
+			field_validations: vec![FieldValidationTemplate {
+				template: 0,
+				field: Field {
+					id: FIELD_ID,
+					field_type: FieldType::Long,
+				},
+				rule: FieldValidationRule::Range { min: 0.0, max: 50.0 },
+				reaction: FieldValidationReaction::Reject,
+			}],
+			..RoomTemplate::default()
+		});
+		schedule(1, TimerTime::ServerTime(0), TimerOwner::Member, set_field(object_id))
+			.execute(&mut room, member_id)
+			.unwrap();
+
+		fire_timers(&mut room, 0);
+		assert!(room.get_object(object_id).unwrap().get_field::<i64>(FIELD_ID).is_none());
+		assert!(room.test_out_commands.is_empty());
+	}
+
+	fn setup() -> (Room, RoomMemberId, GameObjectId) {
+		setup_with_template(RoomTemplate::default())
+	}
+
+	fn setup_with_template(template: RoomTemplate) -> (Room, RoomMemberId, GameObjectId) {
+		let access_groups = AccessGroups(10);
+		let mut room = Room::from_template(template);
+		let member_id = room.register_member(MemberTemplate::stub(access_groups));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_id), access_groups)
+			.id;
+		room.test_out_commands.clear();
+		(room, member_id, object_id)
+	}
+
+	fn schedule(timer_id: u32, time: TimerTime, owner: TimerOwner, action: TimerAction) -> ScheduleTimerCommand {
+		ScheduleTimerCommand {
+			timer_id,
+			time,
+			owner,
+			action,
+		}
+	}
+
+	fn set_field(object_id: GameObjectId) -> TimerAction {
+		TimerAction::Mutation(TransactionMutation::SetField(SetFieldCommand {
+			object_id,
+			field_id: FIELD_ID,
+			value: 100.into(),
+		}))
+	}
+
+	fn fire_timers(room: &mut Room, server_time: u64) {
+		let now = room.start_application_time + Duration::from_millis(server_time);
+		room.fire_timers(now);
+	}
+}
@@ -0,0 +1,301 @@
+use std::collections::hash_map::Entry;
+use std::time::Instant;
+
+use fnv::FnvHashMap;
+
+use cheetah_common::commands::field::Field;
+use cheetah_common::commands::s2c::S2CCommand;
+use cheetah_common::commands::types::transaction::{TransactionCommand, TransactionMutation};
+use cheetah_common::commands::FieldValue;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::{double, long, ServerCommandError, ServerCommandExecutor};
+use crate::room::object::GameObject;
+use crate::room::template::config::Permission;
+use crate::room::validation::apply_validation;
+use crate::room::Room;
+
+///
+/// Выполнение транзакции
+///
+/// - права доступа и предусловия проверяются до применения изменений
+/// - изменения применяются к копиям объектов, при ошибке комната остается без изменений
+/// - команды с результатами изменений отправляются после применения всех изменений
+/// - несколько изменений одного поля проверяются правилами шаблона комнаты относительно значения до транзакции
+///
+impl ServerCommandExecutor for TransactionCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		for precondition in &self.preconditions {
+			room.check_field_access(precondition.object_id, precondition.get_field(), member_id, Permission::Ro)?;
+		}
+		for mutation in &self.mutations {
+			check_mutation(room, mutation, member_id)?;
+		}
+
+		for (index, precondition) in self.preconditions.iter().enumerate() {
+			let field = precondition.get_field();
+			let current = room.get_object(precondition.object_id)?.get_field_wrapped(field.id, field.field_type);
+			if !precondition.check(current) {
+				return Err(ServerCommandError::TransactionPreconditionFailed { index });
+			}
+		}
+
+		let now = Instant::now();
+		let mut objects: FnvHashMap<GameObjectId, GameObject> = Default::default();
+		let mut commands = Vec::with_capacity(self.mutations.len());
+		// значения проверяемых полей до транзакции - несколько изменений одного поля проверяются суммарно
+		let mut validated: FnvHashMap<(GameObjectId, Field), Option<FieldValue>> = Default::default();
+		let mut violations = Vec::new();
+		for mutation in &self.mutations {
+			let object_id = mutation.get_object_id();
+			let field = mutation.get_field();
+			let object = match objects.entry(object_id) {
+				Entry::Occupied(entry) => entry.into_mut(),
+				Entry::Vacant(entry) => entry.insert(room.get_object(object_id)?.clone()),
+			};
+			let previous = room.is_field_validated(object.template_id, field, member_id).then(|| {
+				validated
+					.entry((object_id, field))
+					.or_insert_with(|| object.get_field_wrapped(field.id, field.field_type).cloned())
+					.clone()
+			});
+			let command = apply(room, object, mutation)?;
+			let command = match (previous, room.field_validator.as_ref()) {
+				(Some(previous), Some(validator)) => {
+					let (command, reported) = apply_validation(validator, object, field, previous, command, now)?;
+					if reported && !violations.contains(&(object_id, field)) {
+						violations.push((object_id, field));
+					}
+					command
+				}
+				_ => command,
+			};
+			commands.push((object_id, field, command));
+		}
+
+		for (object_id, object) in objects {
+			*room.get_object_mut(object_id)? = object;
+		}
+		if let Some(validator) = room.field_validator.as_mut() {
+			for ((object_id, field), previous) in validated {
+				if let Some(object) = room.objects.get(&object_id) {
+					validator.changed(object, field, previous.as_ref(), now);
+				}
+			}
+		}
+		for (object_id, field, command) in commands {
+			room.send_field_command(object_id, field, member_id, None, command)?;
+		}
+		for (object_id, field) in violations {
+			room.send_field_violation(member_id, object_id, field)?;
+		}
+		Ok(())
+	}
+}
+
+///
+/// Проверить права пользователя на изменение поля и ограничения нового значения
+///
+pub(crate) fn check_mutation(room: &Room, mutation: &TransactionMutation, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+	room.check_field_access(mutation.get_object_id(), mutation.get_field(), member_id, Permission::Rw)?;
+	if let TransactionMutation::SetField(command) = mutation {
+		long::check_set_field(room, command)?;
+	}
+	Ok(())
+}
+
+///
+/// Применить изменение к объекту без проверки прав доступа
+///
+pub(crate) fn apply(room: &Room, object: &mut GameObject, mutation: &TransactionMutation) -> Result<S2CCommand, ServerCommandError> {
+	match mutation {
+		TransactionMutation::SetField(command) => {
+			object.set_field_wrapped(command.field_id, command.value.clone())?;
+			Ok(S2CCommand::SetField(command.clone()))
+		}
+		TransactionMutation::IncrementLong(command) => {
+			let bounds = room.get_long_bounds(command.object_id, command.field_id);
+			long::increment_field(object, command.field_id, command.increment, bounds)
+		}
+		TransactionMutation::IncrementDouble(command) => {
+			let bounds = room.get_double_bounds(command.object_id, command.field_id);
+			double::increment_field(object, command.field_id, command.increment, bounds)
+		}
+		TransactionMutation::IncrementLongWithBounds(command) => {
+			let bounds = room.get_long_bounds_with(command.object_id, command.field_id, command.bounds)?;
+			long::increment_field(object, command.field_id, command.increment, Some(bounds))
+		}
+		TransactionMutation::IncrementDoubleWithBounds(command) => {
+			let bounds = room.get_double_bounds_with(command.object_id, command.field_id, command.bounds)?;
+			double::increment_field(object, command.field_id, command.increment, Some(bounds))
+		}
+		TransactionMutation::DeleteField(command) => {
+			object.delete_field(command.field_id, command.field_type);
+			Ok(S2CCommand::DeleteField(command.clone()))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::bounds::{Bounds, BoundsMode};
+	use cheetah_common::commands::types::field::SetFieldCommand;
+	use cheetah_common::commands::types::long::{IncrementLongC2SCommand, IncrementLongWithBoundsC2SCommand};
+	use cheetah_common::commands::types::transaction::{PreconditionKind, TransactionCommand, TransactionMutation, TransactionPrecondition};
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::object::GameObjectId;
+	use cheetah_common::room::owner::GameObjectOwner;
+	use cheetah_common::room::RoomMemberId;
+
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::template::config::{FieldBounds, FieldBoundsTemplate, MemberTemplate, RoomTemplate};
+	use crate::room::Room;
+
+	const GOLD: u16 = 1;
+	const BUILDINGS: u16 = 2;
+
+	#[test]
+	fn should_apply_all_mutations() {
+		let (mut room, member_id, object_id) = setup(100);
+		transaction(object_id, vec![spend(object_id, 50), build(object_id)])
+			.execute(&mut room, member_id)
+			.unwrap();
+
+		let object = room.get_object(object_id).unwrap();
+		assert_eq!(*object.get_field::<i64>(GOLD).unwrap(), 50);
+		assert_eq!(*object.get_field::<i64>(BUILDINGS).unwrap(), 1);
+		assert!(matches!(room.test_out_commands.pop_back(), Some((.., S2CCommand::SetField(c))) if c.field_id == GOLD));
+		assert!(matches!(room.test_out_commands.pop_back(), Some((.., S2CCommand::SetField(c))) if c.field_id == BUILDINGS));
+		assert!(room.test_out_commands.is_empty());
+	}
+
+	#[test]
+	fn should_reject_when_precondition_failed() {
+		let (mut room, member_id, object_id) = setup(10);
+		assert_eq!(
+			transaction(object_id, vec![spend(object_id, 50), build(object_id)]).execute(&mut room, member_id),
+			Err(ServerCommandError::TransactionPreconditionFailed { index: 0 })
+		);
+
+		let object = room.get_object(object_id).unwrap();
+		assert_eq!(*object.get_field::<i64>(GOLD).unwrap(), 10);
+		assert!(object.get_field::<i64>(BUILDINGS).is_none());
+		assert!(room.test_out_commands.is_empty());
+	}
+
+	#[test]
+	fn should_not_apply_any_mutation_when_one_failed() {
+		let (mut room, member_id, object_id) = setup(100);
+		let out_of_bounds = TransactionMutation::IncrementLongWithBounds(IncrementLongWithBoundsC2SCommand {
+			object_id,
+			field_id: BUILDINGS,
+			increment: 10,
+			bounds: Bounds {
+				min: 0,
+				max: 5,
+				mode: BoundsMode::Reject,
+			},
+		});
+		assert!(matches!(
+			transaction(object_id, vec![spend(object_id, 50), out_of_bounds]).execute(&mut room, member_id),
+			Err(ServerCommandError::IncrementOutOfBounds { .. })
+		));
+
+		let object = room.get_object(object_id).unwrap();
+		assert_eq!(*object.get_field::<i64>(GOLD).unwrap(), 100);
+		assert!(room.test_out_commands.is_empty());
+	}
+
+	#[test]
+	fn should_check_access_before_apply() {
+		let (mut room, member_id, object_id) = setup(100);
+		let other_object_id = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(0b100)).id;
+		assert!(matches!(
+			transaction(object_id, vec![spend(object_id, 50), build(other_object_id)]).execute(&mut room, member_id),
+			Err(ServerCommandError::MemberCannotAccessToObject { .. })
+		));
+
+		let object = room.get_object(object_id).unwrap();
+		assert_eq!(*object.get_field::<i64>(GOLD).unwrap(), 100);
+		assert!(room.test_out_commands.is_empty());
+	}
+
+	#[test]
+	fn should_not_extend_template_bounds() {
+		let (mut room, member_id, object_id) = setup_with_template(
+			100,
+			RoomTemplate {
+				field_bounds: vec![FieldBoundsTemplate {
+					template: 0,
+					field_id: BUILDINGS,
+					bounds: FieldBounds::Long(Bounds {
+						min: 0,
+						max: 5,
+						mode: BoundsMode::Reject,
+					}),
+				}],
+				..RoomTemplate::default()
+			},
+		);
+		let build_many = TransactionMutation::IncrementLongWithBounds(IncrementLongWithBoundsC2SCommand {
+			object_id,
+			field_id: BUILDINGS,
+			increment: 10,
+			bounds: Bounds {
+				min: 0,
+				max: 100,
+				mode: BoundsMode::Clamp,
+			},
+		});
+		assert!(matches!(
+			transaction(object_id, vec![spend(object_id, 50), build_many]).execute(&mut room, member_id),
+			Err(ServerCommandError::IncrementOutOfBounds { .. })
+		));
+		assert_eq!(*room.get_object(object_id).unwrap().get_field::<i64>(GOLD).unwrap(), 100);
+	}
+
+	fn setup(gold: i64) -> (Room, RoomMemberId, GameObjectId) {
+		setup_with_template(gold, RoomTemplate::default())
+	}
+
+	fn setup_with_template(gold: i64, template: RoomTemplate) -> (Room, RoomMemberId, GameObjectId) {
+		let access_groups = AccessGroups(0b11);
+		let mut room = Room::from_template(template);
+		let member_id = room.register_member(MemberTemplate::stub(access_groups));
+		let object = room.test_create_object_with_created_state(GameObjectOwner::Room, access_groups);
+		object.set_field(GOLD, gold).unwrap();
+		let object_id = object.id;
+		room.test_out_commands.clear();
+		(room, member_id, object_id)
+	}
+
+	fn transaction(object_id: GameObjectId, mutations: Vec<TransactionMutation>) -> TransactionCommand {
+		TransactionCommand {
+			preconditions: vec![TransactionPrecondition {
+				object_id,
+				field_id: GOLD,
+				kind: PreconditionKind::GreaterThan,
+				value: 49.into(),
+			}],
+			mutations,
+		}
+	}
+
+	fn spend(object_id: GameObjectId, gold: i64) -> TransactionMutation {
+		TransactionMutation::IncrementLong(IncrementLongC2SCommand {
+			object_id,
+			field_id: GOLD,
+			increment: -gold,
+		})
+	}
+
+	fn build(object_id: GameObjectId) -> TransactionMutation {
+		TransactionMutation::SetField(SetFieldCommand {
+			object_id,
+			field_id: BUILDINGS,
+			value: 1.into(),
+		})
+	}
+}
@@ -0,0 +1,163 @@
+use std::mem;
+use std::time::Instant;
+
+use cheetah_common::commands::types::ttl::SetObjectTtlCommand;
+use cheetah_common::constants::GameObjectTemplateId;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+use crate::room::Room;
+
+impl Room {
+	///
+	/// Время удаления объекта по времени жизни из шаблона комнаты
+	///
+	pub(crate) fn get_template_expire_at(&self, template: GameObjectTemplateId) -> Option<u64> {
+		if self.object_ttl.is_empty() {
+			return None;
+		}
+		let ttl = self.object_ttl.get(&template)?;
+		Some(self.get_server_time(Instant::now()).saturating_add(*ttl))
+	}
+
+	///
+	/// Изменить время удаления объекта, None - объект не удаляется по времени
+	///
+	pub(crate) fn set_object_expire_at(&mut self, object_id: GameObjectId, expire_at: Option<u64>) -> Result<(), ServerCommandError> {
+		let previous = mem::replace(&mut self.get_object_mut(object_id)?.expire_at, expire_at);
+		if let Some(previous) = previous {
+			self.objects_expiry.remove(&object_id, previous);
+		}
+		if let Some(expire_at) = expire_at {
+			self.objects_expiry.insert(object_id, expire_at);
+		}
+		Ok(())
+	}
+
+	///
+	/// Удалить объекты с истекшим временем жизни, удаление отправляется всем пользователям
+	///
+	pub(crate) fn delete_expired_objects(&mut self, now: Instant) {
+		let server_time = self.get_server_time(now);
+		for object_id in self.objects_expiry.take_expired(server_time) {
+			// объект мог быть удален вместе с родительским, либо получить новый идентификатор при создании
+			let expired = self
+				.objects
+				.get(&object_id)
+				.is_some_and(|object| object.expire_at.is_some_and(|expire_at| expire_at <= server_time));
+			if !expired {
+				continue;
+			}
+			if let Err(e) = self.delete_object_with_filter(object_id, u16::MAX, |_| true) {
+				e.log_error(self.id, u16::MAX);
+			}
+		}
+	}
+}
+
+impl ServerCommandExecutor for SetObjectTtlCommand {
+	fn execute(&self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		room.get_member(&member_id)?;
+		room.check_object_owner(self.object_id, member_id)?;
+		let expire_at = (self.ttl > 0).then(|| room.get_server_time(Instant::now()).saturating_add(self.ttl));
+		room.set_object_expire_at(self.object_id, expire_at)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{Duration, Instant};
+
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::create::CreateGameObjectCommand;
+	use cheetah_common::commands::types::ttl::SetObjectTtlCommand;
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::object::GameObjectId;
+	use cheetah_common::room::owner::GameObjectOwner;
+
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::template::config::{MemberTemplate, ObjectTtlTemplate, RoomTemplate};
+	use crate::room::Room;
+
+	const TEMPLATE: u16 = 5;
+
+	#[test]
+	fn should_set_ttl_from_template() {
+		let template = RoomTemplate {
+			object_ttl: vec![ObjectTtlTemplate {
+				template: TEMPLATE,
+				ttl: Duration::from_millis(10_000),
+			}],
+			..Default::default()
+		};
+		let mut room = Room::from_template(template);
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		let object_id = GameObjectId::new(1, GameObjectOwner::Member(member_id));
+		CreateGameObjectCommand {
+			object_id,
+			template: TEMPLATE,
+			access_groups: AccessGroups(10),
+			parent: None,
+			inherit_access_groups: false,
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+
+		assert!(room.get_object(object_id).unwrap().expire_at.is_some_and(|expire_at| expire_at >= 10_000));
+	}
+
+	#[test]
+	fn should_delete_expired_object() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_id), AccessGroups(10))
+			.id;
+		room.set_object_expire_at(object_id, Some(1000)).unwrap();
+		room.test_out_commands.clear();
+
+		room.delete_expired_objects(room.start_application_time + Duration::from_millis(999));
+		assert!(room.contains_object(&object_id));
+
+		room.delete_expired_objects(room.start_application_time + Duration::from_millis(1000));
+		assert!(!room.contains_object(&object_id));
+		assert!(matches!(room.test_get_member_out_commands(member_id).pop_back(), Some(S2CCommand::Delete(c)) if c.object_id == object_id));
+	}
+
+	#[test]
+	fn should_refresh_ttl() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_id), AccessGroups(10))
+			.id;
+		room.set_object_expire_at(object_id, Some(0)).unwrap();
+
+		SetObjectTtlCommand { object_id, ttl: 60_000 }.execute(&mut room, member_id).unwrap();
+		room.delete_expired_objects(Instant::now());
+		assert!(room.get_object(object_id).unwrap().expire_at.is_some_and(|expire_at| expire_at >= 60_000));
+
+		SetObjectTtlCommand { object_id, ttl: 0 }.execute(&mut room, member_id).unwrap();
+		assert_eq!(room.get_object(object_id).unwrap().expire_at, None);
+	}
+
+	#[test]
+	fn should_not_set_ttl_if_not_owner() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_a = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		let member_b = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_a), AccessGroups(10))
+			.id;
+
+		assert_eq!(
+			SetObjectTtlCommand { object_id, ttl: 100 }.execute(&mut room, member_b),
+			Err(ServerCommandError::MemberNotOwnerGameObject {
+				object_id,
+				member_id: member_b
+			})
+		);
+	}
+}
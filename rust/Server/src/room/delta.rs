@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use fnv::FnvBuildHasher;
+
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::s2c::S2CCommand;
+use cheetah_common::commands::types::field::SetFieldCommand;
+use cheetah_common::commands::types::structure::SetStructureDeltaCommand;
+use cheetah_common::commands::{FieldType, FieldValue};
+use cheetah_common::protocol::frame::applications::ChannelGroup;
+use cheetah_common::protocol::frame::channel::ChannelType;
+use cheetah_common::room::object::GameObjectId;
+
+///
+/// Замена полных значений структур на разницу с последним значением, отправленным пользователю
+///
+/// Разница отправляется только в канале `ReliableSequence` той же группы, что и базовое значение -
+/// канал гарантирует доставку и порядок выполнения, поэтому клиент применит разницу после получения базового значения.
+/// Отправка в любом другом канале сбрасывает базовое значение - следующее изменение уйдет полностью.
+///
+#[derive(Debug, Default)]
+pub struct StructureDeltaEncoder {
+	values: HashMap<(GameObjectId, FieldId), (ChannelGroup, BinaryValue), FnvBuildHasher>,
+}
+
+impl StructureDeltaEncoder {
+	#[must_use]
+	pub fn encode(&mut self, channel_type: ChannelType, command: S2CCommand) -> S2CCommand {
+		match command {
+			S2CCommand::SetField(SetFieldCommand {
+				object_id,
+				field_id,
+				value: FieldValue::Structure(value),
+			}) => {
+				let key = (object_id, field_id);
+				let group = match channel_type {
+					ChannelType::ReliableSequence(group) => group,
+					_ => {
+						self.values.remove(&key);
+						return command;
+					}
+				};
+				let delta = match self.values.get(&key) {
+					Some((base_group, base)) if *base_group == group => SetStructureDeltaCommand::new(object_id, field_id, base, &value),
+					_ => None,
+				};
+				self.values.insert(key, (group, value));
+				match delta {
+					None => command,
+					Some(delta) => S2CCommand::SetStructureDelta(delta),
+				}
+			}
+			S2CCommand::DeleteField(ref delete) if delete.field_type == FieldType::Structure => {
+				self.values.remove(&(delete.object_id, delete.field_id));
+				command
+			}
+			S2CCommand::Delete(ref delete) => {
+				self.values.retain(|(object_id, _), _| *object_id != delete.object_id);
+				command
+			}
+			_ => command,
+		}
+	}
+
+	///
+	/// Клиент заново загружает объекты комнаты - все значения будут отправлены полностью
+	///
+	pub fn clear(&mut self) {
+		self.values.clear();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::binary_value::BinaryValue;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::delete::DeleteGameObjectCommand;
+	use cheetah_common::protocol::frame::applications::ChannelGroup;
+	use cheetah_common::protocol::frame::channel::ChannelType;
+	use cheetah_common::room::object::GameObjectId;
+
+	use crate::room::delta::StructureDeltaEncoder;
+
+	const VALUE: [u8; 10] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+	const CHANGED_VALUE: [u8; 10] = [1, 2, 3, 4, 100, 6, 7, 8, 9, 10];
+
+	#[test]
+	fn should_send_delta_after_full_value() {
+		let mut encoder = StructureDeltaEncoder::default();
+		let channel = ChannelType::ReliableSequence(ChannelGroup(0));
+		assert!(matches!(encoder.encode(channel, set(&VALUE)), S2CCommand::SetField(_)));
+		let delta = encoder.encode(channel, set(&CHANGED_VALUE));
+		assert!(
+			matches!(delta, S2CCommand::SetStructureDelta(ref c) if c.apply(&VALUE.as_slice().into()).unwrap() == CHANGED_VALUE.as_slice().into())
+		);
+	}
+
+	#[test]
+	fn should_send_full_value_after_unreliable_channel() {
+		let mut encoder = StructureDeltaEncoder::default();
+		let channel = ChannelType::ReliableSequence(ChannelGroup(0));
+		assert!(matches!(encoder.encode(channel, set(&VALUE)), S2CCommand::SetField(_)));
+		assert!(matches!(
+			encoder.encode(ChannelType::UnreliableUnordered, set(&VALUE)),
+			S2CCommand::SetField(_)
+		));
+		assert!(matches!(encoder.encode(channel, set(&CHANGED_VALUE)), S2CCommand::SetField(_)));
+	}
+
+	#[test]
+	fn should_send_full_value_for_other_channel_group() {
+		let mut encoder = StructureDeltaEncoder::default();
+		assert!(matches!(
+			encoder.encode(ChannelType::ReliableSequence(ChannelGroup(0)), set(&VALUE)),
+			S2CCommand::SetField(_)
+		));
+		assert!(matches!(
+			encoder.encode(ChannelType::ReliableSequence(ChannelGroup(1)), set(&CHANGED_VALUE)),
+			S2CCommand::SetField(_)
+		));
+	}
+
+	#[test]
+	fn should_send_full_value_after_delete_object() {
+		let mut encoder = StructureDeltaEncoder::default();
+		let channel = ChannelType::ReliableSequence(ChannelGroup(0));
+		assert!(matches!(encoder.encode(channel, set(&VALUE)), S2CCommand::SetField(_)));
+		let _ = encoder.encode(
+			channel,
+			S2CCommand::Delete(DeleteGameObjectCommand {
+				object_id: GameObjectId::default(),
+			}),
+		);
+		assert!(matches!(encoder.encode(channel, set(&CHANGED_VALUE)), S2CCommand::SetField(_)));
+	}
+
+	fn set(value: &[u8]) -> S2CCommand {
+		S2CCommand::new_set_command(BinaryValue::from(value).into(), GameObjectId::default(), 1)
+	}
+}
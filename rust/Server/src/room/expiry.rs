@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use fnv::FnvHashSet;
+
+use cheetah_common::room::object::GameObjectId;
+
+///
+/// Объекты с ограниченным временем жизни, упорядоченные по времени удаления
+///
+#[derive(Debug, Default)]
+pub struct ObjectsExpiry {
+	objects: BTreeMap<u64, FnvHashSet<GameObjectId>>,
+}
+
+impl ObjectsExpiry {
+	pub fn insert(&mut self, object_id: GameObjectId, expire_at: u64) {
+		self.objects.entry(expire_at).or_default().insert(object_id);
+	}
+
+	pub fn remove(&mut self, object_id: &GameObjectId, expire_at: u64) {
+		if let Some(objects) = self.objects.get_mut(&expire_at) {
+			objects.remove(object_id);
+			if objects.is_empty() {
+				self.objects.remove(&expire_at);
+			}
+		}
+	}
+
+	///
+	/// Извлечь объекты с истекшим временем жизни в порядке времени удаления
+	///
+	pub fn take_expired(&mut self, server_time: u64) -> Vec<GameObjectId> {
+		let mut expired = Vec::new();
+		while let Some(entry) = self.objects.first_entry() {
+			if *entry.key() > server_time {
+				break;
+			}
+			expired.extend(entry.remove());
+		}
+		expired
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::room::object::GameObjectId;
+	use cheetah_common::room::owner::GameObjectOwner;
+
+	use crate::room::expiry::ObjectsExpiry;
+
+	#[test]
+	fn should_take_expired_in_order() {
+		let mut expiry = ObjectsExpiry::default();
+		expiry.insert(object_id(1), 300);
+		expiry.insert(object_id(2), 100);
+		expiry.insert(object_id(3), 200);
+
+		assert_eq!(expiry.take_expired(250), vec![object_id(2), object_id(3)]);
+		assert!(expiry.take_expired(250).is_empty());
+		assert_eq!(expiry.take_expired(300), vec![object_id(1)]);
+	}
+
+	#[test]
+	fn should_remove() {
+		let mut expiry = ObjectsExpiry::default();
+		expiry.insert(object_id(1), 100);
+		expiry.remove(&object_id(1), 100);
+		assert!(expiry.take_expired(100).is_empty());
+		assert!(expiry.objects.is_empty());
+	}
+
+	fn object_id(id: u32) -> GameObjectId {
+		GameObjectId::new(id, GameObjectOwner::Room)
+	}
+}
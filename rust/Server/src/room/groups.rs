@@ -0,0 +1,147 @@
+use std::mem;
+
+use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::owner::GameObjectOwner;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::ServerCommandError;
+use crate::room::Room;
+
+impl Room {
+	///
+	/// Изменить группы доступа пользователя без его пересоздания
+	///
+	/// - пользователю отправляется создание объектов, ставших доступными, и удаление объектов, ставших недоступными;
+	/// - для объектов, оставшихся доступными, отправляются поля, ставшие доступными на чтение, и удаление недоступных;
+	/// - объекты с позицией загружаются при пересчете области интереса.
+	///
+	pub(crate) fn update_member_groups(&mut self, member_id: RoomMemberId, groups: AccessGroups) -> Result<(), ServerCommandError> {
+		let member = self.get_member_mut(&member_id)?;
+		let old_groups = mem::replace(&mut member.template.groups, groups);
+		let attached = member.attached;
+		self.permission_manager.borrow().clear_cache();
+		if let Some(interest) = self.interest.as_mut() {
+			interest.mark_member_dirty(member_id);
+		}
+		if !attached || old_groups == groups {
+			return Ok(());
+		}
+
+		let interest = self.interest.as_ref();
+		let mut resync = self.collect_objects_resync(
+			member_id,
+			|o| {
+				!interest.is_some_and(|interest| interest.is_positional(o.template_id))
+					&& o.access_groups.contains_any(&old_groups) != o.access_groups.contains_any(&groups)
+			},
+			|o| o.access_groups.contains_any(&groups),
+		);
+		resync.send_deletes(self, member_id)?;
+		resync.send_creates(self, member_id)?;
+
+		let kept: Vec<_> = self
+			.objects
+			.values()
+			.filter(|o| o.created && o.access_groups.contains_any(&old_groups) && o.access_groups.contains_any(&groups))
+			.map(|o| (o.id, o.owner == GameObjectOwner::Member(member_id)))
+			.collect();
+		for (object_id, is_owner) in kept {
+			self.resync_object_fields(member_id, object_id, old_groups, is_owner)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::field::Field;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::{FieldType, FieldValue};
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::owner::GameObjectOwner;
+
+	use crate::room::template::config::{
+		GameObjectTemplatePermission, GroupsPermissionRule, MemberTemplate, Permission, PermissionField, Permissions, RoomTemplate,
+	};
+	use crate::room::Room;
+
+	#[test]
+	fn should_resync_objects_when_update_member_groups() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(0b01)));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object_a_id = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(0b01)).id;
+		let object_b_id = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(0b10)).id;
+		let object_ab_id = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(0b11)).id;
+
+		room.update_member_groups(member_id, AccessGroups(0b10)).unwrap();
+
+		let commands = room.test_get_member_out_commands(member_id);
+		assert!(matches!(commands.get(0), Some(S2CCommand::Delete(c)) if c.object_id == object_a_id));
+		assert!(matches!(commands.get(1), Some(S2CCommand::Create(c)) if c.object_id == object_b_id));
+		assert!(matches!(commands.get(2), Some(S2CCommand::Created(c)) if c.object_id == object_b_id));
+		assert!(!commands.iter().any(|c| matches!(c, S2CCommand::Create(c) if c.object_id == object_ab_id)));
+		assert_eq!(room.get_member(&member_id).unwrap().template.groups, AccessGroups(0b10));
+	}
+
+	///
+	/// Для объекта, оставшегося доступным, пересинхронизируются поля с изменившимися правами на чтение
+	///
+	#[test]
+	fn should_resync_fields_when_update_member_groups() {
+		let hidden = Field {
+			id: 1,
+			field_type: FieldType::Long,
+		};
+		let revealed = Field {
+			id: 2,
+			field_type: FieldType::Long,
+		};
+		let deny_for = |field, groups| PermissionField {
+			field,
+			rules: vec![GroupsPermissionRule {
+				groups,
+				permission: Permission::Deny,
+			}],
+		};
+		let mut room = Room::from_template(RoomTemplate {
+			permissions: Permissions {
+				templates: vec![GameObjectTemplatePermission {
+					template: 0,
+					rules: Default::default(),
+					fields: vec![deny_for(hidden, AccessGroups(0b10)), deny_for(revealed, AccessGroups(0b01))],
+				}],
+				..Default::default()
+			},
+			..Default::default()
+		});
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(0b01)));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(0b11));
+		object.set_field(hidden.id, 1).unwrap();
+		object.set_field(revealed.id, 2).unwrap();
+		let object_id = object.id;
+
+		room.update_member_groups(member_id, AccessGroups(0b10)).unwrap();
+
+		let commands = room.test_get_member_out_commands(member_id);
+		assert_eq!(commands.len(), 2);
+		assert!(commands
+			.iter()
+			.any(|c| matches!(c, S2CCommand::DeleteField(c) if c.object_id == object_id && c.field_id == hidden.id)));
+		assert!(commands.iter().any(
+			|c| matches!(c, S2CCommand::SetField(c) if c.object_id == object_id && c.field_id == revealed.id && c.value == FieldValue::Long(2))
+		));
+	}
+
+	#[test]
+	fn should_not_send_when_update_groups_of_not_attached_member() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(0b01)));
+		room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(0b10));
+
+		room.update_member_groups(member_id, AccessGroups(0b10)).unwrap();
+
+		assert!(room.test_get_member_out_commands(member_id).is_empty());
+	}
+}
@@ -0,0 +1,270 @@
+use std::collections::VecDeque;
+
+use fnv::FnvHashMap;
+
+use cheetah_common::commands::field::{Field, FieldId};
+use cheetah_common::commands::{FieldType, FieldValue};
+use cheetah_common::constants::GameObjectTemplateId;
+use cheetah_common::room::object::GameObjectId;
+
+use crate::room::object::GameObject;
+use crate::room::template::config::FieldHistoryTemplate;
+use crate::room::Room;
+
+///
+/// История значений полей объектов за заданное в шаблоне комнаты время
+///
+#[derive(Debug, Default)]
+pub struct FieldHistory {
+	///
+	/// Глубина истории в миллисекундах по шаблонам объектов
+	///
+	depths: FnvHashMap<(GameObjectTemplateId, FieldId, FieldType), u64>,
+	///
+	/// Значения полей с временем сервера установки, упорядоченные по времени
+	///
+	samples: FnvHashMap<(GameObjectId, FieldId, FieldType), VecDeque<(u64, FieldValue)>>,
+}
+
+impl FieldHistory {
+	#[must_use]
+	pub fn new(templates: &[FieldHistoryTemplate]) -> Self {
+		Self {
+			depths: templates
+				.iter()
+				.map(|template| {
+					(
+						(template.template, template.field.id, template.field.field_type),
+						template.depth.as_millis().try_into().unwrap_or(u64::MAX),
+					)
+				})
+				.collect(),
+			samples: Default::default(),
+		}
+	}
+
+	///
+	/// Сохранить текущие значения всех полей объекта, для которых ведется история
+	///
+	pub fn record_object(&mut self, object: &GameObject, time: u64) {
+		let fields: Vec<_> = self
+			.depths
+			.keys()
+			.filter(|(template, ..)| *template == object.template_id)
+			.map(|(_, id, field_type)| Field {
+				id: *id,
+				field_type: *field_type,
+			})
+			.collect();
+		for field in fields {
+			self.record(object, field, time);
+		}
+	}
+
+	///
+	/// Сохранить текущее значение поля объекта, значения старше глубины истории удаляются,
+	/// при этом сохраняется последнее из них - оно действует до следующего значения
+	///
+	pub fn record(&mut self, object: &GameObject, field: Field, time: u64) {
+		let Some(depth) = self.depths.get(&(object.template_id, field.id, field.field_type)) else {
+			return;
+		};
+		let Some(value) = object.get_field_wrapped(field.id, field.field_type) else {
+			return;
+		};
+		let cutoff = time.saturating_sub(*depth);
+		let samples = self.samples.entry((object.id, field.id, field.field_type)).or_default();
+		match samples.back_mut() {
+			Some((_, last)) if *last == *value => {}
+			Some((last_time, last)) if *last_time >= time => {
+				last.clone_from(value);
+			}
+			_ => samples.push_back((time, value.clone())),
+		}
+		while samples.len() > 1 && samples[1].0 <= cutoff {
+			samples.pop_front();
+		}
+	}
+
+	///
+	/// Значение поля в момент времени сервера, для полей типа double значение интерполируется между соседними значениями,
+	/// None - если поле не отслеживается или время раньше первого сохраненного значения
+	///
+	#[must_use]
+	pub fn get(&self, object_id: GameObjectId, field: Field, time: u64) -> Option<FieldValue> {
+		let samples = self.samples.get(&(object_id, field.id, field.field_type))?;
+		let index = samples.partition_point(|(sample_time, _)| *sample_time <= time);
+		let (from_time, from) = samples.get(index.checked_sub(1)?)?;
+		match (from, samples.get(index)) {
+			(FieldValue::Double(from), Some((to_time, FieldValue::Double(to)))) => {
+				#[allow(clippy::cast_precision_loss)]
+				let ratio = (time - from_time) as f64 / (to_time - from_time) as f64;
+				Some(FieldValue::Double(from + (to - from) * ratio))
+			}
+			_ => Some(from.clone()),
+		}
+	}
+
+	pub fn remove_object(&mut self, object_id: &GameObjectId) {
+		self.samples.retain(|(id, ..), _| id != object_id);
+	}
+}
+
+impl Room {
+	///
+	/// Сохранить текущее значение поля объекта в истории, если для поля она ведется
+	///
+	pub(crate) fn record_field_history(&mut self, object_id: GameObjectId, field: Field) {
+		let time = self.get_server_time(self.get_current_time());
+		if let (Some(history), Some(object)) = (self.field_history.as_mut(), self.objects.get(&object_id)) {
+			history.record(object, field, time);
+		}
+	}
+
+	///
+	/// Значение поля объекта в момент времени сервера по истории значений
+	///
+	#[must_use]
+	pub fn get_field_history_value(&self, object_id: GameObjectId, field: Field, time: u64) -> Option<FieldValue> {
+		self.field_history.as_ref().and_then(|history| history.get(object_id, field, time))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use cheetah_common::commands::field::Field;
+	use cheetah_common::commands::{FieldType, FieldValue};
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::object::GameObjectId;
+	use cheetah_common::room::owner::GameObjectOwner;
+
+	use crate::room::history::FieldHistory;
+	use crate::room::object::GameObject;
+	use crate::room::template::config::{FieldHistoryTemplate, RoomTemplate};
+	use crate::room::Room;
+
+	const SCORE: Field = Field {
+		id: 1,
+		field_type: FieldType::Long,
+	};
+	const POSITION: Field = Field {
+		id: 2,
+		field_type: FieldType::Double,
+	};
+
+	#[test]
+	fn should_interpolate_double() {
+		let (mut history, mut object) = setup(1000);
+		set(&mut history, &mut object, POSITION, 10.0, 100);
+		set(&mut history, &mut object, POSITION, 20.0, 200);
+
+		assert_eq!(history.get(object.id, POSITION, 50), None);
+		assert_eq!(history.get(object.id, POSITION, 100), Some(FieldValue::Double(10.0)));
+		assert_eq!(history.get(object.id, POSITION, 125), Some(FieldValue::Double(12.5)));
+		assert_eq!(history.get(object.id, POSITION, 200), Some(FieldValue::Double(20.0)));
+		assert_eq!(history.get(object.id, POSITION, 500), Some(FieldValue::Double(20.0)));
+	}
+
+	#[test]
+	fn should_not_interpolate_long() {
+		let (mut history, mut object) = setup(1000);
+		set(&mut history, &mut object, SCORE, 10, 100);
+		set(&mut history, &mut object, SCORE, 20, 200);
+
+		assert_eq!(history.get(object.id, SCORE, 150), Some(FieldValue::Long(10)));
+		assert_eq!(history.get(object.id, SCORE, 200), Some(FieldValue::Long(20)));
+	}
+
+	#[test]
+	fn should_remove_values_older_than_depth() {
+		let (mut history, mut object) = setup(100);
+		set(&mut history, &mut object, SCORE, 1, 0);
+		set(&mut history, &mut object, SCORE, 2, 50);
+		set(&mut history, &mut object, SCORE, 3, 100);
+		set(&mut history, &mut object, SCORE, 4, 200);
+
+		// значение на момент 100 действует до 200 и остается в истории
+		assert_eq!(history.get(object.id, SCORE, 99), None);
+		assert_eq!(history.get(object.id, SCORE, 100), Some(FieldValue::Long(3)));
+		assert_eq!(history.get(object.id, SCORE, 150), Some(FieldValue::Long(3)));
+		assert_eq!(history.samples.values().next().unwrap().len(), 2);
+	}
+
+	#[test]
+	fn should_replace_value_with_same_time() {
+		let (mut history, mut object) = setup(1000);
+		set(&mut history, &mut object, SCORE, 1, 100);
+		set(&mut history, &mut object, SCORE, 2, 100);
+		set(&mut history, &mut object, SCORE, 2, 150);
+
+		assert_eq!(history.get(object.id, SCORE, 100), Some(FieldValue::Long(2)));
+		assert_eq!(history.samples.values().next().unwrap().len(), 1);
+	}
+
+	#[test]
+	fn should_ignore_not_tracked_fields() {
+		let (mut history, mut object) = setup(1000);
+		let field = Field {
+			id: 10,
+			field_type: FieldType::Long,
+		};
+		set(&mut history, &mut object, field, 1, 100);
+		assert_eq!(history.get(object.id, field, 100), None);
+	}
+
+	#[test]
+	fn should_record_object_and_remove() {
+		let (mut history, mut object) = setup(1000);
+		object.set_field(SCORE.id, 7).unwrap();
+		object.set_field(POSITION.id, 1.5).unwrap();
+		history.record_object(&object, 100);
+		assert_eq!(history.get(object.id, SCORE, 100), Some(FieldValue::Long(7)));
+		assert_eq!(history.get(object.id, POSITION, 100), Some(FieldValue::Double(1.5)));
+
+		history.remove_object(&object.id);
+		assert_eq!(history.get(object.id, SCORE, 100), None);
+	}
+
+	#[test]
+	fn should_record_with_command_time() {
+		let mut room = Room::from_template(RoomTemplate {
+			field_history: vec![FieldHistoryTemplate {
+				template: 0,
+				field: SCORE,
+				depth: Duration::from_secs(10),
+			}],
+			..Default::default()
+		});
+		let object = room.test_create_object_with_created_state(GameObjectOwner::Room, AccessGroups(1));
+		object.template_id = 0;
+		object.set_field(SCORE.id, 7).unwrap();
+		let object_id = object.id;
+
+		room.current_time = Some(room.start_application_time + Duration::from_millis(1500));
+		room.record_field_history(object_id, SCORE);
+		room.current_time = None;
+
+		assert_eq!(room.get_field_history_value(object_id, SCORE, 1499), None);
+		assert_eq!(room.get_field_history_value(object_id, SCORE, 1500), Some(FieldValue::Long(7)));
+	}
+
+	fn setup(depth_ms: u64) -> (FieldHistory, GameObject) {
+		let templates: Vec<_> = [SCORE, POSITION]
+			.into_iter()
+			.map(|field| FieldHistoryTemplate {
+				template: 0,
+				field,
+				depth: Duration::from_millis(depth_ms),
+			})
+			.collect();
+		let object = GameObject::new(GameObjectId::new(1, GameObjectOwner::Room), 0, AccessGroups(1), true);
+		(FieldHistory::new(&templates), object)
+	}
+
+	fn set<T: Into<FieldValue>>(history: &mut FieldHistory, object: &mut GameObject, field: Field, value: T, time: u64) {
+		object.set_field_wrapped(field.id, value.into()).unwrap();
+		history.record(object, field, time);
+	}
+}
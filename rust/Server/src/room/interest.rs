@@ -0,0 +1,483 @@
+use std::mem;
+
+use fnv::{FnvHashMap, FnvHashSet};
+
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+use cheetah_common::commands::types::delete::DeleteGameObjectCommand;
+use cheetah_common::constants::GameObjectTemplateId;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::owner::GameObjectOwner;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::ServerCommandError;
+use crate::room::object::{CreateCommandsCollector, GameObject};
+use crate::room::template::config::InterestTemplate;
+use crate::room::Room;
+
+type Cell = (i64, i64);
+
+///
+/// Область интереса пользователей - объекты с позицией загружаются пользователю только рядом с его объектами с позицией
+///
+#[derive(Debug)]
+pub struct Interest {
+	cell_size: f64,
+	view_radius: u64,
+	positions: FnvHashMap<GameObjectTemplateId, (FieldId, FieldId)>,
+	///
+	/// Объекты с позицией, загруженные пользователю
+	///
+	visible: FnvHashMap<RoomMemberId, FnvHashSet<GameObjectId>>,
+	///
+	/// Объекты с позицией, у которых изменились позиция, владелец или группы доступа - их видимость необходимо пересчитать
+	///
+	dirty_objects: FnvHashSet<GameObjectId>,
+	///
+	/// Пользователи, область интереса которых необходимо пересчитать полностью
+	///
+	dirty_members: FnvHashSet<RoomMemberId>,
+}
+
+impl Interest {
+	#[must_use]
+	pub fn new(template: &InterestTemplate) -> Self {
+		Self {
+			cell_size: template.cell_size,
+			view_radius: u64::from(template.view_radius),
+			positions: template
+				.positions
+				.iter()
+				.map(|position| (position.template, (position.x_field_id, position.y_field_id)))
+				.collect(),
+			visible: Default::default(),
+			dirty_objects: Default::default(),
+			dirty_members: Default::default(),
+		}
+	}
+
+	#[must_use]
+	pub fn is_positional(&self, template: GameObjectTemplateId) -> bool {
+		self.positions.contains_key(&template)
+	}
+
+	///
+	/// Можно ли отправлять пользователю команды объекта
+	///
+	#[must_use]
+	pub fn is_visible(&self, member_id: RoomMemberId, template: GameObjectTemplateId, object_id: &GameObjectId) -> bool {
+		!self.is_positional(template) || self.visible.get(&member_id).is_some_and(|visible| visible.contains(object_id))
+	}
+
+	///
+	/// Ячейка сетки объекта, отсутствующие и бесконечные координаты или NaN считаются нулевыми
+	///
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)]
+	pub fn get_cell(&self, object: &GameObject) -> Option<Cell> {
+		let (x_field_id, y_field_id) = self.positions.get(&object.template_id)?;
+		let coordinate = |field_id| {
+			object
+				.get_field::<f64>(field_id)
+				.copied()
+				.filter(|value| value.is_finite())
+				.unwrap_or_default()
+		};
+		let (x, y) = (coordinate(*x_field_id), coordinate(*y_field_id));
+		Some(((x / self.cell_size).floor() as i64, (y / self.cell_size).floor() as i64))
+	}
+
+	#[must_use]
+	pub fn is_near(&self, a: Cell, b: Cell) -> bool {
+		a.0.abs_diff(b.0) <= self.view_radius && a.1.abs_diff(b.1) <= self.view_radius
+	}
+
+	pub fn mark_object_dirty(&mut self, object_id: GameObjectId) {
+		self.dirty_objects.insert(object_id);
+	}
+
+	pub fn mark_member_dirty(&mut self, member_id: RoomMemberId) {
+		self.dirty_members.insert(member_id);
+	}
+
+	///
+	/// Отметить объекты, созданные или изменившие позицию или владельца в результате отправленных команд
+	///
+	pub fn track_commands(&mut self, template: GameObjectTemplateId, commands: &[S2CCommandWithMeta]) {
+		let Some(&(x_field_id, y_field_id)) = self.positions.get(&template) else {
+			return;
+		};
+		let is_position = |field_id| field_id == x_field_id || field_id == y_field_id;
+		for command in commands {
+			let changed = match &command.command {
+				S2CCommand::Create(_) | S2CCommand::Created(_) | S2CCommand::TransferOwnership(_) => true,
+				S2CCommand::SetField(command) => is_position(command.field_id),
+				S2CCommand::DeleteField(command) => is_position(command.field_id),
+				_ => false,
+			};
+			if let (true, Some(object_id)) = (changed, command.command.get_object_id()) {
+				self.dirty_objects.insert(object_id);
+			}
+		}
+	}
+
+	pub fn set_visible(&mut self, member_id: RoomMemberId, object_id: GameObjectId) {
+		self.visible.entry(member_id).or_default().insert(object_id);
+	}
+
+	pub fn remove_object(&mut self, object_id: &GameObjectId, template: GameObjectTemplateId, owner: GameObjectOwner) {
+		for visible in self.visible.values_mut() {
+			visible.remove(object_id);
+		}
+		self.dirty_objects.remove(object_id);
+		// объект с позицией определял область интереса владельца
+		if let (true, GameObjectOwner::Member(owner)) = (self.is_positional(template), owner) {
+			self.dirty_members.insert(owner);
+		}
+	}
+
+	pub fn remove_member(&mut self, member_id: RoomMemberId) {
+		self.visible.remove(&member_id);
+		self.dirty_members.insert(member_id);
+	}
+}
+
+impl Room {
+	///
+	/// Пересчитать области интереса, пользователю отправляется создание вошедших в область объектов и удаление вышедших
+	///
+	/// - для пользователей, у которых изменились группы, подключение или собственные объекты с позицией, область
+	///   пересчитывается полностью;
+	/// - для остальных пользователей пересчитывается только видимость измененных объектов.
+	///
+	pub(crate) fn update_interest(&mut self) {
+		let Some(interest) = self.interest.as_mut() else {
+			return;
+		};
+		if interest.dirty_objects.is_empty() && interest.dirty_members.is_empty() {
+			return;
+		}
+		let dirty_objects = mem::take(&mut interest.dirty_objects);
+		let mut dirty_members = mem::take(&mut interest.dirty_members);
+		let interest = self.interest.as_ref().expect("interest must exist");
+
+		let objects: FnvHashMap<_, _> = self
+			.objects
+			.values()
+			.filter(|object| object.created)
+			.filter_map(|object| interest.get_cell(object).map(|cell| (object.id, (object, cell))))
+			.collect();
+		let mut centers: FnvHashMap<RoomMemberId, Vec<Cell>> = FnvHashMap::default();
+		for (object, cell) in objects.values() {
+			if let GameObjectOwner::Member(owner) = object.owner {
+				centers.entry(owner).or_default().push(*cell);
+			}
+		}
+		// изменение объекта пользователя меняет его область интереса целиком
+		dirty_members.extend(dirty_objects.iter().filter_map(|object_id| match objects.get(object_id)?.0.owner {
+			GameObjectOwner::Member(owner) => Some(owner),
+			GameObjectOwner::Room => None,
+		}));
+
+		let mut changes = Vec::new();
+		for member in self.members.values().filter(|member| member.attached && member.connected) {
+			let centers = centers.get(&member.id).map_or(&[][..], Vec::as_slice);
+			let see_all = member.template.super_member || centers.is_empty();
+			let is_interested = |object_id: &GameObjectId| {
+				objects.get(object_id).is_some_and(|(object, cell)| {
+					object.access_groups.contains_any(&member.template.groups)
+						&& (see_all || centers.iter().any(|center| interest.is_near(*center, *cell)))
+				})
+			};
+			let visible = interest.visible.get(&member.id);
+			let is_visible = |object_id: &GameObjectId| visible.is_some_and(|visible| visible.contains(object_id));
+			let (entered, left): (Vec<_>, Vec<_>) = if dirty_members.contains(&member.id) {
+				let interested: FnvHashSet<_> = objects.keys().filter(|object_id| is_interested(object_id)).copied().collect();
+				(
+					interested.iter().filter(|object_id| !is_visible(object_id)).copied().collect(),
+					visible
+						.into_iter()
+						.flatten()
+						.filter(|object_id| !interested.contains(object_id))
+						.copied()
+						.collect(),
+				)
+			} else {
+				(
+					dirty_objects
+						.iter()
+						.filter(|object_id| !is_visible(object_id) && is_interested(object_id))
+						.copied()
+						.collect(),
+					dirty_objects
+						.iter()
+						.filter(|object_id| is_visible(object_id) && !is_interested(object_id))
+						.copied()
+						.collect(),
+				)
+			};
+			if !entered.is_empty() || !left.is_empty() {
+				changes.push((member.id, entered, left));
+			}
+		}
+
+		for (member_id, entered, left) in changes {
+			if let Err(e) = self.apply_interest_changes(member_id, &entered, &left) {
+				e.log_error(self.id, member_id);
+			}
+		}
+	}
+
+	fn apply_interest_changes(&mut self, member_id: RoomMemberId, entered: &[GameObjectId], left: &[GameObjectId]) -> Result<(), ServerCommandError> {
+		for object_id in left {
+			let template = self.get_object(*object_id)?.template_id;
+			let command = S2CCommandWithMeta {
+				field: None,
+				creator: u16::MAX,
+				command: S2CCommand::Delete(DeleteGameObjectCommand { object_id: *object_id }),
+			};
+			self.send_to_member(&member_id, template, &[command])?;
+			if let Some(visible) = self.interest.as_mut().and_then(|interest| interest.visible.get_mut(&member_id)) {
+				visible.remove(object_id);
+			}
+		}
+		for object_id in entered {
+			if let Some(interest) = self.interest.as_mut() {
+				interest.set_visible(member_id, *object_id);
+			}
+			let object = self.get_object(*object_id)?;
+			let template = object.template_id;
+			let mut commands = CreateCommandsCollector::new();
+			object.collect_create_commands(&mut commands, u16::MAX);
+			self.send_to_member(&member_id, template, commands.as_slice())?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::field::SetFieldCommand;
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::object::GameObjectId;
+	use cheetah_common::room::owner::GameObjectOwner;
+	use cheetah_common::room::RoomMemberId;
+
+	use crate::room::command::room::{attach_to_room, detach_from_room};
+	use crate::room::command::ServerCommandExecutor;
+	use crate::room::template::config::{InterestTemplate, MemberTemplate, PositionFieldsTemplate, RoomTemplate};
+	use crate::room::Room;
+
+	const ACCESS_GROUPS: AccessGroups = AccessGroups(10);
+	const TEMPLATE: u16 = 5;
+	const X: u16 = 1;
+	const Y: u16 = 2;
+
+	#[test]
+	fn should_send_only_near_objects() {
+		let (mut room, member_a, member_b) = setup();
+		let avatar_a = create_object(&mut room, member_a, 0.0, 0.0);
+		let avatar_b = create_object(&mut room, member_b, 1000.0, 1000.0);
+		let near = create_object(&mut room, member_b, 15.0, 0.0);
+		room.update_interest();
+
+		let commands = take_commands(&mut room, member_a);
+		assert!(has_create(&commands, avatar_a));
+		assert!(has_create(&commands, near));
+		assert!(!has_create(&commands, avatar_b));
+
+		// изменения поля объекта вне области интереса не отправляются
+		set_position(&mut room, member_b, avatar_b, 1000.0, 900.0);
+		assert!(take_commands(&mut room, member_a).is_empty());
+	}
+
+	#[test]
+	fn should_create_and_delete_when_interest_changed() {
+		let (mut room, member_a, member_b) = setup();
+		create_object(&mut room, member_a, 0.0, 0.0);
+		let avatar_b = create_object(&mut room, member_b, 1000.0, 0.0);
+		room.update_interest();
+		take_commands(&mut room, member_a);
+
+		set_position(&mut room, member_b, avatar_b, 5.0, 0.0);
+		room.update_interest();
+		let commands = take_commands(&mut room, member_a);
+		assert!(has_create(&commands, avatar_b));
+
+		set_position(&mut room, member_b, avatar_b, 500.0, 0.0);
+		room.update_interest();
+		let commands = take_commands(&mut room, member_a);
+		assert!(commands.iter().any(|c| matches!(c, S2CCommand::Delete(c) if c.object_id == avatar_b)));
+	}
+
+	///
+	/// Пользователь без объектов с позицией получает все объекты
+	///
+	#[test]
+	fn should_send_all_objects_to_member_without_position() {
+		let (mut room, member_a, member_b) = setup();
+		let far = create_object(&mut room, member_b, 1000.0, 1000.0);
+		room.update_interest();
+		assert!(has_create(&take_commands(&mut room, member_a), far));
+	}
+
+	#[test]
+	fn should_send_delete_only_to_interested_members() {
+		let (mut room, member_a, member_b) = setup();
+		create_object(&mut room, member_a, 0.0, 0.0);
+		create_object(&mut room, member_b, 1000.0, 1000.0);
+		let far = create_object(&mut room, member_b, 1000.0, 1010.0);
+		room.update_interest();
+		take_commands(&mut room, member_a);
+		take_commands(&mut room, member_b);
+
+		room.delete_object(far, member_b).unwrap();
+		assert!(take_commands(&mut room, member_a).is_empty());
+		assert!(room.interest.as_ref().unwrap().visible.values().all(|visible| !visible.contains(&far)));
+	}
+
+	///
+	/// При повторном подключении к комнате объекты с позицией загружаются заново, включая собственные
+	///
+	#[test]
+	fn should_reload_objects_after_attach() {
+		let (mut room, member_a, _) = setup();
+		let avatar_a = create_object(&mut room, member_a, 0.0, 0.0);
+		room.update_interest();
+		detach_from_room(&mut room, member_a).unwrap();
+		take_commands(&mut room, member_a);
+
+		attach_to_room(&mut room, member_a).unwrap();
+		assert!(!has_create(&take_commands(&mut room, member_a), avatar_a));
+		room.update_interest();
+		assert!(has_create(&take_commands(&mut room, member_a), avatar_a));
+	}
+
+	#[test]
+	fn should_not_mark_dirty_when_not_position_field_changed() {
+		let (mut room, member_a, _) = setup();
+		let avatar_a = create_object(&mut room, member_a, 0.0, 0.0);
+		room.update_interest();
+
+		SetFieldCommand {
+			object_id: avatar_a,
+			field_id: 100,
+			value: 1.5.into(),
+		}
+		.execute(&mut room, member_a)
+		.unwrap();
+		let interest = room.interest.as_ref().unwrap();
+		assert!(interest.dirty_objects.is_empty() && interest.dirty_members.is_empty());
+
+		set_position(&mut room, member_a, avatar_a, 1.0, 1.0);
+		assert!(room.interest.as_ref().unwrap().dirty_objects.contains(&avatar_a));
+	}
+
+	///
+	/// Перемещение собственного объекта изменяет область интереса пользователя целиком
+	///
+	#[test]
+	fn should_update_interest_when_own_object_moved() {
+		let (mut room, member_a, member_b) = setup();
+		let avatar_a = create_object(&mut room, member_a, 0.0, 0.0);
+		let far = create_object(&mut room, member_b, 1000.0, 0.0);
+		room.update_interest();
+		assert!(!has_create(&take_commands(&mut room, member_a), far));
+
+		set_position(&mut room, member_a, avatar_a, 990.0, 0.0);
+		room.update_interest();
+		assert!(has_create(&take_commands(&mut room, member_a), far));
+	}
+
+	///
+	/// После удаления последнего объекта с позицией пользователь получает все объекты
+	///
+	#[test]
+	fn should_update_interest_when_own_object_deleted() {
+		let (mut room, member_a, member_b) = setup();
+		let avatar_a = create_object(&mut room, member_a, 0.0, 0.0);
+		let far = create_object(&mut room, member_b, 1000.0, 0.0);
+		room.update_interest();
+		take_commands(&mut room, member_a);
+
+		room.delete_object(avatar_a, member_a).unwrap();
+		room.update_interest();
+		assert!(has_create(&take_commands(&mut room, member_a), far));
+	}
+
+	///
+	/// Координаты на границах f64 не переполняют расстояние между ячейками, NaN считается нулем
+	///
+	#[test]
+	fn should_handle_extreme_positions() {
+		let (mut room, member_a, member_b) = setup();
+		create_object(&mut room, member_a, 0.0, 0.0);
+		create_object(&mut room, member_b, -f64::MAX, -f64::MAX);
+		let far = create_object(&mut room, member_b, f64::MAX, f64::MAX);
+		let nan = create_object(&mut room, member_b, f64::NAN, f64::INFINITY);
+		room.update_interest();
+
+		let commands = take_commands(&mut room, member_a);
+		assert!(!has_create(&commands, far));
+		assert!(has_create(&commands, nan));
+	}
+
+	fn setup() -> (Room, RoomMemberId, RoomMemberId) {
+		let template = RoomTemplate {
+			interest: Some(InterestTemplate {
+				cell_size: 10.0,
+				view_radius: 2,
+				positions: vec![PositionFieldsTemplate {
+					template: TEMPLATE,
+					x_field_id: X,
+					y_field_id: Y,
+				}],
+			}),
+			..Default::default()
+		};
+		let mut room = Room::from_template(template);
+		let member_a = room.register_member(MemberTemplate::stub(ACCESS_GROUPS));
+		let member_b = room.register_member(MemberTemplate::stub(ACCESS_GROUPS));
+		room.test_mark_as_connected(member_a).unwrap();
+		room.test_mark_as_connected(member_b).unwrap();
+		(room, member_a, member_b)
+	}
+
+	fn create_object(room: &mut Room, member_id: RoomMemberId, x: f64, y: f64) -> GameObjectId {
+		let object = room.test_create_object_with_created_state(GameObjectOwner::Member(member_id), ACCESS_GROUPS);
+		object.template_id = TEMPLATE;
+		object.set_field(X, x).unwrap();
+		object.set_field(Y, y).unwrap();
+		let object_id = object.id;
+		room.interest.as_mut().unwrap().mark_object_dirty(object_id);
+		object_id
+	}
+
+	fn set_position(room: &mut Room, member_id: RoomMemberId, object_id: GameObjectId, x: f64, y: f64) {
+		SetFieldCommand {
+			object_id,
+			field_id: X,
+			value: x.into(),
+		}
+		.execute(room, member_id)
+		.unwrap();
+		SetFieldCommand {
+			object_id,
+			field_id: Y,
+			value: y.into(),
+		}
+		.execute(room, member_id)
+		.unwrap();
+	}
+
+	fn take_commands(room: &mut Room, member_id: RoomMemberId) -> Vec<S2CCommand> {
+		let commands = room.test_get_member_out_commands(member_id).into_iter().collect();
+		room.get_member_mut(&member_id).unwrap().out_commands.clear();
+		commands
+	}
+
+	fn has_create(commands: &[S2CCommand], object_id: GameObjectId) -> bool {
+		commands.iter().any(|c| matches!(c, S2CCommand::Create(c) if c.object_id == object_id))
+	}
+}
@@ -0,0 +1,298 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::time::{Duration, Instant};
+
+use fnv::FnvHashMap;
+
+use cheetah_common::commands::binary_value::BinaryValue;
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+use cheetah_common::commands::types::lockstep::{LockstepInputCommand, LockstepMemberInputCommand, LockstepTickCommand, LockstepTickId};
+use cheetah_common::protocol::frame::applications::ChannelGroup;
+use cheetah_common::protocol::frame::channel::ChannelType;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::ServerCommandError;
+
+///
+/// Насколько тиков вперед пользователь может отправлять ввод
+///
+pub const MAX_TICKS_AHEAD: LockstepTickId = 256;
+
+///
+/// Количество последних тиков, наборы вводов которых хранятся в истории
+///
+pub const MAX_HISTORY_TICKS: usize = 4096;
+
+///
+/// Максимальное количество тиков в ответе на запрос истории, остальные запрашиваются следующими запросами
+///
+pub const MAX_HISTORY_TICKS_PER_REQUEST: usize = 64;
+
+///
+/// Канал для отправки наборов вводов - гарантирует доставку и порядок
+///
+pub const LOCKSTEP_CHANNEL: ChannelType = ChannelType::ReliableSequence(ChannelGroup(0));
+
+///
+/// Работа комнаты в режиме lockstep
+///
+/// Пользователи отправляют ввод для тика, сервер отправляет всем пользователям набор вводов тика в порядке возрастания идентификатора пользователя.
+/// Набор отправляется, когда получены вводы от всех пользователей комнаты или истекло время ожидания,
+/// время отсчитывается от получения первого ввода для текущего или следующих тиков.
+/// Вместо отсутствующих вводов подставляется последний известный ввод пользователя.
+///
+#[derive(Debug)]
+pub struct Lockstep {
+	deadline: Duration,
+	current_tick: LockstepTickId,
+	current_tick_started: Option<Instant>,
+	inputs: BTreeMap<LockstepTickId, BTreeMap<RoomMemberId, BinaryValue>>,
+	last_inputs: FnvHashMap<RoomMemberId, BinaryValue>,
+	///
+	/// Отправленные наборы вводов последних [`MAX_HISTORY_TICKS`] тиков
+	///
+	history: VecDeque<Vec<LockstepHistoryInput>>,
+	///
+	/// Номер тика первого набора в истории
+	///
+	history_start_tick: LockstepTickId,
+}
+
+///
+/// Ввод пользователя в истории, хранится без буфера максимального размера
+///
+#[derive(Debug)]
+struct LockstepHistoryInput {
+	member_id: RoomMemberId,
+	filled: bool,
+	input: Box<[u8]>,
+}
+
+impl Lockstep {
+	#[must_use]
+	pub fn new(deadline: Duration) -> Self {
+		Self {
+			deadline,
+			current_tick: 0,
+			current_tick_started: None,
+			inputs: Default::default(),
+			last_inputs: Default::default(),
+			history: Default::default(),
+			history_start_tick: 0,
+		}
+	}
+
+	pub fn add_input(&mut self, member_id: RoomMemberId, command: &LockstepInputCommand) -> Result<(), ServerCommandError> {
+		if command.tick < self.current_tick || command.tick >= self.current_tick + MAX_TICKS_AHEAD {
+			return Err(ServerCommandError::LockstepTickOutOfRange {
+				tick: command.tick,
+				current_tick: self.current_tick,
+			});
+		}
+		self.inputs.entry(command.tick).or_default().insert(member_id, command.input);
+		Ok(())
+	}
+
+	///
+	/// Сформировать набор вводов текущего тика, если получены вводы от всех участников или истекло время ожидания
+	///
+	pub fn release(&mut self, now: Instant, participants: &[RoomMemberId]) -> Option<Vec<LockstepMemberInputCommand>> {
+		if participants.is_empty() || self.inputs.is_empty() {
+			return None;
+		}
+		let started = *self.current_tick_started.get_or_insert(now);
+		let all_received = match self.inputs.get(&self.current_tick) {
+			None => false,
+			Some(inputs) => participants.iter().all(|member_id| inputs.contains_key(member_id)),
+		};
+		if !all_received && now.saturating_duration_since(started) < self.deadline {
+			return None;
+		}
+
+		let tick = self.current_tick;
+		let received = self.inputs.remove(&tick).unwrap_or_default();
+		let members: BTreeSet<RoomMemberId> = participants.iter().chain(received.keys()).copied().collect();
+		let bundle: Vec<_> = members
+			.into_iter()
+			.map(|member_id| match received.get(&member_id) {
+				Some(input) => {
+					self.last_inputs.insert(member_id, *input);
+					LockstepMemberInputCommand {
+						tick,
+						member_id,
+						filled: false,
+						input: *input,
+					}
+				}
+				None => LockstepMemberInputCommand {
+					tick,
+					member_id,
+					filled: true,
+					input: self.last_inputs.get(&member_id).copied().unwrap_or_default(),
+				},
+			})
+			.collect();
+
+		if self.history.len() == MAX_HISTORY_TICKS {
+			self.history.pop_front();
+			self.history_start_tick += 1;
+		}
+		self.history.push_back(
+			bundle
+				.iter()
+				.map(|input| LockstepHistoryInput {
+					member_id: input.member_id,
+					filled: input.filled,
+					input: input.input.as_slice().into(),
+				})
+				.collect(),
+		);
+		self.current_tick += 1;
+		self.current_tick_started = None;
+		Some(bundle)
+	}
+
+	///
+	/// Отправленные наборы вводов начиная с заданного тика, но не более [`MAX_HISTORY_TICKS_PER_REQUEST`],
+	/// для тиков старше хранимых в истории набор начинается с первого хранимого тика
+	///
+	pub fn get_history(&self, from_tick: LockstepTickId) -> impl Iterator<Item = Vec<LockstepMemberInputCommand>> + '_ {
+		let from_tick = from_tick.max(self.history_start_tick);
+		let skip = usize::try_from(from_tick - self.history_start_tick).unwrap_or(usize::MAX);
+		self.history
+			.iter()
+			.skip(skip)
+			.take(MAX_HISTORY_TICKS_PER_REQUEST)
+			.zip(from_tick..)
+			.map(|(inputs, tick)| {
+				inputs
+					.iter()
+					.map(|input| LockstepMemberInputCommand {
+						tick,
+						member_id: input.member_id,
+						filled: input.filled,
+						input: BinaryValue::from(input.input.as_ref()),
+					})
+					.collect()
+			})
+	}
+}
+
+///
+/// Команды для отправки набора вводов тика - вводы пользователей и завершающая команда тика
+///
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn bundle_to_commands(bundle: &[LockstepMemberInputCommand]) -> Vec<S2CCommandWithMeta> {
+	let mut commands: Vec<_> = bundle
+		.iter()
+		.map(|input| S2CCommandWithMeta {
+			field: None,
+			creator: input.member_id,
+			command: S2CCommand::LockstepInput(input.clone()),
+		})
+		.collect();
+	if let Some(first) = bundle.first() {
+		commands.push(S2CCommandWithMeta {
+			field: None,
+			creator: u16::MAX,
+			command: S2CCommand::LockstepTick(LockstepTickCommand {
+				tick: first.tick,
+				inputs: bundle.len() as u16,
+			}),
+		});
+	}
+	commands
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{Duration, Instant};
+
+	use cheetah_common::commands::binary_value::BinaryValue;
+	use cheetah_common::commands::types::lockstep::LockstepInputCommand;
+
+	use crate::room::command::ServerCommandError;
+	use crate::room::lockstep::{Lockstep, MAX_HISTORY_TICKS, MAX_HISTORY_TICKS_PER_REQUEST, MAX_TICKS_AHEAD};
+
+	const DEADLINE: Duration = Duration::from_millis(100);
+
+	#[test]
+	fn should_release_when_all_inputs_received() {
+		let mut lockstep = Lockstep::new(DEADLINE);
+		let now = Instant::now();
+		lockstep.add_input(2, &input(0, 20)).unwrap();
+		assert!(lockstep.release(now, &[1, 2]).is_none());
+		lockstep.add_input(1, &input(0, 10)).unwrap();
+		let bundle = lockstep.release(now, &[1, 2]).unwrap();
+		assert_eq!(bundle.iter().map(|i| i.member_id).collect::<Vec<_>>(), vec![1, 2]);
+		assert!(bundle.iter().all(|i| i.tick == 0 && !i.filled));
+		assert_eq!(bundle[0].input, BinaryValue::from([10].as_slice()));
+	}
+
+	#[test]
+	fn should_fill_missing_inputs_after_deadline() {
+		let mut lockstep = Lockstep::new(DEADLINE);
+		let now = Instant::now();
+		lockstep.add_input(1, &input(0, 10)).unwrap();
+		lockstep.add_input(2, &input(0, 20)).unwrap();
+		lockstep.release(now, &[1, 2]).unwrap();
+
+		lockstep.add_input(1, &input(1, 11)).unwrap();
+		assert!(lockstep.release(now, &[1, 2]).is_none());
+		let bundle = lockstep.release(now + DEADLINE, &[1, 2]).unwrap();
+		assert_eq!(bundle[1].tick, 1);
+		assert!(bundle[1].filled);
+		assert_eq!(bundle[1].input, BinaryValue::from([20].as_slice()));
+	}
+
+	#[test]
+	fn should_reject_input_for_released_tick() {
+		let mut lockstep = Lockstep::new(DEADLINE);
+		lockstep.add_input(1, &input(0, 10)).unwrap();
+		lockstep.release(Instant::now(), &[1]).unwrap();
+		assert_eq!(
+			lockstep.add_input(1, &input(0, 10)),
+			Err(ServerCommandError::LockstepTickOutOfRange { tick: 0, current_tick: 1 })
+		);
+		assert!(lockstep.add_input(1, &input(1 + MAX_TICKS_AHEAD, 10)).is_err());
+	}
+
+	#[test]
+	fn should_return_history() {
+		let mut lockstep = Lockstep::new(DEADLINE);
+		for tick in 0..3 {
+			lockstep.add_input(1, &input(tick, 1)).unwrap();
+			lockstep.release(Instant::now(), &[1]).unwrap();
+		}
+		let ticks: Vec<_> = lockstep.get_history(1).map(|bundle| bundle[0].tick).collect();
+		assert_eq!(ticks, vec![1, 2]);
+		assert_eq!(lockstep.get_history(10).count(), 0);
+	}
+
+	#[test]
+	fn should_limit_history() {
+		let mut lockstep = Lockstep::new(DEADLINE);
+		let ticks = MAX_HISTORY_TICKS as u64 + 10;
+		for tick in 0..ticks {
+			lockstep.add_input(1, &input(tick, 1)).unwrap();
+			lockstep.release(Instant::now(), &[1]).unwrap();
+		}
+		assert_eq!(lockstep.history.len(), MAX_HISTORY_TICKS);
+
+		// старые тики удалены из истории, ответ ограничен по количеству тиков
+		let history: Vec<_> = lockstep.get_history(0).map(|bundle| bundle[0].tick).collect();
+		assert_eq!(history.len(), MAX_HISTORY_TICKS_PER_REQUEST);
+		assert_eq!(history.first(), Some(&10));
+		assert_eq!(
+			lockstep.get_history(ticks - 1).map(|bundle| bundle[0].tick).collect::<Vec<_>>(),
+			vec![ticks - 1]
+		);
+	}
+
+	fn input(tick: u64, value: u8) -> LockstepInputCommand {
+		LockstepInputCommand {
+			tick,
+			input: BinaryValue::from([value].as_slice()),
+		}
+	}
+}
@@ -1,6 +1,7 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::iter;
 use std::rc::Rc;
 use std::slice;
 use std::time::Instant;
@@ -9,11 +10,17 @@ use fnv::{FnvBuildHasher, FnvHashMap, FnvHashSet};
 use indexmap::map::IndexMap;
 
 use cheetah_common::commands::binary_value::BinaryValue;
-use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+use cheetah_common::commands::c2s::C2SCommand;
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithCreator, S2CCommandWithMeta};
 use cheetah_common::commands::types::delete::DeleteGameObjectCommand;
 use cheetah_common::commands::types::member_connected::MemberConnectedCommand;
+use cheetah_common::commands::types::rejected::CommandRejectedCommand;
+use cheetah_common::commands::types::rpc::RpcStatus;
+use cheetah_common::commands::FieldType;
 use cheetah_common::constants::GameObjectTemplateId;
 use cheetah_common::protocol::commands::output::CommandWithChannelType;
+use cheetah_common::protocol::disconnect::command::DisconnectByCommandReason;
 use cheetah_common::protocol::frame::applications::{BothDirectionCommand, ChannelGroup, CommandWithChannel};
 use cheetah_common::protocol::frame::channel::ChannelType;
 use cheetah_common::room::access::AccessGroups;
@@ -24,18 +31,42 @@ use cheetah_common::room::{RoomId, RoomMemberId};
 use crate::debug::tracer::CommandTracerSessions;
 use crate::room::command::compare_and_set::{reset_all_compare_and_set, CASCleanersStore};
 use crate::room::command::{execute, ServerCommandError};
+use crate::room::delta::StructureDeltaEncoder;
+use crate::room::expiry::ObjectsExpiry;
 use crate::room::forward::ForwardConfig;
+use crate::room::history::FieldHistory;
+use crate::room::interest::Interest;
+use crate::room::lockstep::{bundle_to_commands, Lockstep, LOCKSTEP_CHANNEL};
 use crate::room::object::{CreateCommandsCollector, GameObject};
-use crate::room::template::config::{MemberTemplate, Permissions, RoomTemplate};
+use crate::room::rate_limit::{RateLimitResult, RateLimiter};
+use crate::room::rpc::RpcCalls;
+use crate::room::subscription::Subscription;
+use crate::room::template::config::{FieldBounds, MemberTemplate, Permissions, RoomTemplate};
 use crate::room::template::permission::PermissionManager;
+use crate::room::tick::RoomTick;
+use crate::room::timer::Timers;
+use crate::room::validation::FieldValidator;
 use crate::server::measurers::Measurers;
 
 pub mod action;
 pub mod command;
+pub mod delta;
+pub mod expiry;
 pub mod forward;
+pub mod groups;
+pub mod history;
+pub mod interest;
+pub mod lockstep;
 pub mod object;
+pub mod rate_limit;
+pub mod resync;
+pub mod rpc;
 pub mod sender;
+pub mod subscription;
 pub mod template;
+pub mod tick;
+pub mod timer;
+pub mod validation;
 
 pub struct Room {
 	pub id: RoomId,
@@ -44,12 +75,84 @@ pub struct Room {
 	pub members: HashMap<RoomMemberId, Member, FnvBuildHasher>,
 	pub(crate) objects: IndexMap<GameObjectId, GameObject, FnvBuildHasher>,
 	current_channel: Option<ChannelType>,
+	///
+	/// Время выполняемой команды или цикла обработки комнаты, если не задано - используется текущее время
+	///
+	current_time: Option<Instant>,
 	pub member_id_generator: RoomMemberId,
 	pub command_trace_session: Rc<RefCell<CommandTracerSessions>>,
 	pub room_object_id_generator: u32,
 	tmp_command_collector: Rc<RefCell<Vec<(GameObjectTemplateId, CreateCommandsCollector)>>>,
 	measurers: Rc<RefCell<Measurers>>,
 	objects_singleton_key: HashMap<BinaryValue, GameObjectId, FnvBuildHasher>,
+	///
+	/// Отправлять изменения структур в виде разницы с предыдущим значением
+	///
+	structure_delta: bool,
+	///
+	/// Режим тиков, если не задан - команды отправляются сразу после выполнения
+	///
+	tick: Option<RoomTick>,
+	///
+	/// Режим lockstep, если не задан - команды с вводом пользователей не принимаются
+	///
+	pub(crate) lockstep: Option<Lockstep>,
+	///
+	/// Время создания комнаты, от него отсчитывается время сервера в командах
+	///
+	start_application_time: Instant,
+	///
+	/// Передавать в командах время сервера
+	///
+	server_time: bool,
+	///
+	/// Сообщать пользователю о невыполненных командах
+	///
+	notify_rejected_commands: bool,
+	///
+	/// Максимальная длина строковых полей в символах
+	///
+	max_string_length: Option<usize>,
+	///
+	/// Ограничения значений полей при инкрементах
+	///
+	field_bounds: FnvHashMap<(GameObjectTemplateId, FieldId, FieldType), FieldBounds>,
+	///
+	/// Отложенные действия пользователей и комнаты
+	///
+	pub(crate) timers: Timers,
+	///
+	/// Вызовы пользователей, ожидающие ответа
+	///
+	pub(crate) rpc_calls: RpcCalls,
+	///
+	/// Время жизни объектов по шаблонам в миллисекундах
+	///
+	object_ttl: FnvHashMap<GameObjectTemplateId, u64>,
+	///
+	/// Объекты с ограниченным временем жизни
+	///
+	objects_expiry: ObjectsExpiry,
+	///
+	/// Область интереса пользователей, если не задана - объекты отправляются без учета позиции
+	///
+	pub(crate) interest: Option<Interest>,
+	///
+	/// Ограничение частоты команд пользователей
+	///
+	rate_limiter: Option<RateLimiter>,
+	///
+	/// Пользователи, соединение с которыми необходимо разорвать на сетевом уровне
+	///
+	pub(crate) members_to_disconnect: Vec<(RoomMemberId, DisconnectByCommandReason)>,
+	///
+	/// Проверка изменений полей пользователями, если не задана - правила проверки в шаблоне отсутствуют
+	///
+	field_validator: Option<FieldValidator>,
+	///
+	/// История значений полей, если не задана - история в шаблоне не настроена
+	///
+	field_history: Option<FieldHistory>,
 
 	#[cfg(test)]
 	test_object_id_generator: u32,
@@ -77,7 +180,41 @@ pub struct Member {
 	pub attached: bool,
 	pub template: MemberTemplate,
 	pub compare_and_set_cleaners: CASCleanersStore,
+	pub structure_delta_encoder: StructureDeltaEncoder,
+	///
+	/// Команды, накопленные за текущий тик
+	///
+	pub tick_commands: Vec<(ChannelType, S2CCommandWithCreator)>,
 	pub out_commands: Vec<CommandWithChannelType>,
+	///
+	/// Шаблоны объектов и поля, на которые подписан пользователь
+	///
+	pub subscription: Subscription,
+}
+
+///
+/// Данные удаленного объекта, необходимые для рассылки команды удаления
+///
+struct DeletedObject {
+	id: GameObjectId,
+	template_id: GameObjectTemplateId,
+	access_groups: AccessGroups,
+	owner: GameObjectOwner,
+	created: bool,
+	expire_at: Option<u64>,
+}
+
+impl From<&GameObject> for DeletedObject {
+	fn from(object: &GameObject) -> Self {
+		Self {
+			id: object.id,
+			template_id: object.template_id,
+			access_groups: object.access_groups,
+			owner: object.owner,
+			created: object.created,
+			expire_at: object.expire_at,
+		}
+	}
 }
 
 impl Room {
@@ -87,6 +224,7 @@ impl Room {
 			members: FnvHashMap::default(),
 			objects: Default::default(),
 			current_channel: Default::default(),
+			current_time: Default::default(),
 			permission_manager: Rc::new(RefCell::new(PermissionManager::new(&template.permissions))),
 			#[cfg(test)]
 			test_object_id_generator: 0,
@@ -99,12 +237,43 @@ impl Room {
 			template_name: template.name.clone(),
 			measurers,
 			objects_singleton_key: Default::default(),
+			structure_delta: template.structure_delta,
+			tick: template.tick_rate.map(RoomTick::new),
+			lockstep: template.lockstep_deadline.map(Lockstep::new),
+			start_application_time: Instant::now(),
+			server_time: template.server_time,
+			notify_rejected_commands: template.notify_rejected_commands,
+			max_string_length: template.max_string_length,
+			field_bounds: template
+				.field_bounds
+				.iter()
+				.map(|field_bounds| {
+					(
+						(field_bounds.template, field_bounds.field_id, field_bounds.bounds.field_type()),
+						field_bounds.bounds,
+					)
+				})
+				.collect(),
+			timers: Default::default(),
+			rpc_calls: Default::default(),
+			object_ttl: template
+				.object_ttl
+				.iter()
+				.map(|object_ttl| (object_ttl.template, object_ttl.ttl.as_millis().try_into().unwrap_or(u64::MAX)))
+				.collect(),
+			objects_expiry: Default::default(),
+			interest: template.interest.as_ref().map(Interest::new),
+			rate_limiter: template.rate_limits.as_ref().map(RateLimiter::new),
+			members_to_disconnect: Default::default(),
+			field_validator: (!template.field_validations.is_empty()).then(|| FieldValidator::new(&template.field_validations)),
+			field_history: (!template.field_history.is_empty()).then(|| FieldHistory::new(&template.field_history)),
 			forward_configs: Default::default(),
 			plugins_pending: plugin_names,
 		};
 
 		template.objects.into_iter().for_each(|object| {
-			let game_object: GameObject = object.to_root_game_object();
+			let mut game_object: GameObject = object.to_root_game_object();
+			game_object.expire_at = room.get_template_expire_at(game_object.template_id);
 			room.insert_object(game_object);
 		});
 
@@ -143,10 +312,18 @@ impl Room {
 	///
 	/// Получить команды для отправки в сеть
 	///
-	pub fn collect_out_commands<F>(&mut self, mut collector: F)
+	pub fn collect_out_commands<F>(&mut self, now: Instant, mut collector: F)
 	where
 		F: FnMut(&RoomMemberId, &[CommandWithChannelType]),
 	{
+		self.current_time = Some(now);
+		self.fire_timers(now);
+		self.expire_rpc_calls(now);
+		self.delete_expired_objects(now);
+		self.release_lockstep_ticks(now);
+		self.update_interest();
+		self.flush_tick_commands(now);
+		self.current_time = None;
 		for (member_id, member) in &mut self.members {
 			let commands = member.out_commands.as_slice();
 			collector(member_id, commands);
@@ -154,6 +331,89 @@ impl Room {
 		}
 	}
 
+	///
+	/// Время сервера в миллисекундах от создания комнаты
+	///
+	#[allow(clippy::cast_possible_truncation)]
+	fn get_server_time(&self, now: Instant) -> u64 {
+		now.saturating_duration_since(self.start_application_time).as_millis() as u64
+	}
+
+	///
+	/// Время выполняемой команды или цикла обработки комнаты
+	///
+	pub(crate) fn get_current_time(&self) -> Instant {
+		self.current_time.unwrap_or_else(Instant::now)
+	}
+
+	///
+	/// Время сервера для исходящих команд, если передача времени включена в шаблоне комнаты
+	///
+	fn get_out_commands_time(&self) -> Option<u64> {
+		self.server_time.then(|| self.get_server_time(self.get_current_time()))
+	}
+
+	///
+	/// Выполнить действия таймеров, время срабатывания которых наступило
+	///
+	fn fire_timers(&mut self, now: Instant) {
+		let server_time = self.get_server_time(now);
+		for timer in self.timers.take_expired(server_time) {
+			command::timer::fire(self, timer);
+		}
+	}
+
+	///
+	/// Отправить ошибку вызовам, не получившим ответа за время ожидания
+	///
+	fn expire_rpc_calls(&mut self, now: Instant) {
+		let server_time = self.get_server_time(now);
+		for call in self.rpc_calls.take_expired(server_time) {
+			if let Err(e) = command::rpc::reply_error(self, &call, RpcStatus::Timeout) {
+				e.log_error(self.id, call.caller);
+			}
+		}
+	}
+
+	///
+	/// В режиме тиков - отправить накопленные команды, если наступил следующий тик
+	///
+	fn flush_tick_commands(&mut self, now: Instant) {
+		if let Some(tick) = self.tick.as_mut() {
+			if tick.is_next_tick(now) {
+				let structure_delta = self.structure_delta;
+				for member in self.members.values_mut() {
+					member.flush_tick_commands(structure_delta);
+				}
+			}
+		}
+	}
+
+	///
+	/// В режиме lockstep - отправить наборы вводов для завершенных тиков
+	///
+	fn release_lockstep_ticks(&mut self, now: Instant) {
+		let lockstep = match self.lockstep.as_mut() {
+			None => return,
+			Some(lockstep) => lockstep,
+		};
+		let participants: Vec<_> = self
+			.members
+			.values()
+			.filter(|member| member.attached && member.connected && !member.template.super_member)
+			.map(|member| member.id)
+			.collect();
+		let mut commands = Vec::new();
+		while let Some(bundle) = lockstep.release(now, &participants) {
+			commands.extend(bundle_to_commands(&bundle));
+		}
+		if !commands.is_empty() {
+			if let Err(e) = self.send_to_members_with_channel(LOCKSTEP_CHANNEL, AccessGroups::super_group(), &commands, |_| true) {
+				e.log_error(self.id, u16::MAX);
+			}
+		}
+	}
+
 	///
 	/// Обработать входящие команды.
 	///
@@ -183,12 +443,22 @@ impl Room {
 			return;
 		}
 
+		if self.members_to_disconnect.iter().any(|(id, _)| *id == member_id) {
+			self.current_channel = None;
+			return;
+		}
+
 		let measurers = Rc::clone(&self.measurers);
 		let mut measurers = measurers.borrow_mut();
 		let tracer = Rc::clone(&self.command_trace_session);
 		for command_with_channel in commands {
 			match &command_with_channel.both_direction_command {
 				BothDirectionCommand::C2S(command) => {
+					match self.apply_rate_limit(member_id, command, &mut measurers) {
+						RateLimitResult::Allowed => {}
+						RateLimitResult::Disconnect => break,
+						RateLimitResult::Dropped | RateLimitResult::Warn => continue,
+					}
 					self.current_channel.replace(From::from(&command_with_channel.channel));
 					tracer.borrow_mut().collect_c2s(&self.objects, member_id, command);
 
@@ -198,10 +468,15 @@ impl Room {
 						}
 					} else {
 						let instant = Instant::now();
+						self.current_time = Some(instant);
 						match execute(command, self, member_id) {
 							Ok(_) => {}
 							Err(e) => {
 								e.log_command_execute_error(command, self.id, member_id);
+								// выход за границы при инкременте клиент должен обработать, поэтому сообщается всегда
+								if self.notify_rejected_commands || matches!(e, ServerCommandError::IncrementOutOfBounds { .. }) {
+									self.send_command_rejected(member_id, command, &e);
+								}
 							}
 						}
 						measurers.on_execute_command(command.get_field_id(), command, instant.elapsed());
@@ -214,6 +489,75 @@ impl Room {
 		}
 
 		self.current_channel = None;
+		self.current_time = None;
+	}
+
+	///
+	/// Проверить лимит частоты команд пользователя, при превышении команда отбрасывается,
+	/// при достижении порогов нарушений - предупреждение в лог или отключение пользователя
+	///
+	/// - вложенные команды транзакций и таймеров учитываются в лимитах своих типов, при превышении любого
+	///   из них отбрасывается вся команда
+	///
+	fn apply_rate_limit(&mut self, member_id: RoomMemberId, command: &C2SCommand, measurers: &mut Measurers) -> RateLimitResult {
+		let Some(rate_limiter) = self.rate_limiter.as_mut() else {
+			return RateLimitResult::Allowed;
+		};
+		if self.members.get(&member_id).is_some_and(|member| member.template.super_member) {
+			return RateLimitResult::Allowed;
+		}
+		let now = Instant::now();
+		let Some((command_type_id, result)) = iter::once((command.get_type_id(), command.get_field_id()))
+			.chain(command.get_nested_commands())
+			.map(|(command_type_id, field_id)| (command_type_id, rate_limiter.check(member_id, command_type_id, field_id, now)))
+			.find(|(_, result)| *result != RateLimitResult::Allowed)
+		else {
+			return RateLimitResult::Allowed;
+		};
+		match result {
+			RateLimitResult::Allowed => return result,
+			RateLimitResult::Dropped => {}
+			RateLimitResult::Warn => {
+				tracing::warn!(
+					"[room({:?})] member({:?}) exceeded rate limit for {:?}",
+					self.id,
+					member_id,
+					command_type_id
+				);
+			}
+			RateLimitResult::Disconnect => {
+				tracing::warn!(
+					"[room({:?})] disconnect member({:?}) by rate limit for {:?}",
+					self.id,
+					member_id,
+					command_type_id
+				);
+				self.members_to_disconnect
+					.push((member_id, DisconnectByCommandReason::CommandRateLimitExceeded));
+				measurers.on_rate_limit_disconnect(&self.template_name);
+			}
+		}
+		measurers.on_rate_limited_command(&self.template_name, command);
+		result
+	}
+
+	///
+	/// Сообщить пользователю, что его команда не выполнена
+	///
+	fn send_command_rejected(&mut self, member_id: RoomMemberId, command: &C2SCommand, error: &ServerCommandError) {
+		let s2c = S2CCommandWithMeta {
+			field: None,
+			creator: member_id,
+			command: S2CCommand::CommandRejected(CommandRejectedCommand {
+				command_type_id: command.get_type_id(),
+				reason: error.get_reject_reason(),
+				object_id: command.get_object_id(),
+				field_id: command.get_field_id(),
+			}),
+		};
+		if let Err(e) = self.send_to_members(AccessGroups::super_group(), None, slice::from_ref(&s2c), |member| member.id == member_id) {
+			e.log_error(self.id, member_id);
+		}
 	}
 
 	fn connect_member(&mut self, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
@@ -239,7 +583,10 @@ impl Room {
 			attached: false,
 			template,
 			compare_and_set_cleaners: Default::default(),
+			structure_delta_encoder: Default::default(),
+			tick_commands: Default::default(),
 			out_commands: Default::default(),
+			subscription: Default::default(),
 		};
 		self.members.insert(member_id, member);
 		member_id
@@ -264,7 +611,7 @@ impl Room {
 			Some(member) => {
 				let mut objects = Vec::new();
 				self.process_objects(&mut |o| {
-					if let GameObjectOwner::Member(owner) = o.id.get_owner() {
+					if let GameObjectOwner::Member(owner) = o.owner {
 						if owner == member.id {
 							objects.push(o.id);
 						}
@@ -272,15 +619,35 @@ impl Room {
 				});
 
 				for id in objects {
-					self.delete_object(id, member_id)?;
+					// объект мог быть удален вместе с родительским
+					if self.contains_object(&id) {
+						self.delete_object(id, member_id)?;
+					}
 				}
 				reset_all_compare_and_set(self, member.id, &member.compare_and_set_cleaners)?;
+				if let Some(interest) = self.interest.as_mut() {
+					interest.remove_member(member.id);
+				}
+				self.timers.remove_member_timers(member.id);
+				if let Some(rate_limiter) = self.rate_limiter.as_mut() {
+					rate_limiter.remove_member(member.id);
+				}
+				for call in self.rpc_calls.remove_member(member.id) {
+					command::rpc::reply_error(self, &call, RpcStatus::TargetNotFound)?;
+				}
 			}
 		};
 		Ok(())
 	}
 
 	pub fn insert_object(&mut self, object: GameObject) {
+		if let Some(expire_at) = object.expire_at {
+			self.objects_expiry.insert(object.id, expire_at);
+		}
+		let time = self.get_server_time(self.get_current_time());
+		if let Some(field_history) = self.field_history.as_mut() {
+			field_history.record_object(&object, time);
+		}
 		self.objects.insert(object.id, object);
 	}
 
@@ -294,39 +661,135 @@ impl Room {
 			.ok_or(ServerCommandError::GameObjectNotFound { object_id })
 	}
 
+	///
+	/// Объектом комнаты может управлять любой пользователь, объектом пользователя - только его текущий владелец
+	///
+	pub(crate) fn check_object_owner(&self, object_id: GameObjectId, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		match self.get_object(object_id)?.owner {
+			GameObjectOwner::Member(owner) if owner != member_id => Err(ServerCommandError::MemberNotOwnerGameObject { object_id, member_id }),
+			_ => Ok(()),
+		}
+	}
+
 	#[must_use]
 	pub fn contains_object(&self, object_id: &GameObjectId) -> bool {
 		self.objects.contains_key(object_id)
 	}
 
 	pub fn delete_object(&mut self, object_id: GameObjectId, member_id: RoomMemberId) -> Result<GameObject, ServerCommandError> {
+		self.delete_object_with_filter(object_id, member_id, |member| member.id != member_id)
+	}
+
+	///
+	/// Удалить объект, команда удаления отправляется пользователям, удовлетворяющим фильтру
+	///
+	pub(crate) fn delete_object_with_filter<T>(
+		&mut self,
+		object_id: GameObjectId,
+		member_id: RoomMemberId,
+		filter: T,
+	) -> Result<GameObject, ServerCommandError>
+	where
+		T: Fn(&Member) -> bool,
+	{
 		match self.objects.shift_remove(&object_id) {
 			None => Err(ServerCommandError::GameObjectNotFound { object_id }),
 			Some(object) => {
-				if object.created {
-					self.send_to_members(
-						object.access_groups,
-						Some(object.template_id),
-						&[S2CCommandWithMeta {
-							field: None,
-							creator: member_id,
-							command: S2CCommand::Delete(DeleteGameObjectCommand { object_id: object.id }),
-						}],
-						|member| member.id != member_id,
-					)?;
-				}
+				self.delete_children(object_id, member_id, &filter)?;
+				self.send_delete(&DeletedObject::from(&object), member_id, &filter)?;
 				Ok(object)
 			}
 		}
 	}
 
+	///
+	/// Удалить дочерние объекты, команды удаления дочерних объектов отправляются раньше родительского
+	///
+	/// Потомки собираются обходом в глубину без рекурсии по индексу дочерних объектов, построенному за один проход.
+	///
+	fn delete_children(
+		&mut self,
+		parent_id: GameObjectId,
+		member_id: RoomMemberId,
+		filter: &dyn Fn(&Member) -> bool,
+	) -> Result<(), ServerCommandError> {
+		let mut children: FnvHashMap<GameObjectId, Vec<GameObjectId>> = FnvHashMap::default();
+		for object in self.objects.values() {
+			if let Some(parent) = object.parent {
+				children.entry(parent).or_default().push(object.id);
+			}
+		}
+		if !children.contains_key(&parent_id) {
+			return Ok(());
+		}
+
+		// порядок удаления - потомки объекта раньше самого объекта, соседние объекты в порядке создания
+		let mut descendants = Vec::new();
+		let mut pending = vec![(parent_id, false)];
+		while let Some((object_id, visited)) = pending.pop() {
+			if visited {
+				descendants.push(object_id);
+				continue;
+			}
+			pending.push((object_id, true));
+			if let Some(object_children) = children.get(&object_id) {
+				pending.extend(object_children.iter().rev().map(|child_id| (*child_id, false)));
+			}
+		}
+		// последним в списке идет сам родительский объект, он уже удален
+		descendants.pop();
+
+		let deleted: Vec<_> = descendants
+			.iter()
+			.filter_map(|object_id| self.objects.get(object_id))
+			.map(DeletedObject::from)
+			.collect();
+		// удаление за один проход с сохранением порядка оставшихся объектов
+		let removed: FnvHashSet<_> = descendants.into_iter().collect();
+		self.objects.retain(|object_id, _| !removed.contains(object_id));
+		for object in &deleted {
+			self.send_delete(object, member_id, filter)?;
+		}
+		Ok(())
+	}
+
+	fn send_delete(&mut self, object: &DeletedObject, member_id: RoomMemberId, filter: &dyn Fn(&Member) -> bool) -> Result<(), ServerCommandError> {
+		if let Some(field_validator) = self.field_validator.as_mut() {
+			field_validator.remove_object(&object.id);
+		}
+		if let Some(field_history) = self.field_history.as_mut() {
+			field_history.remove_object(&object.id);
+		}
+		if let Some(expire_at) = object.expire_at {
+			self.objects_expiry.remove(&object.id, expire_at);
+		}
+		if !object.created {
+			return Ok(());
+		}
+		self.send_to_members(
+			object.access_groups,
+			Some(object.template_id),
+			&[S2CCommandWithMeta {
+				field: None,
+				creator: member_id,
+				command: S2CCommand::Delete(DeleteGameObjectCommand { object_id: object.id }),
+			}],
+			filter,
+		)?;
+		if let Some(interest) = self.interest.as_mut() {
+			interest.remove_object(&object.id, object.template_id, object.owner);
+		}
+		Ok(())
+	}
+
 	pub fn process_objects(&self, f: &mut dyn FnMut(&GameObject)) {
 		self.objects.iter().for_each(|(_, o)| f(o));
 	}
 
 	fn on_member_connect(&mut self, member_id: RoomMemberId, template: MemberTemplate) -> Result<(), ServerCommandError> {
 		for object_template in template.objects {
-			let object = object_template.create_member_game_object(member_id);
+			let mut object = object_template.create_member_game_object(member_id);
+			object.expire_at = self.get_template_expire_at(object.template_id);
 			let mut commands = CreateCommandsCollector::new();
 			object.collect_create_commands(&mut commands, member_id);
 			let template = object.template_id;
@@ -370,15 +833,21 @@ mod tests {
 	use std::collections::VecDeque;
 	use std::rc::Rc;
 	use std::slice;
+	use std::time::{Duration, Instant};
 
 	use cheetah_common::commands::binary_value::BinaryValue;
 	use cheetah_common::commands::c2s::C2SCommand;
-	use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithCreator};
+	use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithCreator, S2CCommandWithMeta};
+	use cheetah_common::commands::types::bounds::{Bounds, BoundsMode};
 	use cheetah_common::commands::types::create::CreateGameObjectCommand;
 	use cheetah_common::commands::types::field::SetFieldCommand;
+	use cheetah_common::commands::types::long::IncrementLongWithBoundsC2SCommand;
 	use cheetah_common::commands::types::member_connected::MemberConnectedCommand;
+	use cheetah_common::commands::types::rejected::{CommandRejectReason, CommandRejectedCommand};
+	use cheetah_common::commands::types::transaction::{TransactionCommand, TransactionMutation};
 	use cheetah_common::commands::{CommandTypeId, FieldType, FieldValue};
 	use cheetah_common::protocol::commands::output::CommandWithChannelType;
+	use cheetah_common::protocol::disconnect::command::DisconnectByCommandReason;
 	use cheetah_common::protocol::frame::applications::{BothDirectionCommand, CommandWithChannel};
 	use cheetah_common::protocol::frame::channel::{Channel, ChannelType};
 	use cheetah_common::room::access::AccessGroups;
@@ -388,7 +857,7 @@ mod tests {
 
 	use crate::room::forward::ForwardConfig;
 	use crate::room::object::GameObject;
-	use crate::room::template::config::{GameObjectTemplate, MemberTemplate, Permission, RoomTemplate};
+	use crate::room::template::config::{GameObjectTemplate, MemberTemplate, Permission, RateLimitTemplate, RateLimitsTemplate, RoomTemplate};
 	use crate::room::{Room, ServerCommandError};
 	use crate::server::measurers::Measurers;
 
@@ -504,6 +973,29 @@ mod tests {
 		assert!(matches!(room.test_out_commands.pop_back(), Some((..,S2CCommand::Delete(command))) if command.object_id == object_a_2));
 	}
 
+	#[test]
+	fn should_remove_child_objects_when_disconnect() {
+		let access_groups = AccessGroups(0b111);
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_a = room.register_member(MemberTemplate::stub(access_groups));
+		let member_b = room.register_member(MemberTemplate::stub(access_groups));
+		let parent = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_a), access_groups)
+			.id;
+		let child_a = room.test_create_object_with_created_state(GameObjectOwner::Member(member_a), access_groups);
+		child_a.parent = Some(parent);
+		let child_a = child_a.id;
+		let child_b = room.test_create_object_with_created_state(GameObjectOwner::Member(member_b), access_groups);
+		child_b.parent = Some(parent);
+		let child_b = child_b.id;
+
+		room.disconnect_member(member_a).unwrap();
+
+		assert!(!room.contains_object(&parent));
+		assert!(!room.contains_object(&child_a));
+		assert!(!room.contains_object(&child_b));
+	}
+
 	#[test]
 	fn should_create_object_from_config() {
 		let mut template = RoomTemplate::default();
@@ -671,13 +1163,78 @@ mod tests {
 					value: 0.into(),
 				}),
 				creator: 0,
+				time: None,
 			}),
 		});
-		room.collect_out_commands(|_, _| {});
+		room.collect_out_commands(Instant::now(), |_, _| {});
 		let member = room.get_member(&member_id).unwrap();
 		assert!(member.out_commands.is_empty());
 	}
 
+	#[test]
+	fn should_set_server_time() {
+		let template = RoomTemplate {
+			server_time: true,
+			..Default::default()
+		};
+		let mut room = Room::from_template(template);
+		let access_groups = AccessGroups(8);
+		let member_id = room.register_member(MemberTemplate::stub(access_groups));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object_id = room.test_create_object_with_created_state(GameObjectOwner::Room, access_groups).id;
+		let command = S2CCommandWithMeta {
+			field: None,
+			creator: u16::MAX,
+			command: S2CCommand::new_set_command(1.into(), object_id, 1),
+		};
+		room.current_time = Some(room.start_application_time + Duration::from_millis(1500));
+		room.send_to_members(access_groups, None, slice::from_ref(&command), |_| true).unwrap();
+		room.current_time = None;
+
+		// время устанавливается при формировании команды, а не при отправке
+		let mut times = Vec::new();
+		room.collect_out_commands(room.start_application_time + Duration::from_millis(3000), |_, commands| {
+			for command in commands {
+				if let BothDirectionCommand::S2CWithCreator(command) = &command.command {
+					times.push(command.time);
+				}
+			}
+		});
+		assert_eq!(times, vec![Some(1500)]);
+	}
+
+	///
+	/// В режиме тиков команды отправляются в конце тика, для поля - только последнее значение
+	///
+	#[test]
+	fn should_send_last_value_on_tick() {
+		let template = RoomTemplate {
+			tick_rate: Some(10),
+			..Default::default()
+		};
+		let mut room = Room::from_template(template);
+		let access_groups = AccessGroups(8);
+		let member_id = room.register_member(MemberTemplate::stub(access_groups));
+		room.test_mark_as_connected(member_id).unwrap();
+		let now = Instant::now();
+		room.collect_out_commands(now, |_, _| {});
+
+		let object_id = room.test_create_object_with_created_state(GameObjectOwner::Room, access_groups).id;
+		for value in 0..10 {
+			let command = S2CCommandWithMeta {
+				field: None,
+				creator: u16::MAX,
+				command: S2CCommand::new_set_command(value.into(), object_id, 1),
+			};
+			room.send_to_members(access_groups, None, slice::from_ref(&command), |_| true).unwrap();
+		}
+		assert!(room.test_get_member_out_commands(member_id).is_empty());
+
+		room.flush_tick_commands(now + Duration::from_millis(100));
+		let commands = room.test_get_member_out_commands(member_id);
+		assert_eq!(commands, [S2CCommand::new_set_command(9.into(), object_id, 1)]);
+	}
+
 	#[test]
 	fn should_check_singleton_key() {
 		let mut room = Room::default();
@@ -732,6 +1289,72 @@ mod tests {
 		assert_eq!(2, room.objects.len());
 	}
 
+	#[test]
+	fn should_send_command_rejected() {
+		let template = RoomTemplate {
+			notify_rejected_commands: true,
+			..Default::default()
+		};
+		let mut room = Room::from_template(template);
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object_id = GameObjectId::new(100, GameObjectOwner::Room);
+		let command = CommandWithChannel {
+			channel: Channel::ReliableUnordered,
+			both_direction_command: BothDirectionCommand::C2S(C2SCommand::SetField(SetFieldCommand {
+				object_id,
+				field_id: 5,
+				value: 1.into(),
+			})),
+		};
+		room.execute_commands(member_id, slice::from_ref(&command));
+
+		assert_eq!(
+			room.test_get_member_out_commands(member_id),
+			vec![S2CCommand::CommandRejected(CommandRejectedCommand {
+				command_type_id: CommandTypeId::SetLong,
+				reason: CommandRejectReason::GameObjectNotFound,
+				object_id: Some(object_id),
+				field_id: Some(5),
+			})]
+		);
+	}
+
+	#[test]
+	fn should_always_send_command_rejected_for_increment_out_of_bounds() {
+		let mut room = Room::from_template(RoomTemplate::default());
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_id), AccessGroups(10))
+			.id;
+		room.test_out_commands.clear();
+		let command = CommandWithChannel {
+			channel: Channel::ReliableUnordered,
+			both_direction_command: BothDirectionCommand::C2S(C2SCommand::IncrementLongWithBounds(IncrementLongWithBoundsC2SCommand {
+				object_id,
+				field_id: 5,
+				increment: 10,
+				bounds: Bounds {
+					min: 0,
+					max: 5,
+					mode: BoundsMode::Reject,
+				},
+			})),
+		};
+		room.execute_commands(member_id, slice::from_ref(&command));
+
+		assert_eq!(
+			room.test_get_member_out_commands(member_id),
+			vec![S2CCommand::CommandRejected(CommandRejectedCommand {
+				command_type_id: CommandTypeId::IncrementLongWithBounds,
+				reason: CommandRejectReason::IncrementOutOfBounds,
+				object_id: Some(object_id),
+				field_id: Some(5),
+			})]
+		);
+	}
+
 	#[test]
 	fn should_send_member_connected_to_super_members() {
 		let template = RoomTemplate::default();
@@ -759,6 +1382,84 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_limit_transaction_mutations() {
+		let template = RoomTemplate {
+			rate_limits: Some(RateLimitsTemplate {
+				limits: vec![RateLimitTemplate {
+					command_type_id: CommandTypeId::SetLong,
+					field_id: None,
+					burst: 1,
+					per_second: 0,
+				}],
+				warn_after: None,
+				disconnect_after: None,
+			}),
+			..Default::default()
+		};
+		let mut room = Room::from_template(template);
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_id), AccessGroups(10))
+			.id;
+		let set_field = |field_id| {
+			TransactionMutation::SetField(SetFieldCommand {
+				object_id,
+				field_id,
+				value: 1.into(),
+			})
+		};
+		let command = CommandWithChannel {
+			channel: Channel::ReliableUnordered,
+			both_direction_command: BothDirectionCommand::C2S(C2SCommand::Transaction(TransactionCommand {
+				preconditions: vec![],
+				mutations: vec![set_field(1), set_field(2)],
+			})),
+		};
+		room.execute_commands(member_id, slice::from_ref(&command));
+
+		let object = room.get_object(object_id).unwrap();
+		assert!(object.get_field::<i64>(1).is_none());
+		assert!(object.get_field::<i64>(2).is_none());
+	}
+
+	#[test]
+	fn should_drop_commands_over_rate_limit() {
+		let template = RoomTemplate {
+			rate_limits: Some(RateLimitsTemplate {
+				limits: vec![RateLimitTemplate {
+					command_type_id: CommandTypeId::CreateGameObject,
+					field_id: None,
+					burst: 2,
+					per_second: 0,
+				}],
+				warn_after: None,
+				disconnect_after: Some(2),
+			}),
+			..Default::default()
+		};
+		let mut room = Room::from_template(template);
+		let member_id = room.register_member(MemberTemplate::stub(AccessGroups(10)));
+		let super_member_id = room.register_member(MemberTemplate::new_super_member());
+
+		let commands: Vec<_> = (1..=5).map(get_create_game_object_command).collect();
+		room.execute_commands(member_id, &commands);
+		assert_eq!(room.objects.len(), 2);
+		assert_eq!(
+			room.members_to_disconnect,
+			vec![(member_id, DisconnectByCommandReason::CommandRateLimitExceeded)]
+		);
+
+		// команды пользователя, ожидающего отключения, не выполняются
+		room.execute_commands(member_id, slice::from_ref(&get_create_game_object_command(10)));
+		assert_eq!(room.objects.len(), 2);
+
+		// super member не ограничивается
+		let commands: Vec<_> = (20..23).map(get_create_game_object_command).collect();
+		room.execute_commands(super_member_id, &commands);
+		assert_eq!(room.objects.len(), 5);
+	}
+
 	pub(crate) fn create_template() -> (RoomTemplate, MemberTemplate) {
 		let template = RoomTemplate::default();
 		let member_template = MemberTemplate::new_member(AccessGroups(55), Default::default());
@@ -772,6 +1473,8 @@ mod tests {
 				object_id: GameObjectId::new(object_id, GameObjectOwner::Room),
 				template: 0,
 				access_groups: Default::default(),
+				parent: None,
+				inherit_access_groups: false,
 			})),
 		}
 	}
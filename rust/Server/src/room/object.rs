@@ -1,18 +1,27 @@
 use thiserror::Error;
 
+use cheetah_common::commands::binary_value::BinaryValue;
 use cheetah_common::commands::field::{Field, FieldId};
 use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
 use cheetah_common::commands::types::create::{CreateGameObjectCommand, GameObjectCreatedS2CCommand};
+use cheetah_common::commands::types::list::ListPushCommand;
+use cheetah_common::commands::types::map::{MapItems, MapSetCommand};
+use cheetah_common::commands::types::ownership::TransferOwnershipCommand;
 use cheetah_common::commands::{field::ToFieldType, FieldType, FieldValue};
 use cheetah_common::constants::GameObjectTemplateId;
 use cheetah_common::room::access::AccessGroups;
 use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::owner::GameObjectOwner;
 use cheetah_common::room::RoomMemberId;
 
 pub const MAX_FIELD_COUNT: usize = 64;
 
 type FieldIndex = heapless::FnvIndexMap<(FieldId, FieldType), FieldValue, { MAX_FIELD_COUNT * 4 }>;
-pub type CreateCommandsCollector = heapless::Vec<S2CCommandWithMeta, 255>;
+///
+/// Команды создания объекта, размер не ограничен - списки и словари передаются поэлементно
+/// и для объекта с заполненными полями команд может быть несколько тысяч
+///
+pub type CreateCommandsCollector = Vec<S2CCommandWithMeta>;
 
 ///
 /// Игровой объект - логическая группировка игровых данных
@@ -26,6 +35,22 @@ pub struct GameObject {
 	/// Объект полностью создан
 	///
 	pub created: bool,
+	///
+	/// Время сервера в миллисекундах, после которого объект удаляется
+	///
+	pub expire_at: Option<u64>,
+	///
+	/// Родительский объект, объект удаляется вместе с ним
+	///
+	pub parent: Option<GameObjectId>,
+	///
+	/// Группы доступа наследуются от родительского объекта, в том числе при их изменении
+	///
+	pub inherit_access_groups: bool,
+	///
+	/// Текущий владелец объекта, изначально - владелец из идентификатора, может быть изменен передачей владения
+	///
+	pub owner: GameObjectOwner,
 	fields: FieldIndex,
 	compare_and_set_owners: heapless::FnvIndexMap<FieldId, RoomMemberId, MAX_FIELD_COUNT>,
 }
@@ -44,6 +69,10 @@ impl GameObject {
 			template_id,
 			access_groups,
 			created,
+			expire_at: None,
+			parent: None,
+			inherit_access_groups: false,
+			owner: id.get_owner(),
 			fields: Default::default(),
 			compare_and_set_owners: Default::default(),
 		}
@@ -89,6 +118,40 @@ impl GameObject {
 			.map_err(|_| GameObjectError::FieldCountOverflow(self.id, self.template_id))
 	}
 
+	pub fn get_list_mut(&mut self, field_id: FieldId) -> Option<&mut Vec<BinaryValue>> {
+		match self.fields.get_mut(&(field_id, FieldType::List)) {
+			Some(FieldValue::List(items)) => Some(items),
+			_ => None,
+		}
+	}
+
+	///
+	/// Список для изменения, если поля нет - создается пустой список
+	///
+	pub fn get_or_create_list_mut(&mut self, field_id: FieldId) -> Result<&mut Vec<BinaryValue>, GameObjectError> {
+		if self.get_list_mut(field_id).is_none() {
+			self.set_field_wrapped(field_id, FieldValue::List(Vec::new()))?;
+		}
+		Ok(self.get_list_mut(field_id).expect("list field must exist"))
+	}
+
+	pub fn get_map_mut(&mut self, field_id: FieldId) -> Option<&mut MapItems> {
+		match self.fields.get_mut(&(field_id, FieldType::Map)) {
+			Some(FieldValue::Map(items)) => Some(items),
+			_ => None,
+		}
+	}
+
+	///
+	/// Словарь для изменения, если поля нет - создается пустой словарь
+	///
+	pub fn get_or_create_map_mut(&mut self, field_id: FieldId) -> Result<&mut MapItems, GameObjectError> {
+		if self.get_map_mut(field_id).is_none() {
+			self.set_field_wrapped(field_id, FieldValue::Map(MapItems::new()))?;
+		}
+		Ok(self.get_map_mut(field_id).expect("map field must exist"))
+	}
+
 	#[must_use]
 	pub fn get_compare_and_set_owners(&self) -> &heapless::FnvIndexMap<FieldId, RoomMemberId, MAX_FIELD_COUNT> {
 		&self.compare_and_set_owners
@@ -108,12 +171,6 @@ impl GameObject {
 	}
 
 	pub fn collect_create_commands(&self, commands: &mut CreateCommandsCollector, member_id: RoomMemberId) {
-		if self.do_collect_create_commands(commands, member_id).is_err() {
-			tracing::error!("Collect create commands overflow {:?}", self);
-		}
-	}
-
-	fn do_collect_create_commands(&self, commands: &mut CreateCommandsCollector, member_id: RoomMemberId) -> Result<(), S2CCommandWithMeta> {
 		commands.push(S2CCommandWithMeta {
 			field: None,
 			creator: member_id,
@@ -121,31 +178,79 @@ impl GameObject {
 				object_id: self.id,
 				template: self.template_id,
 				access_groups: self.access_groups,
+				parent: self.parent,
+				inherit_access_groups: self.inherit_access_groups,
 			}),
-		})?;
+		});
 
-		self.fields_to_commands(commands, member_id)?;
+		if let GameObjectOwner::Member(new_owner) = self.owner {
+			if self.owner != self.id.get_owner() {
+				commands.push(S2CCommandWithMeta {
+					field: None,
+					creator: member_id,
+					command: S2CCommand::TransferOwnership(TransferOwnershipCommand {
+						object_id: self.id,
+						new_owner,
+					}),
+				});
+			}
+		}
+
+		for (&(field_id, field_type), _) in self.fields() {
+			self.field_to_commands(commands, Field { id: field_id, field_type }, member_id);
+		}
 
 		if self.created {
 			commands.push(S2CCommandWithMeta {
 				field: None,
 				creator: member_id,
 				command: S2CCommand::Created(GameObjectCreatedS2CCommand { object_id: self.id }),
-			})?;
+			});
 		}
-		Ok(())
 	}
 
-	fn fields_to_commands(&self, commands: &mut CreateCommandsCollector, member_id: RoomMemberId) -> Result<(), S2CCommandWithMeta> {
-		for (&(field_id, field_type), v) in self.fields() {
-			let command = S2CCommandWithMeta {
-				field: Some(Field { id: field_id, field_type }),
-				creator: member_id,
-				command: S2CCommand::new_set_command(v.clone(), self.id, field_id),
-			};
-			commands.push(command)?;
+	///
+	/// Команды установки текущего значения поля, для отсутствующего поля команды не добавляются
+	///
+	pub(crate) fn field_to_commands(&self, commands: &mut CreateCommandsCollector, field: Field, member_id: RoomMemberId) {
+		let Some(value) = self.get_field_wrapped(field.id, field.field_type) else {
+			return;
+		};
+		let field_id = field.id;
+		let field = Some(field);
+		// списки и словари передаются поэлементно, так как целиком могут не поместиться в пакет
+		match value {
+			FieldValue::List(items) => {
+				commands.extend(items.iter().map(|&value| S2CCommandWithMeta {
+					field,
+					creator: member_id,
+					command: S2CCommand::ListPush(ListPushCommand {
+						object_id: self.id,
+						field_id,
+						value,
+					}),
+				}));
+			}
+			FieldValue::Map(items) => {
+				commands.extend(items.iter().map(|(&key, value)| S2CCommandWithMeta {
+					field,
+					creator: member_id,
+					command: S2CCommand::MapSet(MapSetCommand {
+						object_id: self.id,
+						field_id,
+						key,
+						value: value.clone(),
+					}),
+				}));
+			}
+			_ => {
+				commands.push(S2CCommandWithMeta {
+					field,
+					creator: member_id,
+					command: S2CCommand::new_set_command(value.clone(), self.id, field_id),
+				});
+			}
 		}
-		Ok(())
 	}
 }
 
@@ -154,6 +259,8 @@ mod tests {
 	use cheetah_common::commands::binary_value::BinaryValue;
 	use cheetah_common::commands::field::Field;
 	use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+	use cheetah_common::commands::types::list::MAX_LIST_ITEMS;
+	use cheetah_common::commands::types::map::{MapItems, MapValue, MAX_MAP_ITEMS};
 	use cheetah_common::commands::FieldType;
 	use cheetah_common::room::access::AccessGroups;
 	use cheetah_common::room::object::GameObjectId;
@@ -261,6 +368,100 @@ mod tests {
 			if c.object_id==id && c.field_id== 1 && c.value == 100.into()));
 	}
 
+	///
+	/// Список передается поэлементно
+	///
+	#[test]
+	pub(crate) fn should_collect_list_as_push_commands() {
+		let id = GameObjectId::new(1, GameObjectOwner::Room);
+		let mut object = GameObject::new(id, 0, Default::default(), false);
+		let items = vec![BinaryValue::from([1].as_ref()), BinaryValue::from([2].as_ref())];
+		object.set_field(1, items.clone()).unwrap();
+
+		let mut commands = CreateCommandsCollector::new();
+		object.collect_create_commands(&mut commands, u16::MAX);
+		assert_eq!(commands.len(), 3);
+		for (command, item) in commands[1..].iter().zip(items) {
+			assert!(matches!(
+				command,
+				S2CCommandWithMeta {
+					field: Some(Field { id: 1, field_type: FieldType::List }),
+					creator: u16::MAX,
+					command: S2CCommand::ListPush(c)
+				}
+				if c.object_id == id && c.field_id == 1 && c.value == item
+			));
+		}
+	}
+
+	///
+	/// Заполненные списки не должны вытеснять команду Created
+	///
+	#[test]
+	pub(crate) fn should_collect_several_full_lists() {
+		let id = GameObjectId::new(1, GameObjectOwner::Room);
+		let mut object = GameObject::new(id, 0, Default::default(), true);
+		let items: Vec<_> = (0..MAX_LIST_ITEMS).map(|i| BinaryValue::from([i as u8].as_ref())).collect();
+		for field_id in 0..8 {
+			object.set_field(field_id, items.clone()).unwrap();
+		}
+
+		let mut commands = CreateCommandsCollector::new();
+		object.collect_create_commands(&mut commands, u16::MAX);
+		assert_eq!(commands.len(), 8 * MAX_LIST_ITEMS + 2);
+		assert_eq!(
+			commands.iter().filter(|c| matches!(c.command, S2CCommand::ListPush(_))).count(),
+			8 * MAX_LIST_ITEMS
+		);
+		assert!(matches!(commands.last().unwrap().command, S2CCommand::Created(ref c) if c.object_id == id));
+	}
+
+	///
+	/// Заполненные словари передаются поэлементно и не вытесняют команду Created
+	///
+	#[test]
+	pub(crate) fn should_collect_several_full_maps() {
+		let id = GameObjectId::new(1, GameObjectOwner::Room);
+		let mut object = GameObject::new(id, 0, Default::default(), true);
+		let items: MapItems = (0..MAX_MAP_ITEMS as u64)
+			.map(|key| (key, MapValue::Binary(BinaryValue::from([0; 200].as_ref()))))
+			.collect();
+		for field_id in 0..4 {
+			object.set_field_wrapped(field_id, FieldValue::Map(items.clone())).unwrap();
+		}
+
+		let mut commands = CreateCommandsCollector::new();
+		object.collect_create_commands(&mut commands, u16::MAX);
+		assert_eq!(
+			commands.iter().filter(|c| matches!(c.command, S2CCommand::MapSet(_))).count(),
+			4 * MAX_MAP_ITEMS
+		);
+		assert!(matches!(commands.last().unwrap().command, S2CCommand::Created(ref c) if c.object_id == id));
+	}
+
+	///
+	/// Для объекта с переданным владением владелец передается после команды создания
+	///
+	#[test]
+	pub(crate) fn should_collect_transfer_ownership_command() {
+		let id = GameObjectId::new(1, GameObjectOwner::Member(1));
+		let mut object = GameObject::new(id, 0, Default::default(), true);
+		object.owner = GameObjectOwner::Member(2);
+
+		let mut commands = CreateCommandsCollector::new();
+		object.collect_create_commands(&mut commands, u16::MAX);
+		assert_eq!(commands.len(), 3);
+		assert!(matches!(
+			&commands[1],
+			S2CCommandWithMeta {
+				field: None,
+				creator: u16::MAX,
+				command: S2CCommand::TransferOwnership(c)
+			}
+			if c.object_id == id && c.new_owner == 2
+		));
+	}
+
 	#[test]
 	pub(crate) fn should_update_structure() {
 		let mut object = GameObject::new(GameObjectId::default(), 0, Default::default(), false);
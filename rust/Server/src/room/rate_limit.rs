@@ -0,0 +1,213 @@
+use std::time::{Duration, Instant};
+
+use fnv::FnvHashMap;
+
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::CommandTypeId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::template::config::{RateLimitTemplate, RateLimitsTemplate};
+
+///
+/// Время без отброшенных команд, после которого счетчик нарушений пользователя сбрасывается
+///
+pub const VIOLATIONS_RESET_INTERVAL: Duration = Duration::from_secs(10);
+
+type LimitKey = (CommandTypeId, Option<FieldId>);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RateLimitResult {
+	Allowed,
+	Dropped,
+	///
+	/// Команда отброшена, количество нарушений пользователя достигло порога предупреждения
+	///
+	Warn,
+	///
+	/// Команда отброшена, количество нарушений пользователя достигло порога отключения
+	///
+	Disconnect,
+}
+
+///
+/// Ограничение частоты команд пользователей по алгоритму token bucket
+///
+#[derive(Debug)]
+pub struct RateLimiter {
+	limits: FnvHashMap<LimitKey, RateLimitTemplate>,
+	warn_after: Option<u32>,
+	disconnect_after: Option<u32>,
+	members: FnvHashMap<RoomMemberId, MemberRateLimits>,
+}
+
+#[derive(Debug, Default)]
+struct MemberRateLimits {
+	buckets: FnvHashMap<LimitKey, TokenBucket>,
+	violations: u32,
+	last_violation: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+	tokens: f64,
+	updated: Instant,
+}
+
+impl RateLimiter {
+	#[must_use]
+	pub fn new(template: &RateLimitsTemplate) -> Self {
+		Self {
+			limits: template
+				.limits
+				.iter()
+				.map(|limit| ((limit.command_type_id, limit.field_id), limit.clone()))
+				.collect(),
+			warn_after: template.warn_after,
+			disconnect_after: template.disconnect_after,
+			members: Default::default(),
+		}
+	}
+
+	///
+	/// Учесть команду пользователя, лимит поля имеет приоритет над лимитом типа команды
+	///
+	pub fn check(&mut self, member_id: RoomMemberId, command_type_id: CommandTypeId, field_id: Option<FieldId>, now: Instant) -> RateLimitResult {
+		let Some((key, limit)) = self
+			.limits
+			.get_key_value(&(command_type_id, field_id))
+			.or_else(|| self.limits.get_key_value(&(command_type_id, None)))
+		else {
+			return RateLimitResult::Allowed;
+		};
+		let member = self.members.entry(member_id).or_default();
+		let bucket = member.buckets.entry(*key).or_insert_with(|| TokenBucket {
+			tokens: f64::from(limit.burst),
+			updated: now,
+		});
+		if bucket.try_take(limit, now) {
+			return RateLimitResult::Allowed;
+		}
+
+		let violations = member.add_violation(now);
+		if self.disconnect_after.is_some_and(|disconnect_after| violations >= disconnect_after) {
+			RateLimitResult::Disconnect
+		} else if self.warn_after == Some(violations) {
+			RateLimitResult::Warn
+		} else {
+			RateLimitResult::Dropped
+		}
+	}
+
+	pub fn remove_member(&mut self, member_id: RoomMemberId) {
+		self.members.remove(&member_id);
+	}
+}
+
+impl MemberRateLimits {
+	fn add_violation(&mut self, now: Instant) -> u32 {
+		if self
+			.last_violation
+			.is_some_and(|last_violation| now.saturating_duration_since(last_violation) >= VIOLATIONS_RESET_INTERVAL)
+		{
+			self.violations = 0;
+		}
+		self.last_violation = Some(now);
+		self.violations = self.violations.saturating_add(1);
+		self.violations
+	}
+}
+
+impl TokenBucket {
+	fn try_take(&mut self, limit: &RateLimitTemplate, now: Instant) -> bool {
+		let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+		self.tokens = (self.tokens + elapsed * f64::from(limit.per_second)).min(f64::from(limit.burst));
+		self.updated = now;
+		if self.tokens >= 1.0 {
+			self.tokens -= 1.0;
+			true
+		} else {
+			false
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{Duration, Instant};
+
+	use cheetah_common::commands::CommandTypeId;
+
+	use crate::room::rate_limit::{RateLimitResult, RateLimiter, VIOLATIONS_RESET_INTERVAL};
+	use crate::room::template::config::{RateLimitTemplate, RateLimitsTemplate};
+
+	#[test]
+	fn should_refill_tokens() {
+		let mut limiter = RateLimiter::new(&template(None, None));
+		let now = Instant::now();
+		assert_eq!(limiter.check(1, CommandTypeId::Event, None, now), RateLimitResult::Allowed);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, None, now), RateLimitResult::Allowed);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, None, now), RateLimitResult::Dropped);
+		// лимиты пользователей не зависят друг от друга
+		assert_eq!(limiter.check(2, CommandTypeId::Event, None, now), RateLimitResult::Allowed);
+
+		let now = now + Duration::from_millis(100);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, None, now), RateLimitResult::Allowed);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, None, now), RateLimitResult::Dropped);
+	}
+
+	#[test]
+	fn should_prefer_field_limit() {
+		let mut template = template(None, None);
+		template.limits.push(RateLimitTemplate {
+			command_type_id: CommandTypeId::Event,
+			field_id: Some(5),
+			burst: 1,
+			per_second: 1,
+		});
+		let mut limiter = RateLimiter::new(&template);
+		let now = Instant::now();
+		assert_eq!(limiter.check(1, CommandTypeId::Event, Some(5), now), RateLimitResult::Allowed);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, Some(5), now), RateLimitResult::Dropped);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, Some(6), now), RateLimitResult::Allowed);
+		assert_eq!(limiter.check(1, CommandTypeId::SetLong, Some(5), now), RateLimitResult::Allowed);
+	}
+
+	#[test]
+	fn should_escalate_violations() {
+		let mut limiter = RateLimiter::new(&template(Some(2), Some(3)));
+		let now = Instant::now();
+		limiter.check(1, CommandTypeId::Event, None, now);
+		limiter.check(1, CommandTypeId::Event, None, now);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, None, now), RateLimitResult::Dropped);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, None, now), RateLimitResult::Warn);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, None, now), RateLimitResult::Disconnect);
+	}
+
+	#[test]
+	fn should_reset_violations() {
+		let mut limiter = RateLimiter::new(&template(None, Some(2)));
+		let now = Instant::now();
+		limiter.check(1, CommandTypeId::Event, None, now);
+		limiter.check(1, CommandTypeId::Event, None, now);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, None, now), RateLimitResult::Dropped);
+
+		let now = now + VIOLATIONS_RESET_INTERVAL;
+		limiter.check(1, CommandTypeId::Event, None, now);
+		limiter.check(1, CommandTypeId::Event, None, now);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, None, now), RateLimitResult::Dropped);
+		assert_eq!(limiter.check(1, CommandTypeId::Event, None, now), RateLimitResult::Disconnect);
+	}
+
+	fn template(warn_after: Option<u32>, disconnect_after: Option<u32>) -> RateLimitsTemplate {
+		RateLimitsTemplate {
+			limits: vec![RateLimitTemplate {
+				command_type_id: CommandTypeId::Event,
+				field_id: None,
+				burst: 2,
+				per_second: 10,
+			}],
+			warn_after,
+			disconnect_after,
+		}
+	}
+}
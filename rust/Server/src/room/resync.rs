@@ -0,0 +1,161 @@
+use cheetah_common::commands::field::Field;
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+use cheetah_common::commands::types::delete::DeleteGameObjectCommand;
+use cheetah_common::commands::types::field::DeleteFieldCommand;
+use cheetah_common::constants::GameObjectTemplateId;
+use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::owner::GameObjectOwner;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::room::get_depth;
+use crate::room::command::ServerCommandError;
+use crate::room::object::{CreateCommandsCollector, GameObject};
+use crate::room::subscription::Subscription;
+use crate::room::template::config::Permission;
+use crate::room::Room;
+
+///
+/// Команды пересинхронизации объектов, видимость которых для пользователя изменилась
+///
+#[derive(Default)]
+pub(crate) struct ObjectsResync {
+	deletes: Vec<(GameObjectTemplateId, S2CCommandWithMeta)>,
+	creates: Vec<(GameObjectTemplateId, CreateCommandsCollector)>,
+}
+
+impl Room {
+	///
+	/// Собрать команды пересинхронизации созданных объектов, отобранных `changed`: для видимых после изменения (`visible`)
+	/// собирается создание, для остальных - удаление
+	///
+	pub(crate) fn collect_objects_resync<C, V>(&self, member_id: RoomMemberId, changed: C, visible: V) -> ObjectsResync
+	where
+		C: Fn(&GameObject) -> bool,
+		V: Fn(&GameObject) -> bool,
+	{
+		let mut changed: Vec<_> = self.objects.values().filter(|o| o.created).filter(|o| changed(o)).collect();
+		// родительские объекты создаются раньше дочерних и удаляются позже
+		changed.sort_by_cached_key(|o| get_depth(self, o));
+		let (entered, left): (Vec<_>, Vec<_>) = changed.into_iter().partition(|o| visible(o));
+		ObjectsResync {
+			deletes: left
+				.into_iter()
+				.rev()
+				.map(|o| {
+					let command = S2CCommandWithMeta {
+						field: None,
+						creator: u16::MAX,
+						command: S2CCommand::Delete(DeleteGameObjectCommand { object_id: o.id }),
+					};
+					(o.template_id, command)
+				})
+				.collect(),
+			creates: entered
+				.into_iter()
+				.map(|o| {
+					let mut commands = CreateCommandsCollector::new();
+					o.collect_create_commands(&mut commands, member_id);
+					(o.template_id, commands)
+				})
+				.collect(),
+		}
+	}
+
+	///
+	/// Пересинхронизировать поля объекта, оставшегося доступным пользователю, после изменения его групп или владения объектом:
+	/// поля, ставшие доступными на чтение, отправляются, ставшие недоступными - удаляются
+	///
+	pub(crate) fn resync_object_fields(
+		&mut self,
+		member_id: RoomMemberId,
+		object_id: GameObjectId,
+		previous_groups: AccessGroups,
+		was_owner: bool,
+	) -> Result<(), ServerCommandError> {
+		let groups = self.get_member(&member_id)?.template.groups;
+		let object = self.get_object(object_id)?;
+		if !object.created || !object.access_groups.contains_any(&groups) {
+			return Ok(());
+		}
+		let is_owner = object.owner == GameObjectOwner::Member(member_id);
+		let template = object.template_id;
+		let mut commands = CreateCommandsCollector::new();
+		{
+			let permission_manager = self.permission_manager.borrow();
+			for &(id, field_type) in object.fields().keys() {
+				let field = Field { id, field_type };
+				let readable = permission_manager.get_permission(template, field, groups, is_owner) > Permission::Deny;
+				let was_readable = permission_manager.get_permission(template, field, previous_groups, was_owner) > Permission::Deny;
+				match (was_readable, readable) {
+					(false, true) => object.field_to_commands(&mut commands, field, u16::MAX),
+					(true, false) => commands.push(delete_field_command(object_id, field)),
+					_ => {}
+				}
+			}
+		}
+		self.send_to_member(&member_id, template, &commands)
+	}
+
+	///
+	/// Пересинхронизировать поля загруженного пользователем объекта после изменения подписки на поля:
+	/// доступные на чтение поля, на которые пользователь подписался, отправляются, поля, от которых отписался - удаляются
+	///
+	pub(crate) fn resync_subscribed_fields(
+		&mut self,
+		member_id: RoomMemberId,
+		object_id: GameObjectId,
+		previous: &Subscription,
+	) -> Result<(), ServerCommandError> {
+		let member = self.get_member(&member_id)?;
+		let groups = member.template.groups;
+		let object = self.get_object(object_id)?;
+		let is_owner = object.owner == GameObjectOwner::Member(member_id);
+		let template = object.template_id;
+		let mut commands = CreateCommandsCollector::new();
+		{
+			let permission_manager = self.permission_manager.borrow();
+			for &(id, field_type) in object.fields().keys() {
+				let field = Field { id, field_type };
+				if permission_manager.get_permission(template, field, groups, is_owner) == Permission::Deny {
+					continue;
+				}
+				match (previous.is_field_subscribed(id), member.subscription.is_field_subscribed(id)) {
+					(false, true) => object.field_to_commands(&mut commands, field, u16::MAX),
+					(true, false) => commands.push(delete_field_command(object_id, field)),
+					_ => {}
+				}
+			}
+		}
+		self.send_to_member(&member_id, template, &commands)
+	}
+}
+
+fn delete_field_command(object_id: GameObjectId, field: Field) -> S2CCommandWithMeta {
+	S2CCommandWithMeta {
+		// без поля - иначе команда будет отфильтрована по правам или подписке, которых у пользователя уже нет
+		field: None,
+		creator: u16::MAX,
+		command: S2CCommand::DeleteField(DeleteFieldCommand {
+			field_id: field.id,
+			object_id,
+			field_type: field.field_type,
+		}),
+	}
+}
+
+impl ObjectsResync {
+	pub(crate) fn send_deletes(&mut self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		for (template, command) in self.deletes.drain(..) {
+			room.send_to_member(&member_id, template, &[command])?;
+		}
+		Ok(())
+	}
+
+	pub(crate) fn send_creates(&mut self, room: &mut Room, member_id: RoomMemberId) -> Result<(), ServerCommandError> {
+		for (template, commands) in self.creates.drain(..) {
+			room.send_to_member(&member_id, template, commands.as_slice())?;
+		}
+		Ok(())
+	}
+}
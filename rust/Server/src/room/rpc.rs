@@ -0,0 +1,172 @@
+use fnv::FnvHashMap;
+
+use cheetah_common::commands::types::rpc::RpcCallId;
+use cheetah_common::protocol::frame::channel::ChannelType;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::ServerCommandError;
+
+///
+/// Максимальное количество ожидающих ответа вызовов одного пользователя
+///
+pub const MAX_MEMBER_RPC_CALLS: usize = 64;
+
+///
+/// Вызов, ожидающий ответа
+///
+#[derive(Debug, Clone)]
+pub struct RpcCall {
+	pub caller: RoomMemberId,
+	pub call_id: RpcCallId,
+	pub target: RoomMemberId,
+	///
+	/// Время сервера в миллисекундах от создания комнаты, после которого вызывающему отправляется ошибка
+	///
+	pub deadline: u64,
+	///
+	/// Канал команды запроса, в нем же отправляется сформированный сервером ответ
+	///
+	pub channel: Option<ChannelType>,
+}
+
+///
+/// Ожидающие ответа вызовы пользователей комнаты
+///
+#[derive(Debug, Default)]
+pub struct RpcCalls {
+	calls: FnvHashMap<(RoomMemberId, RpcCallId), RpcCall>,
+}
+
+impl RpcCalls {
+	pub fn add(&mut self, call: RpcCall) -> Result<(), ServerCommandError> {
+		let key = (call.caller, call.call_id);
+		if self.calls.contains_key(&key) {
+			return Err(ServerCommandError::RpcCallAlreadyExists {
+				member_id: call.caller,
+				call_id: call.call_id,
+			});
+		}
+		if self.calls.keys().filter(|(caller, _)| *caller == call.caller).count() >= MAX_MEMBER_RPC_CALLS {
+			return Err(ServerCommandError::RpcCallsOverflow { member_id: call.caller });
+		}
+		self.calls.insert(key, call);
+		Ok(())
+	}
+
+	///
+	/// Извлечь вызов для ответа, отвечать может только вызываемый пользователь
+	///
+	pub fn take(&mut self, caller: RoomMemberId, call_id: RpcCallId, target: RoomMemberId) -> Option<RpcCall> {
+		let key = (caller, call_id);
+		match self.calls.get(&key) {
+			Some(call) if call.target == target => self.calls.remove(&key),
+			_ => None,
+		}
+	}
+
+	///
+	/// Извлечь вызовы с истекшим временем ожидания в порядке времени истечения
+	///
+	pub fn take_expired(&mut self, server_time: u64) -> Vec<RpcCall> {
+		if self.calls.is_empty() {
+			return Default::default();
+		}
+		let mut keys: Vec<_> = self
+			.calls
+			.iter()
+			.filter(|(_, call)| call.deadline <= server_time)
+			.map(|(key, call)| (call.deadline, *key))
+			.collect();
+		keys.sort_unstable();
+		keys.into_iter().filter_map(|(_, key)| self.calls.remove(&key)).collect()
+	}
+
+	///
+	/// Удалить вызовы вышедшего пользователя, возвращаются вызовы, ожидавшие от него ответа
+	///
+	pub fn remove_member(&mut self, member_id: RoomMemberId) -> Vec<RpcCall> {
+		self.calls.retain(|_, call| call.caller != member_id);
+		let keys: Vec<_> = self
+			.calls
+			.iter()
+			.filter(|(_, call)| call.target == member_id)
+			.map(|(key, _)| *key)
+			.collect();
+		keys.into_iter().filter_map(|key| self.calls.remove(&key)).collect()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &RpcCall> {
+		self.calls.values()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::types::rpc::RpcCallId;
+	use cheetah_common::room::RoomMemberId;
+
+	use crate::room::command::ServerCommandError;
+	use crate::room::rpc::{RpcCall, RpcCalls, MAX_MEMBER_RPC_CALLS};
+
+	#[test]
+	fn should_take_call_only_by_target() {
+		let mut calls = RpcCalls::default();
+		calls.add(call(1, 1, 2, 100)).unwrap();
+		assert!(calls.take(1, 1, 3).is_none());
+		assert!(calls.take(1, 2, 2).is_none());
+		assert!(calls.take(1, 1, 2).is_some());
+		assert!(calls.take(1, 1, 2).is_none());
+	}
+
+	#[test]
+	fn should_take_expired_in_order() {
+		let mut calls = RpcCalls::default();
+		calls.add(call(1, 1, 2, 300)).unwrap();
+		calls.add(call(1, 2, 2, 100)).unwrap();
+		calls.add(call(2, 1, 1, 200)).unwrap();
+
+		let expired: Vec<_> = calls.take_expired(250).iter().map(|c| (c.caller, c.call_id)).collect();
+		assert_eq!(expired, vec![(1, 2), (2, 1)]);
+		assert_eq!(calls.iter().count(), 1);
+	}
+
+	#[test]
+	fn should_remove_member_calls() {
+		let mut calls = RpcCalls::default();
+		calls.add(call(1, 1, 2, 100)).unwrap();
+		calls.add(call(2, 1, 1, 100)).unwrap();
+		calls.add(call(3, 1, 2, 100)).unwrap();
+
+		let waiting: Vec<_> = calls.remove_member(1).iter().map(|c| c.caller).collect();
+		assert_eq!(waiting, vec![2]);
+		let rest: Vec<_> = calls.iter().map(|c| c.caller).collect();
+		assert_eq!(rest, vec![3]);
+	}
+
+	#[test]
+	#[allow(clippy::cast_possible_truncation)]
+	fn should_limit_member_calls() {
+		let mut calls = RpcCalls::default();
+		for call_id in 0..MAX_MEMBER_RPC_CALLS {
+			calls.add(call(1, call_id as RpcCallId, 2, 100)).unwrap();
+		}
+		assert_eq!(
+			calls.add(call(1, MAX_MEMBER_RPC_CALLS as RpcCallId, 2, 100)),
+			Err(ServerCommandError::RpcCallsOverflow { member_id: 1 })
+		);
+		assert_eq!(
+			calls.add(call(2, 0, 1, 100)).and_then(|()| calls.add(call(2, 0, 1, 100))),
+			Err(ServerCommandError::RpcCallAlreadyExists { member_id: 2, call_id: 0 })
+		);
+	}
+
+	fn call(caller: RoomMemberId, call_id: RpcCallId, target: RoomMemberId, deadline: u64) -> RpcCall {
+		RpcCall {
+			caller,
+			call_id,
+			target,
+			deadline,
+			channel: None,
+		}
+	}
+}
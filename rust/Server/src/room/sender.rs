@@ -1,14 +1,21 @@
-use cheetah_common::commands::s2c::{S2CCommandWithCreator, S2CCommandWithMeta};
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithCreator, S2CCommandWithMeta};
 use cheetah_common::constants::GameObjectTemplateId;
 use cheetah_common::protocol::commands::output::CommandWithChannelType;
 use cheetah_common::protocol::frame::applications::{BothDirectionCommand, ChannelGroup};
 use cheetah_common::protocol::frame::channel::ChannelType;
 use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::owner::GameObjectOwner;
 use cheetah_common::room::RoomMemberId;
+use fnv::FnvBuildHasher;
+use indexmap::IndexMap;
+use std::mem;
 use std::rc::Rc;
 
 use crate::room::command::ServerCommandError;
+use crate::room::object::GameObject;
 use crate::room::template::config::Permission;
+use crate::room::tick::coalesce;
 use crate::room::{Member, Room};
 
 ///
@@ -31,10 +38,18 @@ impl Room {
 			self.test_out_commands.push_front((access_groups, command.command.clone()));
 		}
 
-		let channel_type = self.current_channel.as_ref().unwrap_or(&ChannelType::ReliableSequence(ChannelGroup(0)));
+		if let (Some(interest), Some(template)) = (self.interest.as_mut(), object_template) {
+			interest.track_commands(template, commands);
+		}
 
+		let channel_type = self.current_channel.as_ref().unwrap_or(&ChannelType::ReliableSequence(ChannelGroup(0)));
+		let structure_delta = self.structure_delta;
+		let tick_mode = self.tick.is_some();
+		let time = self.get_out_commands_time();
 		let permission_manager = Rc::clone(&self.permission_manager);
 		let command_trace_session = Rc::clone(&self.command_trace_session);
+		let interest = self.interest.as_ref();
+		let objects = &self.objects;
 
 		let members_for_send = self
 			.members
@@ -45,14 +60,27 @@ impl Room {
 			.filter(|member| filter(member));
 
 		for member in members_for_send {
+			let groups = member.template.groups;
+			let member_id = member.id;
+			// подписка не изменяется при отправке, забираем её на время отправки для раздельного заимствования
+			let subscription = mem::take(&mut member.subscription);
 			commands
 				.iter()
 				.filter(|&command| {
 					if let Some(template) = object_template {
-						match command.field {
-							None => true,
-							Some(field) => permission_manager.borrow_mut().get_permission(template, field, member.template.groups) > Permission::Deny,
-						}
+						let visible = match (interest, command.command.get_object_id()) {
+							(Some(interest), Some(object_id)) => interest.is_visible(member_id, template, &object_id),
+							_ => true,
+						};
+						visible
+							&& subscription.is_subscribed(template, command.field)
+							&& match command.field {
+								None => true,
+								Some(field) => {
+									let is_owner = is_object_owner(objects, &command.command, member_id);
+									permission_manager.borrow_mut().get_permission(template, field, groups, is_owner) > Permission::Deny
+								}
+							}
 					} else {
 						true
 					}
@@ -65,18 +93,35 @@ impl Room {
 					let member_with_creator = S2CCommandWithCreator {
 						creator: command.creator,
 						command: command.command.clone(),
+						time,
 					};
-
-					member.out_commands.push(CommandWithChannelType {
-						channel_type: *channel_type,
-						command: BothDirectionCommand::S2CWithCreator(member_with_creator),
-					});
+					member.add_out_command(structure_delta, tick_mode, *channel_type, member_with_creator);
 				});
+			member.subscription = subscription;
 		}
 
 		Ok(())
 	}
 
+	///
+	/// Отправить команды в заданном канале, независимо от канала выполняемой команды
+	///
+	pub fn send_to_members_with_channel<T>(
+		&mut self,
+		channel_type: ChannelType,
+		access_groups: AccessGroups,
+		commands: &[S2CCommandWithMeta],
+		filter: T,
+	) -> Result<(), ServerCommandError>
+	where
+		T: Fn(&Member) -> bool,
+	{
+		let current_channel = self.current_channel.replace(channel_type);
+		let result = self.send_to_members(access_groups, None, commands, filter);
+		self.current_channel = current_channel;
+		result
+	}
+
 	pub fn send_to_member(
 		&mut self,
 		member_id: &RoomMemberId,
@@ -86,28 +131,39 @@ impl Room {
 		let command_trace_session = Rc::clone(&self.command_trace_session);
 		let permission_manager = Rc::clone(&self.permission_manager);
 		let channel = self.current_channel.unwrap_or(ChannelType::ReliableSequence(ChannelGroup(0)));
-		let member = self.get_member_mut(member_id)?;
+		let structure_delta = self.structure_delta;
+		let tick_mode = self.tick.is_some();
+		let time = self.get_out_commands_time();
+		let interest = self.interest.as_ref();
+		let objects = &self.objects;
+		let member = self.members.get_mut(member_id).ok_or(ServerCommandError::MemberNotFound(*member_id))?;
 
 		if member.attached && member.connected {
 			let groups = member.template.groups;
 			for command in commands {
-				let allow = match command.field {
-					None => true,
-					Some(field) => permission_manager.borrow_mut().get_permission(object_template, field, groups) > Permission::Deny,
+				let visible = match (interest, command.command.get_object_id()) {
+					(Some(interest), Some(object_id)) => interest.is_visible(member.id, object_template, &object_id),
+					_ => true,
 				};
+				let allow = visible
+					&& member.subscription.is_subscribed(object_template, command.field)
+					&& match command.field {
+						None => true,
+						Some(field) => {
+							let is_owner = is_object_owner(objects, &command.command, member.id);
+							permission_manager.borrow_mut().get_permission(object_template, field, groups, is_owner) > Permission::Deny
+						}
+					};
 				if allow {
 					let command_with_meta = S2CCommandWithCreator {
 						creator: command.creator,
 						command: command.command.clone(),
+						time,
 					};
 					command_trace_session
 						.borrow_mut()
 						.collect_s2c(Some(object_template), member.id, &command.command);
-
-					member.out_commands.push(CommandWithChannelType {
-						channel_type: channel,
-						command: BothDirectionCommand::S2CWithCreator(command_with_meta),
-					});
+					member.add_out_command(structure_delta, tick_mode, channel, command_with_meta);
 				}
 			}
 		}
@@ -115,8 +171,54 @@ impl Room {
 	}
 }
 
+///
+/// Пользователь является владельцем объекта, к которому относится команда
+///
+fn is_object_owner(objects: &IndexMap<GameObjectId, GameObject, FnvBuildHasher>, command: &S2CCommand, member_id: RoomMemberId) -> bool {
+	command
+		.get_object_id()
+		.and_then(|object_id| objects.get(&object_id))
+		.is_some_and(|object| object.owner == GameObjectOwner::Member(member_id))
+}
+
+impl Member {
+	///
+	/// В режиме тиков команда будет отправлена в конце тика, иначе - сразу
+	///
+	fn add_out_command(&mut self, structure_delta: bool, tick_mode: bool, channel_type: ChannelType, command: S2CCommandWithCreator) {
+		if tick_mode {
+			self.tick_commands.push((channel_type, command));
+		} else {
+			self.push_out_command(structure_delta, channel_type, command);
+		}
+	}
+
+	///
+	/// Отправить накопленные за тик команды, оставив только последние значения полей
+	///
+	pub(crate) fn flush_tick_commands(&mut self, structure_delta: bool) {
+		let mut commands = mem::take(&mut self.tick_commands);
+		coalesce(&mut commands);
+		for (channel_type, command) in commands.drain(..) {
+			self.push_out_command(structure_delta, channel_type, command);
+		}
+		self.tick_commands = commands;
+	}
+
+	fn push_out_command(&mut self, structure_delta: bool, channel_type: ChannelType, mut command: S2CCommandWithCreator) {
+		if structure_delta {
+			command.command = self.structure_delta_encoder.encode(channel_type, command.command);
+		}
+		self.out_commands.push(CommandWithChannelType {
+			channel_type,
+			command: BothDirectionCommand::S2CWithCreator(command),
+		});
+	}
+}
+
 #[cfg(test)]
 mod tests {
+	use cheetah_common::commands::binary_value::BinaryValue;
 	use cheetah_common::commands::field::Field;
 	use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithCreator, S2CCommandWithMeta};
 	use cheetah_common::commands::{types::field::SetFieldCommand, FieldType};
@@ -307,7 +409,7 @@ mod tests {
 		let command = out_commands.get(0);
 
 		assert!(
-			matches!(command, Some(S2CCommandWithCreator{creator: _member_source_id, command: S2CCommand::SetField
+			matches!(command, Some(S2CCommandWithCreator{creator: _member_source_id, time: _, command: S2CCommand::SetField
 				(command)}) if command.field_id == allow_field_id)
 		);
 		assert_eq!(out_commands.len(), 1);
@@ -375,6 +477,55 @@ mod tests {
 		assert_eq!(commands.len(), 1);
 	}
 
+	///
+	/// Поле с правами Owner доступно только владельцу объекта
+	///
+	#[test]
+	fn should_send_owner_field_only_to_owner() {
+		let access_groups = AccessGroups(0b111);
+		let object_template = 100;
+		let field = Field {
+			id: 10,
+			field_type: FieldType::Long,
+		};
+
+		let mut template = RoomTemplate::default();
+		template
+			.permissions
+			.set_permission(object_template, &field.id, field.field_type, &access_groups, Permission::Owner);
+
+		let mut room = Room::from_template(template);
+		let member_1 = room.register_member(MemberTemplate::stub(access_groups));
+		let member_2 = room.register_member(MemberTemplate::stub(access_groups));
+		room.test_mark_as_connected(member_1).unwrap();
+		room.test_mark_as_connected(member_2).unwrap();
+
+		let object = room.test_create_object_with_not_created_state(GameObjectOwner::Member(member_1), access_groups);
+		object.created = true;
+		object.template_id = object_template;
+		let object_id = object.id;
+
+		let commands = [S2CCommandWithMeta {
+			field: Some(field),
+			creator: u16::MAX,
+			command: S2CCommand::SetField(SetFieldCommand {
+				object_id,
+				field_id: field.id,
+				value: 155.into(),
+			}),
+		}];
+		room.send_to_members(access_groups, Some(object_template), &commands, |_| true).unwrap();
+
+		assert!(matches!(room.test_get_member_out_commands(member_1).get(0), Some(S2CCommand::SetField(c)) if c.field_id == field.id));
+		assert!(room.test_get_member_out_commands(member_2).is_empty());
+
+		// изменять поле может только владелец
+		room.send_command_from_action(object_id, field, member_2, Permission::Rw, None, |_| Ok(None))
+			.unwrap_err();
+		room.send_command_from_action(object_id, field, member_1, Permission::Rw, None, |_| Ok(None))
+			.unwrap();
+	}
+
 	#[test]
 	fn should_do_action_not_send_if_object_not_created() {
 		let field_id = 10;
@@ -410,4 +561,39 @@ mod tests {
 		let commands = room.test_get_member_out_commands(member_2);
 		assert!(commands.is_empty());
 	}
+	///
+	/// Изменения структуры отправляются в виде разницы, если это разрешено в шаблоне комнаты
+	///
+	#[test]
+	fn should_send_structure_delta() {
+		let access_groups = AccessGroups(0b111);
+		let template = RoomTemplate {
+			structure_delta: true,
+			..Default::default()
+		};
+		let mut room = Room::from_template(template);
+		let member_id = room.register_member(MemberTemplate::stub(access_groups));
+		room.test_mark_as_connected(member_id).unwrap();
+		let object = room.test_create_object_with_not_created_state(GameObjectOwner::Room, access_groups);
+		object.created = true;
+		let object_id = object.id;
+
+		let field_id = 10;
+		let values = [[1, 2, 3, 4, 5, 6, 7, 8], [1, 2, 3, 4, 5, 6, 7, 100]];
+		for value in values {
+			let command = S2CCommandWithMeta {
+				field: Some(Field {
+					id: field_id,
+					field_type: FieldType::Structure,
+				}),
+				creator: u16::MAX,
+				command: S2CCommand::new_set_command(BinaryValue::from(value.as_slice()).into(), object_id, field_id),
+			};
+			room.send_to_members(access_groups, Some(0), &[command], |_| true).unwrap();
+		}
+
+		let commands = room.test_get_member_out_commands(member_id);
+		assert!(matches!(commands.get(0), Some(S2CCommand::SetField(_))));
+		assert!(matches!(commands.get(1), Some(S2CCommand::SetStructureDelta(c)) if c.field_id == field_id));
+	}
 }
@@ -0,0 +1,155 @@
+use fnv::FnvHashSet;
+
+use cheetah_common::commands::field::{Field, FieldId};
+use cheetah_common::commands::types::subscription::SubscriptionCommand;
+use cheetah_common::constants::GameObjectTemplateId;
+
+///
+/// Подписка пользователя на шаблоны объектов и поля, None - подписка на все шаблоны (поля), кроме исключенных
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Subscription {
+	templates: Option<FnvHashSet<GameObjectTemplateId>>,
+	fields: Option<FnvHashSet<FieldId>>,
+	///
+	/// Шаблоны, от которых пользователь отписался при подписке на все шаблоны
+	///
+	excluded_templates: FnvHashSet<GameObjectTemplateId>,
+	///
+	/// Поля, от которых пользователь отписался при подписке на все поля
+	///
+	excluded_fields: FnvHashSet<FieldId>,
+}
+
+impl Subscription {
+	#[must_use]
+	pub fn is_template_subscribed(&self, template: GameObjectTemplateId) -> bool {
+		match &self.templates {
+			None => !self.excluded_templates.contains(&template),
+			Some(templates) => templates.contains(&template),
+		}
+	}
+
+	#[must_use]
+	pub fn is_field_subscribed(&self, field_id: FieldId) -> bool {
+		match &self.fields {
+			None => !self.excluded_fields.contains(&field_id),
+			Some(fields) => fields.contains(&field_id),
+		}
+	}
+
+	///
+	/// Отправлять ли пользователю команду объекта шаблона, команды без поля фильтруются только по шаблону
+	///
+	#[must_use]
+	pub fn is_subscribed(&self, template: GameObjectTemplateId, field: Option<Field>) -> bool {
+		self.is_template_subscribed(template)
+			&& match field {
+				None => true,
+				Some(field) => self.is_field_subscribed(field.id),
+			}
+	}
+
+	///
+	/// Подписаться на шаблоны и поля, первая подписка заменяет подписку на все шаблоны (поля) списком из команды
+	///
+	pub fn subscribe(&mut self, command: &SubscriptionCommand) {
+		if !command.templates.is_empty() {
+			self.excluded_templates.clear();
+			self.templates
+				.get_or_insert_with(Default::default)
+				.extend(command.templates.iter().copied());
+		}
+		if !command.fields.is_empty() {
+			self.excluded_fields.clear();
+			self.fields.get_or_insert_with(Default::default).extend(command.fields.iter().copied());
+		}
+	}
+
+	///
+	/// Отписаться от шаблонов и полей, отписка с пустыми списками сбрасывает подписку
+	///
+	pub fn unsubscribe(&mut self, command: &SubscriptionCommand) {
+		if command.templates.is_empty() && command.fields.is_empty() {
+			*self = Default::default();
+			return;
+		}
+		match self.templates.as_mut() {
+			None => self.excluded_templates.extend(command.templates.iter().copied()),
+			Some(templates) => command.templates.iter().for_each(|template| {
+				templates.remove(template);
+			}),
+		}
+		match self.fields.as_mut() {
+			None => self.excluded_fields.extend(command.fields.iter().copied()),
+			Some(fields) => command.fields.iter().for_each(|field_id| {
+				fields.remove(field_id);
+			}),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::field::Field;
+	use cheetah_common::commands::types::subscription::SubscriptionCommand;
+	use cheetah_common::commands::FieldType;
+
+	use crate::room::subscription::Subscription;
+
+	#[test]
+	fn should_subscribe_all_by_default() {
+		let subscription = Subscription::default();
+		assert!(subscription.is_subscribed(1, None));
+		assert!(subscription.is_subscribed(1, Some(field(10))));
+	}
+
+	#[test]
+	fn should_subscribe_and_unsubscribe() {
+		let mut subscription = Subscription::default();
+		subscription.subscribe(&SubscriptionCommand {
+			templates: vec![1, 2],
+			fields: vec![10],
+		});
+		assert!(subscription.is_subscribed(1, Some(field(10))));
+		assert!(!subscription.is_subscribed(1, Some(field(11))));
+		assert!(!subscription.is_subscribed(3, None));
+
+		subscription.unsubscribe(&SubscriptionCommand {
+			templates: vec![1],
+			fields: vec![],
+		});
+		assert!(!subscription.is_template_subscribed(1));
+		assert!(subscription.is_template_subscribed(2));
+
+		subscription.unsubscribe(&SubscriptionCommand::default());
+		assert_eq!(subscription, Subscription::default());
+	}
+
+	#[test]
+	fn should_unsubscribe_from_all() {
+		let mut subscription = Subscription::default();
+		subscription.unsubscribe(&SubscriptionCommand {
+			templates: vec![1],
+			fields: vec![10],
+		});
+		assert!(!subscription.is_template_subscribed(1));
+		assert!(subscription.is_template_subscribed(2));
+		assert!(!subscription.is_subscribed(2, Some(field(10))));
+		assert!(subscription.is_subscribed(2, Some(field(11))));
+
+		subscription.subscribe(&SubscriptionCommand {
+			templates: vec![1],
+			fields: vec![],
+		});
+		assert!(subscription.is_template_subscribed(1));
+		assert!(!subscription.is_template_subscribed(2));
+	}
+
+	fn field(id: u16) -> Field {
+		Field {
+			id,
+			field_type: FieldType::Long,
+		}
+	}
+}
@@ -1,9 +1,13 @@
 use std::collections::HashMap;
+use std::time::Duration;
 
 use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::types::bounds::Bounds;
+use cheetah_common::commands::types::room_event::RoomEventId;
 use fnv::FnvBuildHasher;
 use num_derive::{FromPrimitive, ToPrimitive};
 
+use cheetah_common::commands::CommandTypeId;
 use cheetah_common::commands::FieldType;
 use cheetah_common::commands::FieldValue;
 use cheetah_common::constants::GameObjectTemplateId;
@@ -21,6 +25,54 @@ pub struct RoomTemplate {
 	pub name: String,
 	pub objects: Vec<GameObjectTemplate>,
 	pub permissions: Permissions,
+	///
+	/// Отправлять изменения структур в виде разницы с последним значением, отправленным пользователю
+	///
+	pub structure_delta: bool,
+	///
+	/// Количество тиков в секунду, если задано - изменения полей отправляются один раз за тик
+	///
+	pub tick_rate: Option<u32>,
+	///
+	/// Время ожидания вводов пользователей для тика, если задано - комната работает в режиме lockstep
+	///
+	pub lockstep_deadline: Option<Duration>,
+	///
+	/// Передавать в командах время сервера в миллисекундах от создания комнаты
+	///
+	pub server_time: bool,
+	///
+	/// Отправлять пользователю информацию о его командах, которые не удалось выполнить
+	///
+	pub notify_rejected_commands: bool,
+	///
+	/// Максимальная длина строковых полей в символах, если не задана - ограничен только размер строки в байтах
+	///
+	pub max_string_length: Option<usize>,
+	///
+	/// Ограничения значений полей при инкрементах
+	///
+	pub field_bounds: Vec<FieldBoundsTemplate>,
+	///
+	/// Время жизни объектов шаблона, по истечении объект удаляется сервером
+	///
+	pub object_ttl: Vec<ObjectTtlTemplate>,
+	///
+	/// Область интереса пользователей, если не задана - изменения объектов отправляются без учета позиции
+	///
+	pub interest: Option<InterestTemplate>,
+	///
+	/// Ограничение частоты команд пользователей, если не задано - команды не ограничиваются
+	///
+	pub rate_limits: Option<RateLimitsTemplate>,
+	///
+	/// Правила проверки изменений полей пользователями
+	///
+	pub field_validations: Vec<FieldValidationTemplate>,
+	///
+	/// Поля, для которых хранится история значений
+	///
+	pub field_history: Vec<FieldHistoryTemplate>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -43,9 +95,153 @@ pub struct GameObjectTemplate {
 	pub fields: HashMap<(FieldId, FieldType), FieldValue, FnvBuildHasher>,
 }
 
+///
+/// Ограничение значения поля объектов шаблона
+///
+#[derive(Debug, Clone)]
+pub struct FieldBoundsTemplate {
+	pub template: GameObjectTemplateId,
+	pub field_id: FieldId,
+	pub bounds: FieldBounds,
+}
+
+///
+/// Время жизни объектов шаблона
+///
+#[derive(Debug, Clone)]
+pub struct ObjectTtlTemplate {
+	pub template: GameObjectTemplateId,
+	pub ttl: Duration,
+}
+
+///
+/// Область интереса - объекты с позицией загружаются пользователю, если находятся рядом с его объектами с позицией
+///
+#[derive(Debug, Clone)]
+pub struct InterestTemplate {
+	///
+	/// Размер ячейки сетки
+	///
+	pub cell_size: f64,
+	///
+	/// Радиус области интереса в ячейках
+	///
+	pub view_radius: u32,
+	pub positions: Vec<PositionFieldsTemplate>,
+}
+
+///
+/// Ограничение частоты команд пользователей, команды сверх лимита отбрасываются
+///
+#[derive(Debug, Clone)]
+pub struct RateLimitsTemplate {
+	pub limits: Vec<RateLimitTemplate>,
+	///
+	/// Количество отброшенных команд пользователя, после которого в лог записывается предупреждение
+	///
+	pub warn_after: Option<u32>,
+	///
+	/// Количество отброшенных команд пользователя, после которого пользователь отключается от комнаты
+	///
+	pub disconnect_after: Option<u32>,
+}
+
+///
+/// Лимит для типа команды, если поле не задано - лимит действует для команд без поля и для полей без собственного лимита
+///
+#[derive(Debug, Clone)]
+pub struct RateLimitTemplate {
+	pub command_type_id: CommandTypeId,
+	pub field_id: Option<FieldId>,
+	///
+	/// Максимальное количество команд подряд
+	///
+	pub burst: u32,
+	///
+	/// Количество команд в секунду
+	///
+	pub per_second: u32,
+}
+
+///
+/// Правило проверки изменения поля типа long или double объектов шаблона
+///
+#[derive(Debug, Clone)]
+pub struct FieldValidationTemplate {
+	pub template: GameObjectTemplateId,
+	pub field: Field,
+	pub rule: FieldValidationRule,
+	pub reaction: FieldValidationReaction,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FieldValidationRule {
+	///
+	/// Значение поля в диапазоне [min, max]
+	///
+	Range { min: f64, max: f64 },
+	///
+	/// Изменение значения за период в диапазоне [min_delta, max_delta], допустимое изменение пропорционально времени с предыдущего изменения
+	///
+	Rate { min_delta: f64, max_delta: f64, period: Duration },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum FieldValidationReaction {
+	///
+	/// Отклонить команду
+	///
+	Reject = 0,
+	///
+	/// Ограничить значение ближайшим допустимым
+	///
+	Clamp,
+	///
+	/// Применить изменение и сообщить о нарушении супер-пользователям
+	///
+	Report,
+}
+
+///
+/// История значений поля типа long или double объектов шаблона за последние `depth`
+///
+#[derive(Debug, Clone)]
+pub struct FieldHistoryTemplate {
+	pub template: GameObjectTemplateId,
+	pub field: Field,
+	pub depth: Duration,
+}
+
+///
+/// Поля типа double с координатами объектов шаблона
+///
+#[derive(Debug, Clone)]
+pub struct PositionFieldsTemplate {
+	pub template: GameObjectTemplateId,
+	pub x_field_id: FieldId,
+	pub y_field_id: FieldId,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FieldBounds {
+	Long(Bounds<i64>),
+	Double(Bounds<f64>),
+}
+
+impl FieldBounds {
+	#[must_use]
+	pub fn field_type(&self) -> FieldType {
+		match self {
+			FieldBounds::Long(_) => FieldType::Long,
+			FieldBounds::Double(_) => FieldType::Double,
+		}
+	}
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct Permissions {
 	pub templates: Vec<GameObjectTemplatePermission>,
+	pub room_events: Vec<RoomEventPermission>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -55,6 +251,15 @@ pub struct GameObjectTemplatePermission {
 	pub fields: Vec<PermissionField>,
 }
 
+///
+/// Права доступа к событию комнаты - [`Permission::Rw`] отправка и получение, [`Permission::Ro`] только получение
+///
+#[derive(Debug, Default, Clone)]
+pub struct RoomEventPermission {
+	pub event_id: RoomEventId,
+	pub rules: Vec<GroupsPermissionRule>,
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct GroupsPermissionRule {
 	pub groups: AccessGroups,
@@ -72,6 +277,10 @@ pub enum Permission {
 	Deny = 0,
 	Ro,
 	Rw,
+	///
+	/// Чтение и запись только для владельца объекта, для остальных пользователей - Deny
+	///
+	Owner,
 }
 
 #[derive(Debug)]
@@ -1,6 +1,7 @@
 use fnv::{FnvHashMap, FnvHashSet};
 use std::cell::RefCell;
 
+use cheetah_common::commands::types::room_event::RoomEventId;
 use cheetah_common::constants::GameObjectTemplateId;
 use cheetah_common::room::access::AccessGroups;
 
@@ -15,6 +16,8 @@ pub struct PermissionManager {
 	write_access_template: FnvHashSet<GameObjectTemplateId>,
 	write_access_fields: FnvHashSet<PermissionFieldKey>,
 
+	room_event_rules: FnvHashMap<RoomEventId, FnvHashMap<AccessGroups, Permission>>,
+
 	cache: RefCell<FnvHashMap<PermissionCachedFieldKey, Permission>>,
 }
 
@@ -28,6 +31,7 @@ struct PermissionFieldKey {
 struct PermissionCachedFieldKey {
 	field_key: PermissionFieldKey,
 	groups: AccessGroups,
+	is_owner: bool,
 }
 
 impl PermissionManager {
@@ -38,8 +42,15 @@ impl PermissionManager {
 		pm
 	}
 
-	pub fn update_permissions(&mut self, permissions: &Permissions) {
+	///
+	/// Сбросить закешированные права, например при изменении групп доступа пользователя
+	///
+	pub fn clear_cache(&self) {
 		self.cache.borrow_mut().clear();
+	}
+
+	pub fn update_permissions(&mut self, permissions: &Permissions) {
+		self.clear_cache();
 		for template in &permissions.templates {
 			let entry = self.template_rules.entry(template.template).or_default();
 			for GroupsPermissionRule { groups, permission } in &template.rules {
@@ -70,6 +81,12 @@ impl PermissionManager {
 				}
 			}
 		}
+		for room_event in &permissions.room_events {
+			let entry = self.room_event_rules.entry(room_event.event_id).or_default();
+			for GroupsPermissionRule { groups, permission } in &room_event.rules {
+				entry.insert(*groups, *permission);
+			}
+		}
 	}
 
 	///
@@ -80,29 +97,49 @@ impl PermissionManager {
 		self.write_access_template.contains(&template) || self.write_access_fields.contains(&PermissionFieldKey { template, field })
 	}
 
+	///
+	/// Права пользователя на поле, `is_owner` - пользователь является владельцем объекта, правило [`Permission::Owner`]
+	/// для владельца равно [`Permission::Rw`], для остальных - [`Permission::Deny`]
+	///
 	#[must_use]
-	pub fn get_permission(&self, template: GameObjectTemplateId, field: Field, groups: AccessGroups) -> Permission {
+	pub fn get_permission(&self, template: GameObjectTemplateId, field: Field, groups: AccessGroups, is_owner: bool) -> Permission {
 		let field_key = PermissionFieldKey { template, field };
 
 		*self
 			.cache
 			.borrow_mut()
-			.entry(PermissionCachedFieldKey { field_key, groups })
+			.entry(PermissionCachedFieldKey { field_key, groups, is_owner })
 			.or_insert_with(|| {
 				if let Some(field_rules) = self.field_rules.get(&field_key) {
-					Self::get_permission_by_group(groups, field_rules)
+					Self::get_permission_by_group(groups, field_rules, is_owner)
 				} else if let Some(template_rules) = self.template_rules.get(&template) {
-					Self::get_permission_by_group(groups, template_rules)
+					Self::get_permission_by_group(groups, template_rules, is_owner)
 				} else {
 					Permission::Rw
 				}
 			})
 	}
 
-	fn get_permission_by_group(member_group: AccessGroups, groups: &FnvHashMap<AccessGroups, Permission>) -> Permission {
+	///
+	/// Права пользователя на событие комнаты, правило [`Permission::Owner`] для событий равно [`Permission::Deny`]
+	///
+	#[must_use]
+	pub fn get_room_event_permission(&self, event_id: RoomEventId, groups: AccessGroups) -> Permission {
+		match self.room_event_rules.get(&event_id) {
+			None => Permission::Rw,
+			Some(rules) => Self::get_permission_by_group(groups, rules, false),
+		}
+	}
+
+	fn get_permission_by_group(member_group: AccessGroups, groups: &FnvHashMap<AccessGroups, Permission>, is_owner: bool) -> Permission {
 		groups
 			.iter()
 			.filter_map(|(group, &permission)| group.contains_any(&member_group).then_some(permission))
+			.map(|permission| match permission {
+				Permission::Owner if is_owner => Permission::Rw,
+				Permission::Owner => Permission::Deny,
+				permission => permission,
+			})
 			.max()
 			.unwrap_or(Permission::Rw)
 	}
@@ -113,7 +150,9 @@ mod tests {
 	use cheetah_common::commands::FieldType;
 	use cheetah_common::room::access::AccessGroups;
 
-	use crate::room::template::config::{GameObjectTemplatePermission, GroupsPermissionRule, Permission, PermissionField, Permissions};
+	use crate::room::template::config::{
+		GameObjectTemplatePermission, GroupsPermissionRule, Permission, PermissionField, Permissions, RoomEventPermission,
+	};
 	use crate::room::template::permission::PermissionManager;
 	use cheetah_common::commands::field::Field;
 
@@ -127,7 +166,8 @@ mod tests {
 					id: 10,
 					field_type: FieldType::Long
 				},
-				AccessGroups(0)
+				AccessGroups(0),
+				false
 			),
 			Permission::Rw
 		);
@@ -161,7 +201,8 @@ mod tests {
 					id: 10,
 					field_type: FieldType::Long
 				},
-				AccessGroups(0b01)
+				AccessGroups(0b01),
+				false
 			),
 			Permission::Rw
 		);
@@ -172,7 +213,8 @@ mod tests {
 					id: 10,
 					field_type: FieldType::Long
 				},
-				AccessGroups(0b1000)
+				AccessGroups(0b1000),
+				false
 			),
 			Permission::Deny
 		);
@@ -212,7 +254,8 @@ mod tests {
 					id: 10,
 					field_type: FieldType::Long
 				},
-				AccessGroups(0b01)
+				AccessGroups(0b01),
+				false
 			),
 			Permission::Deny
 		);
@@ -223,7 +266,8 @@ mod tests {
 					id: 15,
 					field_type: FieldType::Long,
 				},
-				AccessGroups(0b01)
+				AccessGroups(0b01),
+				false
 			),
 			Permission::Rw
 		);
@@ -263,6 +307,7 @@ mod tests {
 				field_type: FieldType::Long,
 			},
 			AccessGroups(0b01),
+			false,
 		);
 		let _ = permissions_manager.get_permission(
 			10,
@@ -271,6 +316,7 @@ mod tests {
 				field_type: FieldType::Long,
 			},
 			AccessGroups(0b01),
+			false,
 		);
 		// удаляем исходные данные
 		permissions_manager.field_rules.clear();
@@ -284,6 +330,7 @@ mod tests {
 					field_type: FieldType::Long,
 				},
 				AccessGroups(0b01),
+				false,
 			),
 			Permission::Deny
 		);
@@ -295,11 +342,57 @@ mod tests {
 					field_type: FieldType::Long,
 				},
 				AccessGroups(0b01),
+				false,
 			),
 			Permission::Rw
 		);
 	}
 
+	#[test]
+	fn should_permission_for_owner() {
+		let mut permissions = Permissions::default();
+		permissions.templates.push(GameObjectTemplatePermission {
+			template: 10,
+			rules: vec![GroupsPermissionRule {
+				groups: AccessGroups(0b11),
+				permission: Permission::Owner,
+			}],
+			fields: Default::default(),
+		});
+		let permissions_manager = PermissionManager::new(&permissions);
+		let field = Field {
+			id: 10,
+			field_type: FieldType::Long,
+		};
+
+		assert_eq!(permissions_manager.get_permission(10, field, AccessGroups(0b01), true), Permission::Rw);
+		assert_eq!(permissions_manager.get_permission(10, field, AccessGroups(0b01), false), Permission::Deny);
+	}
+
+	#[test]
+	fn should_permission_for_room_event() {
+		let mut permissions = Permissions::default();
+		permissions.room_events.push(RoomEventPermission {
+			event_id: 5,
+			rules: vec![
+				GroupsPermissionRule {
+					groups: AccessGroups(0b01),
+					permission: Permission::Rw,
+				},
+				GroupsPermissionRule {
+					groups: AccessGroups(0b10),
+					permission: Permission::Ro,
+				},
+			],
+		});
+		let permissions_manager = PermissionManager::new(&permissions);
+
+		assert_eq!(permissions_manager.get_room_event_permission(5, AccessGroups(0b01)), Permission::Rw);
+		assert_eq!(permissions_manager.get_room_event_permission(5, AccessGroups(0b10)), Permission::Ro);
+		assert_eq!(permissions_manager.get_room_event_permission(5, AccessGroups(0b100)), Permission::Rw);
+		assert_eq!(permissions_manager.get_room_event_permission(6, AccessGroups(0b10)), Permission::Rw);
+	}
+
 	#[test]
 	fn should_not_has_write_access_by_default() {
 		let permissions = Permissions::default();
@@ -404,13 +497,13 @@ mod tests {
 
 		// should have write access
 		assert!(permissions_manager.has_write_access(template, field));
-		assert_eq!(Permission::Rw, permissions_manager.get_permission(template, field, groups));
+		assert_eq!(Permission::Rw, permissions_manager.get_permission(template, field, groups, false));
 
 		// should not have write access after update
 		permissions.templates[0].rules[0].permission = Permission::Ro;
 		permissions_manager.update_permissions(&permissions);
 		assert!(!permissions_manager.has_write_access(template, field));
-		assert_eq!(Permission::Ro, permissions_manager.get_permission(template, field, groups));
+		assert_eq!(Permission::Ro, permissions_manager.get_permission(template, field, groups, false));
 	}
 
 	#[test]
@@ -493,6 +586,7 @@ mod tests {
 					field_type: FieldType::Long,
 				},
 				AccessGroups(0b11),
+				false,
 			)
 		);
 	}
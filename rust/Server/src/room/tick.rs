@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use fnv::FnvBuildHasher;
+
+use cheetah_common::commands::field::FieldId;
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithCreator};
+use cheetah_common::commands::FieldType;
+use cheetah_common::protocol::frame::channel::ChannelType;
+use cheetah_common::room::object::GameObjectId;
+
+///
+/// Работа комнаты в режиме тиков - изменения накапливаются и отправляются пользователям один раз за тик
+///
+#[derive(Debug)]
+pub struct RoomTick {
+	period: Duration,
+	last_tick: Option<Instant>,
+}
+
+impl RoomTick {
+	///
+	/// tick_rate - количество тиков в секунду
+	///
+	#[must_use]
+	pub fn new(tick_rate: u32) -> Self {
+		Self {
+			period: Duration::from_secs(1) / tick_rate.max(1),
+			last_tick: None,
+		}
+	}
+
+	///
+	/// Наступил ли следующий тик, если да - то он становится текущим
+	///
+	pub fn is_next_tick(&mut self, now: Instant) -> bool {
+		match self.last_tick {
+			Some(last_tick) if now.saturating_duration_since(last_tick) < self.period => false,
+			_ => {
+				self.last_tick.replace(now);
+				true
+			}
+		}
+	}
+}
+
+///
+/// Оставляем только последнюю установку значения для каждого поля объекта (last-write-wins),
+/// остальные команды (события, создание и удаление объектов и так далее) сохраняются без изменений.
+///
+/// Инкременты отправляются клиентам в виде установки итогового значения, поэтому последнее значение учитывает все инкременты тика.
+///
+/// Создание/удаление объекта и удаление поля разделяют последовательность установок -
+/// значения до и после таких команд не объединяются.
+///
+pub fn coalesce(commands: &mut Vec<(ChannelType, S2CCommandWithCreator)>) {
+	let mut last_sets: HashMap<(GameObjectId, FieldId, FieldType), ChannelType, FnvBuildHasher> = Default::default();
+	let mut keep = Vec::with_capacity(commands.len());
+	for (channel_type, command) in commands.iter().rev() {
+		let keep_command = match &command.command {
+			S2CCommand::SetField(command) => {
+				let key = (command.object_id, command.field_id, command.value.field_type());
+				match last_sets.insert(key, *channel_type) {
+					Some(last_channel_type) => last_channel_type != *channel_type,
+					None => true,
+				}
+			}
+			S2CCommand::Create(command) => {
+				last_sets.retain(|(object_id, _, _), _| *object_id != command.object_id);
+				true
+			}
+			S2CCommand::Created(command) => {
+				last_sets.retain(|(object_id, _, _), _| *object_id != command.object_id);
+				true
+			}
+			S2CCommand::Delete(command) => {
+				last_sets.retain(|(object_id, _, _), _| *object_id != command.object_id);
+				true
+			}
+			S2CCommand::DeleteField(command) => {
+				last_sets.remove(&(command.object_id, command.field_id, command.field_type));
+				true
+			}
+			_ => true,
+		};
+		keep.push(keep_command);
+	}
+
+	let mut keep = keep.into_iter().rev();
+	commands.retain(|_| keep.next().unwrap_or(true));
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{Duration, Instant};
+
+	use cheetah_common::commands::binary_value::BinaryValue;
+	use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithCreator};
+	use cheetah_common::commands::types::create::GameObjectCreatedS2CCommand;
+	use cheetah_common::commands::types::event::EventCommand;
+	use cheetah_common::protocol::frame::applications::ChannelGroup;
+	use cheetah_common::protocol::frame::channel::ChannelType;
+	use cheetah_common::room::object::GameObjectId;
+
+	use crate::room::tick::{coalesce, RoomTick};
+
+	#[test]
+	fn should_detect_next_tick() {
+		let mut tick = RoomTick::new(10);
+		let now = Instant::now();
+		assert!(tick.is_next_tick(now));
+		assert!(!tick.is_next_tick(now + Duration::from_millis(50)));
+		assert!(tick.is_next_tick(now + Duration::from_millis(100)));
+	}
+
+	#[test]
+	fn should_keep_last_set_field() {
+		let channel = ChannelType::ReliableSequence(ChannelGroup(0));
+		let mut commands = vec![
+			(channel, set(1, 100)),
+			(channel, set(2, 200)),
+			(channel, event()),
+			(channel, set(1, 101)),
+			(channel, set(1, 102)),
+		];
+		coalesce(&mut commands);
+		let commands: Vec<_> = commands.into_iter().map(|(_, c)| c.command).collect();
+		assert_eq!(commands, vec![set(2, 200).command, event().command, set(1, 102).command]);
+	}
+
+	#[test]
+	fn should_not_coalesce_through_created() {
+		let channel = ChannelType::ReliableSequence(ChannelGroup(0));
+		let mut commands = vec![
+			(channel, set(1, 100)),
+			(
+				channel,
+				S2CCommandWithCreator {
+					command: S2CCommand::Created(GameObjectCreatedS2CCommand {
+						object_id: GameObjectId::default(),
+					}),
+					creator: 1,
+					time: None,
+				},
+			),
+			(channel, set(1, 101)),
+		];
+		coalesce(&mut commands);
+		assert_eq!(commands.len(), 3);
+	}
+
+	#[test]
+	fn should_not_coalesce_different_channels() {
+		let mut commands = vec![
+			(ChannelType::ReliableUnordered, set(1, 100)),
+			(ChannelType::UnreliableUnordered, set(1, 101)),
+		];
+		coalesce(&mut commands);
+		assert_eq!(commands.len(), 2);
+	}
+
+	fn set(field_id: u16, value: i64) -> S2CCommandWithCreator {
+		S2CCommandWithCreator {
+			command: S2CCommand::new_set_command(value.into(), GameObjectId::default(), field_id),
+			creator: 1,
+			time: None,
+		}
+	}
+
+	fn event() -> S2CCommandWithCreator {
+		S2CCommandWithCreator {
+			command: S2CCommand::Event(EventCommand {
+				object_id: GameObjectId::default(),
+				field_id: 1,
+				event: BinaryValue::from([1, 2, 3].as_slice()),
+			}),
+			creator: 1,
+			time: None,
+		}
+	}
+}
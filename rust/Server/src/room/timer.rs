@@ -0,0 +1,174 @@
+use fnv::FnvHashMap;
+
+use cheetah_common::commands::types::timer::{TimerAction, TimerId, TimerOwner};
+use cheetah_common::protocol::frame::channel::ChannelType;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::ServerCommandError;
+
+///
+/// Максимальное количество таймеров одного пользователя
+///
+pub const MAX_MEMBER_TIMERS: usize = 64;
+
+///
+/// Отложенное действие
+///
+#[derive(Debug, Clone)]
+pub struct Timer {
+	pub creator: RoomMemberId,
+	pub timer_id: TimerId,
+	pub owner: TimerOwner,
+	///
+	/// Время сервера в миллисекундах от создания комнаты
+	///
+	pub fire_at: u64,
+	///
+	/// Канал команды создания таймера, в нем же отправляется результат действия
+	///
+	pub channel: Option<ChannelType>,
+	pub action: TimerAction,
+}
+
+///
+/// Таймеры комнаты, срабатывают в цикле сервера
+///
+#[derive(Debug, Default)]
+pub struct Timers {
+	timers: FnvHashMap<(RoomMemberId, TimerId), Timer>,
+}
+
+impl Timers {
+	///
+	/// Добавить таймер, таймер пользователя с тем же идентификатором заменяется
+	///
+	pub fn schedule(&mut self, timer: Timer) -> Result<(), ServerCommandError> {
+		let key = (timer.creator, timer.timer_id);
+		if !self.timers.contains_key(&key) && self.timers.keys().filter(|(creator, _)| *creator == timer.creator).count() >= MAX_MEMBER_TIMERS {
+			return Err(ServerCommandError::TimersOverflow { member_id: timer.creator });
+		}
+		self.timers.insert(key, timer);
+		Ok(())
+	}
+
+	pub fn cancel(&mut self, creator: RoomMemberId, timer_id: TimerId) -> Option<Timer> {
+		self.timers.remove(&(creator, timer_id))
+	}
+
+	///
+	/// Удалить таймеры пользователя, кроме таймеров комнаты
+	///
+	pub fn remove_member_timers(&mut self, member_id: RoomMemberId) {
+		self.timers
+			.retain(|_, timer| timer.creator != member_id || timer.owner == TimerOwner::Room);
+	}
+
+	///
+	/// Удалить таймеры пользователя на удаление объекта - при передаче владения объектом другому пользователю
+	///
+	pub fn remove_object_delete_timers(&mut self, creator: RoomMemberId, object_id: GameObjectId) {
+		self.timers
+			.retain(|_, timer| timer.creator != creator || !matches!(&timer.action, TimerAction::Delete(command) if command.object_id == object_id));
+	}
+
+	///
+	/// Извлечь сработавшие таймеры в порядке времени срабатывания
+	///
+	pub fn take_expired(&mut self, server_time: u64) -> Vec<Timer> {
+		if self.timers.is_empty() {
+			return Default::default();
+		}
+		let mut keys: Vec<_> = self
+			.timers
+			.iter()
+			.filter(|(_, timer)| timer.fire_at <= server_time)
+			.map(|(key, timer)| (timer.fire_at, *key))
+			.collect();
+		keys.sort_unstable();
+		keys.into_iter().filter_map(|(_, key)| self.timers.remove(&key)).collect()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &Timer> {
+		self.timers.values()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use cheetah_common::commands::types::delete::DeleteGameObjectCommand;
+	use cheetah_common::commands::types::timer::{TimerAction, TimerId, TimerOwner};
+	use cheetah_common::room::object::GameObjectId;
+	use cheetah_common::room::RoomMemberId;
+
+	use crate::room::command::ServerCommandError;
+	use crate::room::timer::{Timer, Timers, MAX_MEMBER_TIMERS};
+
+	#[test]
+	fn should_take_expired_in_order() {
+		let mut timers = Timers::default();
+		timers.schedule(timer(1, 1, 300, TimerOwner::Member)).unwrap();
+		timers.schedule(timer(1, 2, 100, TimerOwner::Member)).unwrap();
+		timers.schedule(timer(2, 1, 200, TimerOwner::Member)).unwrap();
+
+		let expired: Vec<_> = timers.take_expired(250).iter().map(|t| (t.creator, t.timer_id)).collect();
+		assert_eq!(expired, vec![(1, 2), (2, 1)]);
+		assert_eq!(timers.iter().count(), 1);
+	}
+
+	#[test]
+	fn should_replace_timer_with_same_id() {
+		let mut timers = Timers::default();
+		timers.schedule(timer(1, 1, 100, TimerOwner::Member)).unwrap();
+		timers.schedule(timer(1, 1, 500, TimerOwner::Member)).unwrap();
+		assert!(timers.take_expired(100).is_empty());
+		assert_eq!(timers.take_expired(500).len(), 1);
+	}
+
+	#[test]
+	fn should_cancel_timer() {
+		let mut timers = Timers::default();
+		timers.schedule(timer(1, 1, 100, TimerOwner::Member)).unwrap();
+		assert!(timers.cancel(2, 1).is_none());
+		assert!(timers.cancel(1, 1).is_some());
+		assert!(timers.take_expired(100).is_empty());
+	}
+
+	#[test]
+	fn should_keep_room_timers_after_member_removed() {
+		let mut timers = Timers::default();
+		timers.schedule(timer(1, 1, 100, TimerOwner::Member)).unwrap();
+		timers.schedule(timer(1, 2, 100, TimerOwner::Room)).unwrap();
+		timers.remove_member_timers(1);
+		let expired: Vec<_> = timers.take_expired(100).iter().map(|t| t.timer_id).collect();
+		assert_eq!(expired, vec![2]);
+	}
+
+	#[test]
+	#[allow(clippy::cast_possible_truncation)]
+	fn should_limit_member_timers() {
+		let mut timers = Timers::default();
+		for timer_id in 0..MAX_MEMBER_TIMERS {
+			timers.schedule(timer(1, timer_id as TimerId, 100, TimerOwner::Member)).unwrap();
+		}
+		assert_eq!(
+			timers.schedule(timer(1, MAX_MEMBER_TIMERS as TimerId, 100, TimerOwner::Member)),
+			Err(ServerCommandError::TimersOverflow { member_id: 1 })
+		);
+		timers.schedule(timer(1, 0, 200, TimerOwner::Member)).unwrap();
+		timers.schedule(timer(2, 0, 100, TimerOwner::Member)).unwrap();
+	}
+
+	fn timer(creator: RoomMemberId, timer_id: TimerId, fire_at: u64, owner: TimerOwner) -> Timer {
+		Timer {
+			creator,
+			timer_id,
+			owner,
+			fire_at,
+			channel: None,
+			action: TimerAction::Delete(DeleteGameObjectCommand {
+				object_id: GameObjectId::default(),
+			}),
+		}
+	}
+}
@@ -0,0 +1,527 @@
+use std::slice;
+use std::time::Instant;
+
+use fnv::FnvHashMap;
+
+use cheetah_common::commands::field::{Field, FieldId};
+use cheetah_common::commands::s2c::{S2CCommand, S2CCommandWithMeta};
+use cheetah_common::commands::types::field_violation::FieldViolationCommand;
+use cheetah_common::commands::{FieldType, FieldValue};
+use cheetah_common::constants::GameObjectTemplateId;
+use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::object::GameObjectId;
+use cheetah_common::room::RoomMemberId;
+
+use crate::room::command::ServerCommandError;
+use crate::room::object::GameObject;
+use crate::room::template::config::{FieldValidationReaction, FieldValidationRule, FieldValidationTemplate};
+use crate::room::Room;
+
+///
+/// Проверка изменений числовых полей пользователями по правилам шаблона комнаты
+///
+#[derive(Debug, Default)]
+pub struct FieldValidator {
+	rules: FnvHashMap<(GameObjectTemplateId, FieldId, FieldType), Vec<(FieldValidationRule, FieldValidationReaction)>>,
+	///
+	/// Время и значение поля после последнего проверенного изменения, от них отсчитывается допустимое изменение
+	/// для [`FieldValidationRule::Rate`], при удалении поля сохраняется значение до удаления
+	///
+	last_changes: FnvHashMap<(GameObjectId, FieldId, FieldType), (Instant, f64)>,
+}
+
+///
+/// Результат проверки изменения, допустимого с учетом реакций на нарушения
+///
+#[derive(Debug, Default, PartialEq)]
+pub struct ValidationOutcome {
+	///
+	/// Значение, ограниченное правилами с реакцией [`FieldValidationReaction::Clamp`]
+	///
+	pub clamped: Option<FieldValue>,
+	///
+	/// Нарушено правило с реакцией [`FieldValidationReaction::Report`]
+	///
+	pub reported: bool,
+}
+
+impl FieldValidator {
+	#[must_use]
+	pub fn new(templates: &[FieldValidationTemplate]) -> Self {
+		let mut rules: FnvHashMap<_, Vec<_>> = Default::default();
+		for template in templates {
+			rules
+				.entry((template.template, template.field.id, template.field.field_type))
+				.or_default()
+				.push((template.rule, template.reaction));
+		}
+		Self {
+			rules,
+			last_changes: Default::default(),
+		}
+	}
+
+	#[must_use]
+	pub fn has_rules(&self, template: GameObjectTemplateId, field: Field) -> bool {
+		self.rules.contains_key(&(template, field.id, field.field_type))
+	}
+
+	///
+	/// Проверить текущее значение поля объекта относительно значения до изменения, удаление поля не проверяется,
+	/// для отсутствующего до изменения поля используется значение последнего проверенного изменения
+	///
+	pub fn validate(
+		&self,
+		object: &GameObject,
+		field: Field,
+		previous: Option<&FieldValue>,
+		now: Instant,
+	) -> Result<ValidationOutcome, ServerCommandError> {
+		let Some(rules) = self.rules.get(&(object.template_id, field.id, field.field_type)) else {
+			return Ok(ValidationOutcome::default());
+		};
+		let Some(mut value) = object.get_field_wrapped(field.id, field.field_type).and_then(to_f64) else {
+			return Ok(ValidationOutcome::default());
+		};
+		let mut clamped = false;
+		let mut reported = false;
+		let key = (object.id, field.id, field.field_type);
+		let previous = previous.and_then(to_f64).or_else(|| self.last_changes.get(&key).map(|(_, value)| *value));
+		for (rule, reaction) in rules {
+			let Some((min, max)) = self.get_allowed_range(rule, object.id, field, previous, now) else {
+				continue;
+			};
+			if min <= value && value <= max {
+				continue;
+			}
+			match reaction {
+				FieldValidationReaction::Reject => {
+					return Err(ServerCommandError::FieldValidationFailed {
+						object_id: object.id,
+						field_id: field.id,
+					});
+				}
+				FieldValidationReaction::Clamp => {
+					value = value.max(min).min(max);
+					clamped = true;
+				}
+				FieldValidationReaction::Report => reported = true,
+			}
+		}
+		Ok(ValidationOutcome {
+			clamped: clamped.then(|| match field.field_type {
+				FieldType::Long => FieldValue::Long(round_into_range(object, field, value)),
+				_ => FieldValue::Double(value),
+			}),
+			reported,
+		})
+	}
+
+	///
+	/// Допустимый диапазон значения, для [`FieldValidationRule::Rate`] без предыдущего значения и без проверенных изменений поля
+	/// изменение не проверяется
+	///
+	fn get_allowed_range(
+		&self,
+		rule: &FieldValidationRule,
+		object_id: GameObjectId,
+		field: Field,
+		previous: Option<f64>,
+		now: Instant,
+	) -> Option<(f64, f64)> {
+		match *rule {
+			FieldValidationRule::Range { min, max } => Some((min, max)),
+			FieldValidationRule::Rate {
+				min_delta,
+				max_delta,
+				period,
+			} => {
+				let previous = previous?;
+				// без сохраненного времени изменения или с нулевым периодом допустимо изменение за один период
+				let scale = match self.last_changes.get(&(object_id, field.id, field.field_type)) {
+					Some((changed_at, _)) if !period.is_zero() => now.saturating_duration_since(*changed_at).as_secs_f64() / period.as_secs_f64(),
+					_ => 1.0,
+				};
+				Some((previous + min_delta * scale, previous + max_delta * scale))
+			}
+		}
+	}
+
+	///
+	/// Сохранить проверенное изменение поля, для удаленного поля сохраняется значение до изменения
+	///
+	pub fn changed(&mut self, object: &GameObject, field: Field, previous: Option<&FieldValue>, now: Instant) {
+		let value = object.get_field_wrapped(field.id, field.field_type).or(previous).and_then(to_f64);
+		if let Some(value) = value {
+			self.last_changes.insert((object.id, field.id, field.field_type), (now, value));
+		}
+	}
+
+	pub fn remove_object(&mut self, object_id: &GameObjectId) {
+		self.last_changes.retain(|(id, ..), _| id != object_id);
+	}
+}
+
+impl Room {
+	///
+	/// Проверяются изменения поля пользователями, кроме супер-пользователей
+	///
+	pub(crate) fn is_field_validated(&self, template: GameObjectTemplateId, field: Field, member_id: RoomMemberId) -> bool {
+		self.field_validator
+			.as_ref()
+			.is_some_and(|validator| validator.has_rules(template, field))
+			&& self.members.get(&member_id).is_some_and(|member| !member.template.super_member)
+	}
+
+	///
+	/// Проверить изменение поля объекта комнаты, при отклонении восстанавливается предыдущее значение,
+	/// при ограничении значения команда заменяется на установку ограниченного значения
+	///
+	pub(crate) fn validate_field_change(
+		&mut self,
+		object_id: GameObjectId,
+		field: Field,
+		member_id: RoomMemberId,
+		previous: Option<FieldValue>,
+		command: S2CCommand,
+	) -> Result<S2CCommand, ServerCommandError> {
+		let now = Instant::now();
+		let (Some(validator), Some(object)) = (self.field_validator.as_mut(), self.objects.get_mut(&object_id)) else {
+			return Ok(command);
+		};
+		let (command, reported) = apply_validation(validator, object, field, previous.clone(), command, now)?;
+		validator.changed(object, field, previous.as_ref(), now);
+		if reported {
+			self.send_field_violation(member_id, object_id, field)?;
+		}
+		Ok(command)
+	}
+
+	///
+	/// Сообщить супер-пользователям о нарушении правила проверки поля
+	///
+	pub(crate) fn send_field_violation(&mut self, member_id: RoomMemberId, object_id: GameObjectId, field: Field) -> Result<(), ServerCommandError> {
+		let command = S2CCommandWithMeta {
+			field: None,
+			creator: member_id,
+			command: S2CCommand::FieldViolation(FieldViolationCommand {
+				member_id,
+				object_id,
+				field_id: field.id,
+				field_type: field.field_type,
+			}),
+		};
+		self.send_to_members(AccessGroups::super_group(), None, slice::from_ref(&command), |member| {
+			member.template.super_member
+		})
+	}
+}
+
+///
+/// Проверить изменение поля объекта, возвращается итоговая команда и признак нарушения правила с реакцией [`FieldValidationReaction::Report`]
+///
+pub(crate) fn apply_validation(
+	validator: &FieldValidator,
+	object: &mut GameObject,
+	field: Field,
+	previous: Option<FieldValue>,
+	command: S2CCommand,
+	now: Instant,
+) -> Result<(S2CCommand, bool), ServerCommandError> {
+	match validator.validate(object, field, previous.as_ref(), now) {
+		Err(e) => {
+			match previous {
+				None => object.delete_field(field.id, field.field_type),
+				Some(previous) => object.set_field_wrapped(field.id, previous)?,
+			}
+			Err(e)
+		}
+		Ok(ValidationOutcome { clamped: None, reported }) => Ok((command, reported)),
+		Ok(ValidationOutcome {
+			clamped: Some(value),
+			reported,
+		}) => {
+			object.set_field_wrapped(field.id, value.clone())?;
+			Ok((S2CCommand::new_set_command(value, object.id, field.id), reported))
+		}
+	}
+}
+
+fn to_f64(value: &FieldValue) -> Option<f64> {
+	match value {
+		#[allow(clippy::cast_precision_loss)]
+		FieldValue::Long(value) => Some(*value as f64),
+		FieldValue::Double(value) => Some(*value),
+		_ => None,
+	}
+}
+
+///
+/// Ограниченное значение для поля типа long округляется в сторону исходного значения, чтобы остаться в диапазоне
+///
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn round_into_range(object: &GameObject, field: Field, clamped: f64) -> i64 {
+	let value = object.get_field_wrapped(field.id, field.field_type).and_then(to_f64).unwrap_or_default();
+	if clamped < value {
+		clamped.floor() as i64
+	} else {
+		clamped.ceil() as i64
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{Duration, Instant};
+
+	use cheetah_common::commands::field::Field;
+	use cheetah_common::commands::s2c::S2CCommand;
+	use cheetah_common::commands::types::field::{DeleteFieldCommand, SetFieldCommand};
+	use cheetah_common::commands::types::long::IncrementLongC2SCommand;
+	use cheetah_common::commands::types::transaction::{TransactionCommand, TransactionMutation};
+	use cheetah_common::commands::{FieldType, FieldValue};
+	use cheetah_common::room::access::AccessGroups;
+	use cheetah_common::room::object::GameObjectId;
+	use cheetah_common::room::owner::GameObjectOwner;
+	use cheetah_common::room::RoomMemberId;
+
+	use crate::room::command::{ServerCommandError, ServerCommandExecutor};
+	use crate::room::template::config::{FieldValidationReaction, FieldValidationRule, FieldValidationTemplate, MemberTemplate, RoomTemplate};
+	use crate::room::validation::FieldValidator;
+	use crate::room::Room;
+
+	const SCORE: Field = Field {
+		id: 1,
+		field_type: FieldType::Long,
+	};
+	const POSITION: Field = Field {
+		id: 2,
+		field_type: FieldType::Double,
+	};
+
+	#[test]
+	fn should_reject_and_restore_value() {
+		let (mut room, member_id, object_id) = setup(vec![validation(SCORE, range(0.0, 3.0), FieldValidationReaction::Reject)]);
+		set(&mut room, member_id, object_id, SCORE, 2.into()).unwrap();
+		room.test_out_commands.clear();
+
+		assert_eq!(
+			set(&mut room, member_id, object_id, SCORE, 4.into()),
+			Err(ServerCommandError::FieldValidationFailed {
+				object_id,
+				field_id: SCORE.id
+			})
+		);
+		assert_eq!(*room.get_object(object_id).unwrap().get_field::<i64>(SCORE.id).unwrap(), 2);
+		assert!(room.test_out_commands.is_empty());
+	}
+
+	#[test]
+	fn should_clamp_value() {
+		let (mut room, member_id, object_id) = setup(vec![validation(SCORE, range(0.0, 3.5), FieldValidationReaction::Clamp)]);
+		IncrementLongC2SCommand {
+			object_id,
+			field_id: SCORE.id,
+			increment: 10,
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+
+		assert_eq!(*room.get_object(object_id).unwrap().get_field::<i64>(SCORE.id).unwrap(), 3);
+		assert!(matches!(room.test_out_commands.pop_back(), Some((.., S2CCommand::SetField(c))) if c.value == FieldValue::Long(3)));
+	}
+
+	#[test]
+	fn should_report_violation_to_super_members() {
+		let (mut room, member_id, object_id) = setup(vec![validation(POSITION, range(0.0, 10.0), FieldValidationReaction::Report)]);
+		let super_member_id = room.register_member(MemberTemplate::new_super_member());
+		room.test_mark_as_connected(super_member_id).unwrap();
+
+		set(&mut room, member_id, object_id, POSITION, 5.0.into()).unwrap();
+		set(&mut room, member_id, object_id, POSITION, 15.0.into()).unwrap();
+		assert_eq!(*room.get_object(object_id).unwrap().get_field::<f64>(POSITION.id).unwrap(), 15.0);
+
+		let violations: Vec<_> = room
+			.test_get_member_out_commands(super_member_id)
+			.into_iter()
+			.filter(|command| matches!(command, S2CCommand::FieldViolation(_)))
+			.collect();
+		assert_eq!(violations.len(), 1);
+		assert!(matches!(violations.first(), Some(S2CCommand::FieldViolation(c))
+			if c.member_id == member_id && c.object_id == object_id && c.field_id == POSITION.id && c.field_type == FieldType::Double));
+	}
+
+	#[test]
+	fn should_not_validate_super_member() {
+		let (mut room, _, object_id) = setup(vec![validation(SCORE, range(0.0, 3.0), FieldValidationReaction::Reject)]);
+		let super_member_id = room.register_member(MemberTemplate::new_super_member());
+		set(&mut room, super_member_id, object_id, SCORE, 100.into()).unwrap();
+		assert_eq!(*room.get_object(object_id).unwrap().get_field::<i64>(SCORE.id).unwrap(), 100);
+	}
+
+	#[test]
+	fn should_scale_rate_by_elapsed_time() {
+		let rule = FieldValidationRule::Rate {
+			min_delta: -20.0,
+			max_delta: 20.0,
+			period: Duration::from_millis(100),
+		};
+		let mut validator = FieldValidator::new(&[validation(POSITION, rule, FieldValidationReaction::Clamp)]);
+		let (mut room, _, object_id) = setup(vec![]);
+		let object = room.get_object_mut(object_id).unwrap();
+		let now = Instant::now();
+		let previous = FieldValue::Double(100.0);
+
+		// без времени предыдущего изменения допустимо изменение за один период
+		object.set_field(POSITION.id, 130.0).unwrap();
+		assert_eq!(
+			validator.validate(object, POSITION, Some(&previous), now).unwrap().clamped,
+			Some(FieldValue::Double(120.0))
+		);
+
+		validator.changed(object, POSITION, None, now);
+		let now = now + Duration::from_millis(50);
+		assert_eq!(
+			validator.validate(object, POSITION, Some(&previous), now).unwrap().clamped,
+			Some(FieldValue::Double(110.0))
+		);
+		object.set_field(POSITION.id, 95.0).unwrap();
+		assert_eq!(validator.validate(object, POSITION, Some(&previous), now).unwrap().clamped, None);
+		// без предыдущего значения изменение проверяется относительно последнего проверенного значения
+		object.set_field(POSITION.id, 1000.0).unwrap();
+		assert_eq!(
+			validator.validate(object, POSITION, None, now).unwrap().clamped,
+			Some(FieldValue::Double(140.0))
+		);
+		// без предыдущего значения и проверенных изменений поля изменение не проверяется
+		validator.remove_object(&object_id);
+		assert_eq!(validator.validate(object, POSITION, None, now).unwrap().clamped, None);
+	}
+
+	///
+	/// После удаления поля новое значение проверяется относительно значения до удаления
+	///
+	#[test]
+	fn should_validate_rate_after_delete_field() {
+		let rule = FieldValidationRule::Rate {
+			min_delta: -10.0,
+			max_delta: 10.0,
+			period: Duration::from_secs(100),
+		};
+		let (mut room, member_id, object_id) = setup(vec![validation(SCORE, rule, FieldValidationReaction::Reject)]);
+		room.get_object_mut(object_id).unwrap().set_field(SCORE.id, 0).unwrap();
+		DeleteFieldCommand {
+			object_id,
+			field_id: SCORE.id,
+			field_type: SCORE.field_type,
+		}
+		.execute(&mut room, member_id)
+		.unwrap();
+
+		assert_eq!(
+			set(&mut room, member_id, object_id, SCORE, 1000.into()),
+			Err(ServerCommandError::FieldValidationFailed {
+				object_id,
+				field_id: SCORE.id
+			})
+		);
+		assert!(room.get_object(object_id).unwrap().get_field::<i64>(SCORE.id).is_none());
+	}
+
+	#[test]
+	fn should_reject_transaction() {
+		let (mut room, member_id, object_id) = setup(vec![validation(SCORE, range(0.0, 3.0), FieldValidationReaction::Reject)]);
+		let command = TransactionCommand {
+			preconditions: vec![],
+			mutations: vec![
+				TransactionMutation::SetField(SetFieldCommand {
+					object_id,
+					field_id: POSITION.id,
+					value: 1.0.into(),
+				}),
+				TransactionMutation::SetField(SetFieldCommand {
+					object_id,
+					field_id: SCORE.id,
+					value: 5.into(),
+				}),
+			],
+		};
+		assert_eq!(
+			command.execute(&mut room, member_id),
+			Err(ServerCommandError::FieldValidationFailed {
+				object_id,
+				field_id: SCORE.id
+			})
+		);
+		let object = room.get_object(object_id).unwrap();
+		assert!(object.get_field::<f64>(POSITION.id).is_none());
+		assert!(object.get_field::<i64>(SCORE.id).is_none());
+		assert!(room.test_out_commands.is_empty());
+	}
+
+	///
+	/// Несколько изменений поля в транзакции проверяются относительно значения до транзакции
+	///
+	#[test]
+	fn should_validate_net_change_in_transaction() {
+		let rule = FieldValidationRule::Rate {
+			min_delta: -10.0,
+			max_delta: 10.0,
+			period: Duration::from_millis(100),
+		};
+		let (mut room, member_id, object_id) = setup(vec![validation(SCORE, rule, FieldValidationReaction::Reject)]);
+		room.get_object_mut(object_id).unwrap().set_field(SCORE.id, 0).unwrap();
+		let increment = TransactionMutation::IncrementLong(IncrementLongC2SCommand {
+			object_id,
+			field_id: SCORE.id,
+			increment: 10,
+		});
+		let command = TransactionCommand {
+			preconditions: vec![],
+			mutations: vec![increment.clone(), increment],
+		};
+		assert_eq!(
+			command.execute(&mut room, member_id),
+			Err(ServerCommandError::FieldValidationFailed {
+				object_id,
+				field_id: SCORE.id
+			})
+		);
+		assert_eq!(*room.get_object(object_id).unwrap().get_field::<i64>(SCORE.id).unwrap(), 0);
+	}
+
+	fn setup(field_validations: Vec<FieldValidationTemplate>) -> (Room, RoomMemberId, GameObjectId) {
+		let access_groups = AccessGroups(10);
+		let mut room = Room::from_template(RoomTemplate {
+			field_validations,
+			..RoomTemplate::default()
+		});
+		let member_id = room.register_member(MemberTemplate::stub(access_groups));
+		let object_id = room
+			.test_create_object_with_created_state(GameObjectOwner::Member(member_id), access_groups)
+			.id;
+		room.test_out_commands.clear();
+		(room, member_id, object_id)
+	}
+
+	fn set(room: &mut Room, member_id: RoomMemberId, object_id: GameObjectId, field: Field, value: FieldValue) -> Result<(), ServerCommandError> {
+		SetFieldCommand {
+			object_id,
+			field_id: field.id,
+			value,
+		}
+		.execute(room, member_id)
+	}
+
+	fn range(min: f64, max: f64) -> FieldValidationRule {
+		FieldValidationRule::Range { min, max }
+	}
+
+	fn validation(field: Field, rule: FieldValidationRule, reaction: FieldValidationReaction) -> FieldValidationTemplate {
+		FieldValidationTemplate {
+			template: 0,
+			field,
+			rule,
+			reaction,
+		}
+	}
+}
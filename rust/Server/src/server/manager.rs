@@ -8,7 +8,11 @@ use std::time::Duration;
 
 use thiserror::Error;
 
+use cheetah_common::commands::field::Field;
+use cheetah_common::commands::FieldValue;
 use cheetah_common::protocol::others::member_id::MemberAndRoomId;
+use cheetah_common::room::access::AccessGroups;
+use cheetah_common::room::object::GameObjectId;
 use cheetah_common::room::{RoomId, RoomMemberId};
 
 use crate::debug::proto::admin;
@@ -33,7 +37,7 @@ pub struct RoomsServerManager {
 
 #[derive(Debug)]
 pub enum ManagementTask {
-	CreateRoom(RoomTemplate),
+	CreateRoom(Box<RoomTemplate>),
 	CreateMember(RoomId, MemberTemplate),
 	DeleteMember(MemberAndRoomId),
 	Dump(RoomId),
@@ -44,6 +48,9 @@ pub enum ManagementTask {
 	MarkRoomAsReady(RoomId, String),
 	GetRoomInfo(RoomId),
 	UpdateRoomPermissions(RoomId, Permissions),
+	UpdateMemberGroups(MemberAndRoomId, AccessGroups),
+	UpdateObjectGroups(RoomId, GameObjectId, AccessGroups),
+	GetFieldHistoryValue(RoomId, GameObjectId, Field, u64),
 }
 
 #[derive(Debug)]
@@ -59,6 +66,9 @@ pub enum ManagementTaskResult {
 	MarkRoomAsReady,
 	GetRoomInfo(RoomInfo),
 	UpdateRoomPermissions,
+	UpdateMemberGroups,
+	UpdateObjectGroups,
+	GetFieldHistoryValue(Option<FieldValue>),
 }
 
 #[derive(Error, Debug)]
@@ -136,7 +146,7 @@ impl RoomsServerManager {
 	}
 
 	pub fn create_room(&mut self, template: RoomTemplate) -> Result<RoomId, TaskError> {
-		self.execute_task(ManagementTask::CreateRoom(template)).map(|res| {
+		self.execute_task(ManagementTask::CreateRoom(Box::new(template))).map(|res| {
 			if let ManagementTaskResult::CreateRoom(room_id) = res {
 				self.created_room_counter += 1;
 				Ok(room_id)
@@ -178,6 +188,41 @@ impl RoomsServerManager {
 		self.execute_task(ManagementTask::UpdateRoomPermissions(room_id, permissions)).map(|_| ())
 	}
 
+	///
+	/// Изменить группы доступа пользователя, пользователю загружаются ставшие доступными объекты и удаляются недоступные
+	///
+	pub(crate) fn update_member_groups(&mut self, id: MemberAndRoomId, groups: AccessGroups) -> Result<(), TaskError> {
+		self.execute_task(ManagementTask::UpdateMemberGroups(id, groups)).map(|_| ())
+	}
+
+	///
+	/// Изменить группы доступа объекта, пользователям, получившим доступ, загружается объект, потерявшим - удаляется
+	///
+	pub(crate) fn update_object_groups(&mut self, room_id: RoomId, object_id: GameObjectId, groups: AccessGroups) -> Result<(), TaskError> {
+		self.execute_task(ManagementTask::UpdateObjectGroups(room_id, object_id, groups))
+			.map(|_| ())
+	}
+
+	///
+	/// Получить значение поля объекта в момент времени сервера из истории значений
+	///
+	pub(crate) fn get_field_history_value(
+		&mut self,
+		room_id: RoomId,
+		object_id: GameObjectId,
+		field: Field,
+		time: u64,
+	) -> Result<Option<FieldValue>, TaskError> {
+		self.execute_task(ManagementTask::GetFieldHistoryValue(room_id, object_id, field, time))
+			.map(|res| {
+				if let ManagementTaskResult::GetFieldHistoryValue(value) = res {
+					Ok(value)
+				} else {
+					Err(TaskError::UnexpectedResultError)
+				}
+			})?
+	}
+
 	pub(crate) fn get_room_info(&mut self, room_id: RoomId) -> Result<RoomInfo, TaskError> {
 		self.execute_task(ManagementTask::GetRoomInfo(room_id)).map(|res| {
 			if let ManagementTaskResult::GetRoomInfo(room_info) = res {
@@ -46,6 +46,14 @@ pub struct Measurers {
 	///
 	input_command_execution_time: HistogramMeasurersByLabel<(MeasureStringId, Option<FieldId>)>,
 	///
+	/// Количество команд, отброшенных из-за превышения лимита частоты
+	///
+	rate_limited_command_count: IntCounterMeasurersByLabel<(MeasureStringId, Option<FieldId>, RoomTemplateString)>,
+	///
+	/// Количество пользователей, отключенных из-за превышения лимита частоты команд
+	///
+	rate_limit_disconnect_count: MeasurersByLabel<String, IntCounter, Opts>,
+	///
 	/// Размер входящего фрейма
 	///
 	input_frame_size: Histogram,
@@ -86,6 +94,8 @@ impl Measurers {
 			income_command_count: Self::create_income_command_count_measurers(registry),
 			outcome_command_count: Self::create_outcome_command_count_measurers(registry),
 			input_command_execution_time: Self::create_execution_command_time_measurers(registry),
+			rate_limited_command_count: Self::create_rate_limited_command_count_measurers(registry),
+			rate_limit_disconnect_count: Self::create_rate_limit_disconnect_count_measurers(registry),
 			input_frame_size: Self::create_input_frame_size(registry),
 			input_frame_execution_time: Self::create_input_frame_time(registry),
 			server_cycle_execution_time: Self::create_server_cycle_execution_time(registry),
@@ -211,6 +221,38 @@ impl Measurers {
 		)
 	}
 
+	fn create_rate_limited_command_count_measurers(
+		registry: &Registry,
+	) -> MeasurersByLabel<(MeasureStringId, Option<FieldId>, MeasureStringId), IntCounter, Opts> {
+		MeasurersByLabel::new(
+			registry,
+			Box::new(|(command, field_id, template)| {
+				Opts::new("rate_limited_command_counter", "Commands dropped by rate limit").const_labels(
+					vec![
+						("command".to_owned(), command.to_string()),
+						(
+							"field_id".to_owned(),
+							field_id.map(|f| format!("{f}")).unwrap_or_else(|| "unknown".to_owned()),
+						),
+						("template".to_owned(), template.to_string()),
+					]
+					.into_iter()
+					.collect(),
+				)
+			}),
+		)
+	}
+
+	fn create_rate_limit_disconnect_count_measurers(registry: &Registry) -> MeasurersByLabel<String, IntCounter, Opts> {
+		MeasurersByLabel::new(
+			registry,
+			Box::new(|template| {
+				Opts::new("rate_limit_disconnect_counter", "Members disconnected by rate limit")
+					.const_labels(vec![("template".to_owned(), template.clone())].into_iter().collect())
+			}),
+		)
+	}
+
 	fn create_object_count_measurers(registry: &Registry) -> MeasurersByLabel<String, IntGauge, Opts> {
 		MeasurersByLabel::new(
 			registry,
@@ -276,6 +318,19 @@ impl Measurers {
 		self.input_command_execution_time.measurer(&key).observe(duration.as_secs_f64());
 	}
 
+	pub(crate) fn on_rate_limited_command(&mut self, template: &str, command: &C2SCommand) {
+		let key = (
+			MeasureStringId::from(command.as_ref()),
+			command.get_field_id(),
+			MeasureStringId::from(template),
+		);
+		self.rate_limited_command_count.measurer(&key).inc();
+	}
+
+	pub(crate) fn on_rate_limit_disconnect(&mut self, template: &String) {
+		self.rate_limit_disconnect_count.measurer(template).inc();
+	}
+
 	#[allow(clippy::cast_precision_loss)]
 	pub(crate) fn on_income_frame(&mut self, size: usize, duration: Duration) {
 		self.input_frame_size.observe(size as f64);
@@ -83,7 +83,7 @@ impl RoomsServer {
 
 	fn execute_task(&mut self, task: ManagementTask, now: Instant) -> Result<ManagementTaskResult, TaskExecutionError> {
 		let res = match task {
-			ManagementTask::CreateRoom(template) => ManagementTaskResult::CreateRoom(self.rooms.create_room(template)),
+			ManagementTask::CreateRoom(template) => ManagementTaskResult::CreateRoom(self.rooms.create_room(*template)),
 			ManagementTask::DeleteRoom(room_id) => self.delete_room(room_id).map(|_| ManagementTaskResult::DeleteRoom)?,
 			ManagementTask::CreateMember(room_id, member_template) => self
 				.register_member(room_id, member_template, now)
@@ -122,6 +122,29 @@ impl RoomsServer {
 				.map(|room| ManagementTaskResult::GetRoomInfo(room.get_info()))
 				.ok_or(TaskExecutionError::RoomNotFound(RoomNotFoundError(room_id)))?,
 			ManagementTask::UpdateRoomPermissions(room_id, permissions) => self.update_room_permissions(room_id, &permissions)?,
+			ManagementTask::UpdateMemberGroups(id, groups) => self
+				.rooms
+				.room_by_id
+				.get_mut(&id.room_id)
+				.ok_or(TaskExecutionError::RoomNotFound(RoomNotFoundError(id.room_id)))?
+				.update_member_groups(id.member_id, groups)
+				.map(|_| ManagementTaskResult::UpdateMemberGroups)?,
+			ManagementTask::UpdateObjectGroups(room_id, object_id, groups) => self
+				.rooms
+				.room_by_id
+				.get_mut(&room_id)
+				.ok_or(TaskExecutionError::RoomNotFound(RoomNotFoundError(room_id)))?
+				.set_object_access_groups(object_id, groups, u16::MAX)
+				.map(|_| ManagementTaskResult::UpdateObjectGroups)?,
+			ManagementTask::GetFieldHistoryValue(room_id, object_id, field, time) => {
+				let room = self
+					.rooms
+					.room_by_id
+					.get(&room_id)
+					.ok_or(TaskExecutionError::RoomNotFound(RoomNotFoundError(room_id)))?;
+				room.get_object(object_id)?;
+				ManagementTaskResult::GetFieldHistoryValue(room.get_field_history_value(object_id, field, time))
+			}
 		};
 		Ok(res)
 	}
@@ -1,6 +1,7 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::io::{Cursor, Error, ErrorKind};
+use std::iter;
 use std::net::{SocketAddr, UdpSocket};
 use std::rc::Rc;
 use std::time::Instant;
@@ -47,7 +48,8 @@ impl NetworkLayer {
 
 	pub fn cycle(&mut self, rooms: &mut Rooms, now: Instant) {
 		self.receive(rooms, now);
-		self.send(rooms);
+		self.disconnect_members_by_rooms(rooms);
+		self.send(rooms, now);
 
 		let mut disconnected = heapless::Vec::<MemberAndRoomId, 1000>::new();
 		self.sessions.iter_mut().for_each(|(id, session)| {
@@ -69,8 +71,8 @@ impl NetworkLayer {
 	///
 	/// Отправить команды клиентам
 	///
-	fn send(&mut self, rooms: &mut Rooms) {
-		rooms.collect_out_commands(|room_id, member_id, commands| {
+	fn send(&mut self, rooms: &mut Rooms, now: Instant) {
+		rooms.collect_out_commands(now, |room_id, member_id, commands| {
 			let id = MemberAndRoomId {
 				member_id: *member_id,
 				room_id: *room_id,
@@ -195,6 +197,18 @@ impl NetworkLayer {
 		);
 	}
 
+	///
+	/// Разорвать соединение с пользователями, отключенными комнатами (например, при превышении лимита частоты команд)
+	///
+	fn disconnect_members_by_rooms(&mut self, rooms: &mut Rooms) {
+		for (id, reason) in rooms.take_members_to_disconnect() {
+			self.disconnect_members(iter::once(id), reason);
+			if let Err(e) = rooms.member_disconnected(&id) {
+				e.log_error(id.room_id, id.member_id);
+			}
+		}
+	}
+
 	/// Послать `DisconnectHeader` пользователю и удалить сессию с сервера
 	pub fn disconnect_members(&mut self, member_and_room_ids: impl Iterator<Item = MemberAndRoomId>, reason: DisconnectByCommandReason) {
 		for id in member_and_room_ids {
@@ -222,7 +236,7 @@ mod tests {
 	use cheetah_common::protocol::frame::MAX_FRAME_SIZE;
 	use cheetah_common::protocol::others::member_id::MemberAndRoomId;
 
-	use crate::room::template::config::MemberTemplate;
+	use crate::room::template::config::{MemberTemplate, RoomTemplate};
 	use crate::room::Member;
 	use crate::server::measurers::Measurers;
 	use crate::server::network::NetworkLayer;
@@ -280,7 +294,10 @@ mod tests {
 			attached: false,
 			template: member_template.clone(),
 			compare_and_set_cleaners: Default::default(),
+			structure_delta_encoder: Default::default(),
+			tick_commands: Default::default(),
 			out_commands: Default::default(),
+			subscription: Default::default(),
 		};
 		udp_server.register_member(Instant::now(), 0, member.id, member.template.clone());
 
@@ -323,6 +340,29 @@ mod tests {
 		assert!(!udp_server.sessions.contains_key(&member_to_delete), "session should be deleted");
 	}
 
+	#[test]
+	fn should_disconnect_members_by_rooms() {
+		let mut udp_server = create_network_layer();
+		let mut rooms = Rooms::default();
+		let room_id = rooms.create_room(RoomTemplate::default());
+		let member_template = MemberTemplate::new_member(Default::default(), Default::default());
+		let member_id = rooms.register_member(room_id, member_template.clone()).unwrap();
+		udp_server.register_member(Instant::now(), room_id, member_id, member_template);
+
+		let room = rooms.room_by_id.get_mut(&room_id).unwrap();
+		room.members_to_disconnect
+			.push((member_id, DisconnectByCommandReason::CommandRateLimitExceeded));
+		udp_server.disconnect_members_by_rooms(&mut rooms);
+
+		let id = MemberAndRoomId { member_id, room_id };
+		assert!(!udp_server.sessions.contains_key(&id), "session should be deleted");
+		assert!(
+			!rooms.room_by_id[&room_id].members.contains_key(&member_id),
+			"member should be removed from room"
+		);
+		assert!(rooms.take_members_to_disconnect().is_empty());
+	}
+
 	fn create_network_layer() -> NetworkLayer {
 		NetworkLayer::new(
 			bind_to_free_socket().unwrap(),
@@ -1,11 +1,14 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::mem;
 use std::rc::Rc;
+use std::time::Instant;
 
 use fnv::{FnvBuildHasher, FnvHashSet};
 use thiserror::Error;
 
 use cheetah_common::protocol::commands::output::CommandWithChannelType;
+use cheetah_common::protocol::disconnect::command::DisconnectByCommandReason;
 use cheetah_common::protocol::frame::applications::CommandWithChannel;
 use cheetah_common::protocol::others::member_id::MemberAndRoomId;
 use cheetah_common::room::{RoomId, RoomMemberId};
@@ -65,13 +68,13 @@ impl Rooms {
 		}
 	}
 
-	pub fn collect_out_commands<F>(&mut self, mut collector: F)
+	pub fn collect_out_commands<F>(&mut self, now: Instant, mut collector: F)
 	where
 		F: FnMut(&RoomId, &RoomMemberId, &[CommandWithChannelType]),
 	{
 		for (room_id, room) in &mut self.room_by_id {
 			let template = MeasureStringId::from(room.template_name.as_str());
-			room.collect_out_commands(|member_id, commands| {
+			room.collect_out_commands(now, |member_id, commands| {
 				collector(room_id, member_id, commands);
 				self.measurers.borrow_mut().on_output_commands(&template, commands);
 			});
@@ -98,6 +101,26 @@ impl Rooms {
 		}
 	}
 
+	///
+	/// Извлечь пользователей, отключенных комнатами
+	///
+	pub fn take_members_to_disconnect(&mut self) -> Vec<(MemberAndRoomId, DisconnectByCommandReason)> {
+		self.room_by_id
+			.iter_mut()
+			.flat_map(|(room_id, room)| {
+				mem::take(&mut room.members_to_disconnect).into_iter().map(|(member_id, reason)| {
+					(
+						MemberAndRoomId {
+							member_id,
+							room_id: *room_id,
+						},
+						reason,
+					)
+				})
+			})
+			.collect()
+	}
+
 	pub fn member_disconnected(&mut self, member_and_room_id: &MemberAndRoomId) -> Result<(), ServerCommandError> {
 		match self.room_by_id.get_mut(&member_and_room_id.room_id) {
 			None => Err(ServerCommandError::RoomNotFound(RoomNotFoundError(member_and_room_id.room_id))),